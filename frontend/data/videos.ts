@@ -74,7 +74,6 @@ export const registerVideoOnBlockchain = async (
       totalChunks: videoData.totalChunks,
       pricePerChunk: videoData.pricePerChunk,
       title: videoData.title,
-      description: videoData.description,
     });
 
     return {
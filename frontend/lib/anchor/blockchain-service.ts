@@ -104,7 +104,10 @@ export class BlockchainService {
     totalChunks: number;
     pricePerChunk: number; // In tokens (e.g., 0.001)
     title: string;
-    description: string;
+    metadataUri?: string; // Off-chain metadata JSON (description etc.)
+    metadataHash?: number[];
+    thumbnailCid?: string;
+    posterCid?: string;
   }) {
     if (!this.program || !this.wallet?.publicKey) {
       throw new Error("Wallet not connected");
@@ -120,7 +123,10 @@ export class BlockchainService {
       totalChunks: params.totalChunks,
       pricePerChunk: pricePerChunkBN,
       title: params.title,
-      description: params.description,
+      metadataUri: params.metadataUri,
+      metadataHash: params.metadataHash,
+      thumbnailCid: params.thumbnailCid,
+      posterCid: params.posterCid,
       creator: this.wallet.publicKey,
     });
 
@@ -151,7 +157,16 @@ export class BlockchainService {
         totalChunks: video.totalChunks,
         pricePerChunk: video.pricePerChunk,
         title: video.title,
-        description: video.description,
+        metadataUri: video.metadataUri,
+        metadataHash: video.metadataHash,
+        thumbnailCid: video.thumbnailCid,
+        posterCid: video.posterCid,
+        durationSeconds: video.durationSeconds,
+        chunkDurationMs: video.chunkDurationMs,
+        mediaFormat: video.mediaFormat,
+        rating: video.rating,
+        contentFlags: video.contentFlags,
+        isImmutable: video.isImmutable,
         isActive: video.isActive,
         totalSessions: video.totalSessions,
         totalChunksServed: video.totalChunksServed,
@@ -170,6 +185,8 @@ export class BlockchainService {
     videoId: string;
     pricePerChunk?: number;
     isActive?: boolean;
+    thumbnailCid?: string;
+    posterCid?: string;
   }) {
     if (!this.program || !this.wallet?.publicKey) {
       throw new Error("Wallet not connected");
@@ -183,6 +200,8 @@ export class BlockchainService {
       videoId: params.videoId,
       pricePerChunk: pricePerChunkBN,
       isActive: params.isActive,
+      thumbnailCid: params.thumbnailCid,
+      posterCid: params.posterCid,
       creator: this.wallet.publicKey,
     });
 
@@ -450,11 +469,17 @@ export class BlockchainService {
       viewerTokenAccountInfo.address.toString()
     );
 
-    // 2. Creator token account (create if needed)
+    // 2. Creator token account (create if needed), owned by the payout
+    // address when the creator has set one
+    const earnings = await this.getCreatorEarnings(params.videoId);
+    const payoutOwner =
+      earnings && !earnings.payoutAddress.equals(PublicKey.default)
+        ? earnings.payoutAddress
+        : video.creator;
     const creatorTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
       this.connection,
       tokenMint,
-      video.creator,
+      payoutOwner,
       this.wallet.publicKey // Viewer pays for creation
     );
     console.log(
@@ -579,6 +604,7 @@ export class BlockchainService {
         totalEarned: earnings.totalEarned,
         totalSessions: earnings.totalSessions,
         totalChunksSold: earnings.totalChunksSold,
+        payoutAddress: earnings.payoutAddress as PublicKey,
       };
     } catch (error) {
       console.error("Error fetching creator earnings:", error);
@@ -60,7 +60,11 @@ export async function createVideo(
     totalChunks: number;
     pricePerChunk: BN;
     title: string;
-    description: string;
+    metadataUri?: string; // Off-chain metadata JSON (description etc.)
+    metadataHash?: number[]; // sha256 of the metadata JSON (default: none)
+    chunkMerkleRoot?: number[]; // sha256 manifest commitment (default: none)
+    thumbnailCid?: string; // IPFS CID of the thumbnail image (default: none)
+    posterCid?: string; // IPFS CID of the poster image (default: none)
     creator: PublicKey;
   }
 ) {
@@ -75,7 +79,11 @@ export async function createVideo(
       params.totalChunks,
       params.pricePerChunk,
       params.title,
-      params.description
+      params.metadataUri ?? "",
+      params.metadataHash ?? Array(32).fill(0),
+      params.chunkMerkleRoot ?? Array(32).fill(0),
+      params.thumbnailCid ?? "",
+      params.posterCid ?? ""
     )
     .accounts({
       video: videoPda,
@@ -96,6 +104,9 @@ export async function updateVideo(
     videoId: string;
     pricePerChunk?: BN;
     isActive?: boolean;
+    thumbnailCid?: string; // Empty string clears the thumbnail
+    posterCid?: string; // Empty string clears the poster
+    ipfsHash?: string; // Replacement content (only before the first sale)
     creator: PublicKey;
   }
 ) {
@@ -103,7 +114,13 @@ export async function updateVideo(
   const [platformPda] = derivePlatformPda();
 
   return await program.methods
-    .updateVideo(params.pricePerChunk || null, params.isActive ?? null)
+    .updateVideo(
+      params.pricePerChunk || null,
+      params.isActive ?? null,
+      params.thumbnailCid ?? null,
+      params.posterCid ?? null,
+      params.ipfsHash ?? null
+    )
     .accounts({
       video: videoPda,
       platform: platformPda,
@@ -124,6 +141,10 @@ export async function approveStreamingDelegate(
   params: {
     videoId: string;
     maxChunks: number;
+    qualityTier?: number; // Index into the video's quality tiers
+    sessionNonce?: number; // Concurrent session index (default: 0)
+    region?: number[]; // ISO 3166-1 alpha-2 bytes (region-restricted videos)
+    clientTag?: number[]; // 32-byte hashed client app/device identifier
     viewer: PublicKey;
     viewerTokenAccount: PublicKey;
     platformTokenAccount: PublicKey;
@@ -131,12 +152,22 @@ export async function approveStreamingDelegate(
   }
 ) {
   const [videoPda] = deriveVideoPda(params.videoId);
-  const [viewerSessionPda] = deriveViewerSessionPda(params.viewer, videoPda);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
   const [creatorEarningsPda] = deriveCreatorEarningsPda(videoPda);
   const [platformPda] = derivePlatformPda();
 
   return await program.methods
-    .approveStreamingDelegate(params.maxChunks)
+    .approveStreamingDelegate(
+      params.maxChunks,
+      params.qualityTier ?? null,
+      params.sessionNonce ?? 0,
+      params.region ?? null,
+      params.clientTag ?? null
+    )
     .accounts({
       viewerSession: viewerSessionPda,
       video: videoPda,
@@ -152,6 +183,81 @@ export async function approveStreamingDelegate(
     .rpc();
 }
 
+/**
+ * Start streaming in one transaction (one signature): approves the delegation,
+ * opens the session and, when `payFirstChunk` is set, pays for chunk 0 right
+ * away so playback can begin without a second round trip
+ */
+export async function startStreaming(
+  program: any,
+  params: {
+    videoId: string;
+    maxChunks: number;
+    qualityTier?: number; // Index into the video's quality tiers
+    sessionNonce?: number; // Concurrent session index (default: 0)
+    region?: number[]; // ISO 3166-1 alpha-2 bytes (region-restricted videos)
+    clientTag?: number[]; // 32-byte hashed client app/device identifier
+    payFirstChunk?: boolean;
+    viewer: PublicKey;
+    viewerTokenAccount: PublicKey;
+    creatorTokenAccount: PublicKey;
+    platformTokenAccount: PublicKey;
+    tokenMint: PublicKey;
+  }
+) {
+  const [videoPda] = deriveVideoPda(params.videoId);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
+  const [creatorEarningsPda] = deriveCreatorEarningsPda(videoPda);
+  const [platformPda] = derivePlatformPda();
+
+  const payIxs = params.payFirstChunk
+    ? [
+        await program.methods
+          .payForChunk(0)
+          .accounts({
+            viewerSession: viewerSessionPda,
+            video: videoPda,
+            creatorEarnings: creatorEarningsPda,
+            platform: platformPda,
+            viewerTokenAccount: params.viewerTokenAccount,
+            creatorTokenAccount: params.creatorTokenAccount,
+            platformTokenAccount: params.platformTokenAccount,
+            viewer: params.viewer,
+            authority: params.viewer,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .instruction(),
+      ]
+    : [];
+
+  return await program.methods
+    .approveStreamingDelegate(
+      params.maxChunks,
+      params.qualityTier ?? null,
+      params.sessionNonce ?? 0,
+      params.region ?? null,
+      params.clientTag ?? null
+    )
+    .accounts({
+      viewerSession: viewerSessionPda,
+      video: videoPda,
+      creatorEarnings: creatorEarningsPda,
+      platform: platformPda,
+      tokenMint: params.tokenMint,
+      viewerTokenAccount: params.viewerTokenAccount,
+      platformTokenAccount: params.platformTokenAccount,
+      viewer: params.viewer,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: PublicKey.default,
+    })
+    .postInstructions(payIxs)
+    .rpc();
+}
+
 /**
  * Pay for a single chunk (DEPRECATED - use settleSession for batch settlement)
  */
@@ -160,6 +266,8 @@ export async function payForChunk(
   params: {
     videoId: string;
     chunkIndex: number;
+    sessionKey?: PublicKey; // Registered session key signing instead of the viewer
+    sessionNonce?: number; // Concurrent session index (default: 0)
     viewer: PublicKey;
     viewerTokenAccount: PublicKey;
     creatorTokenAccount: PublicKey;
@@ -167,7 +275,11 @@ export async function payForChunk(
   }
 ) {
   const [videoPda] = deriveVideoPda(params.videoId);
-  const [viewerSessionPda] = deriveViewerSessionPda(params.viewer, videoPda);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
   const [creatorEarningsPda] = deriveCreatorEarningsPda(videoPda);
   const [platformPda] = derivePlatformPda();
 
@@ -182,6 +294,7 @@ export async function payForChunk(
       creatorTokenAccount: params.creatorTokenAccount,
       platformTokenAccount: params.platformTokenAccount,
       viewer: params.viewer,
+      authority: params.sessionKey ?? params.viewer,
       tokenProgram: TOKEN_PROGRAM_ID,
     })
     .rpc();
@@ -196,6 +309,10 @@ export async function settleSession(
     videoId: string;
     chunkCount: number;
     settlementTimestamp: BN;
+    secondsWatched?: number; // Per-second billed videos (chunkCount = 0)
+    settlementId?: number[]; // 16-byte idempotency key (default: none)
+    lastWatchedChunk?: number; // Player resume position (default: unchanged)
+    sessionNonce?: number; // Concurrent session index (default: 0)
     viewer: PublicKey;
     platformAuthority: PublicKey; // Platform PDA authority
     viewerTokenAccount: PublicKey;
@@ -204,12 +321,22 @@ export async function settleSession(
   }
 ) {
   const [videoPda] = deriveVideoPda(params.videoId);
-  const [viewerSessionPda] = deriveViewerSessionPda(params.viewer, videoPda);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
   const [creatorEarningsPda] = deriveCreatorEarningsPda(videoPda);
   const [platformPda] = derivePlatformPda();
 
   return await program.methods
-    .settleSession(new BN(params.chunkCount), params.settlementTimestamp)
+    .settleSession(
+      new BN(params.chunkCount),
+      params.settlementTimestamp,
+      params.secondsWatched ?? 0,
+      params.settlementId ?? Array(16).fill(0),
+      params.lastWatchedChunk ?? null
+    )
     .accounts({
       viewer: params.viewer,
       video: videoPda,
@@ -232,12 +359,17 @@ export async function revokeStreamingDelegate(
   program: any,
   params: {
     videoId: string;
+    sessionNonce?: number; // Concurrent session index (default: 0)
     viewer: PublicKey;
     viewerTokenAccount: PublicKey;
   }
 ) {
   const [videoPda] = deriveVideoPda(params.videoId);
-  const [viewerSessionPda] = deriveViewerSessionPda(params.viewer, videoPda);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
   const [platformPda] = derivePlatformPda();
 
   return await program.methods
@@ -254,28 +386,70 @@ export async function revokeStreamingDelegate(
 }
 
 /**
- * Close viewer session (cleanup)
+ * Close viewer session (cleanup). Passing the viewer's token account releases
+ * the unwatched allowance from the streaming delegation.
  */
 export async function closeViewerSession(
   program: any,
   params: {
     videoId: string;
+    sessionNonce?: number; // Concurrent session index (default: 0)
     viewer: PublicKey;
+    viewerTokenAccount?: PublicKey;
   }
 ) {
   const [videoPda] = deriveVideoPda(params.videoId);
-  const [viewerSessionPda] = deriveViewerSessionPda(params.viewer, videoPda);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
+  const [platformPda] = derivePlatformPda();
 
   return await program.methods
     .closeViewerSession()
     .accounts({
       viewerSession: viewerSessionPda,
       video: videoPda,
+      platform: params.viewerTokenAccount ? platformPda : null,
+      viewerTokenAccount: params.viewerTokenAccount ?? null,
       viewer: params.viewer,
+      tokenProgram: params.viewerTokenAccount ? TOKEN_PROGRAM_ID : null,
     })
     .rpc();
 }
 
+/**
+ * Session health check (simulated, nothing is sent): remaining approval,
+ * actual SPL delegation and expiry countdowns, as the program derives them
+ */
+export async function getSessionStatus(
+  program: any,
+  params: {
+    videoId: string;
+    sessionNonce?: number; // Concurrent session index (default: 0)
+    viewer: PublicKey;
+    viewerTokenAccount: PublicKey;
+  }
+) {
+  const [videoPda] = deriveVideoPda(params.videoId);
+  const [viewerSessionPda] = deriveViewerSessionPda(
+    params.viewer,
+    videoPda,
+    params.sessionNonce
+  );
+  const [platformPda] = derivePlatformPda();
+
+  return await program.methods
+    .getSessionStatus()
+    .accounts({
+      viewerSession: viewerSessionPda,
+      platform: platformPda,
+      viewerTokenAccount: params.viewerTokenAccount,
+    })
+    .view();
+}
+
 // =============================================================================
 // Export all instruction wrappers
 // =============================================================================
@@ -285,10 +459,12 @@ export const instructions = {
   createVideo,
   updateVideo,
   approveStreamingDelegate,
+  startStreaming,
   payForChunk, // Deprecated
   settleSession, // NEW: Batch settlement
   revokeStreamingDelegate,
   closeViewerSession,
+  getSessionStatus,
 };
 
 export default instructions;
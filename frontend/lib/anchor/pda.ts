@@ -35,16 +35,23 @@ export function deriveVideoPda(videoId: string): [PublicKey, number] {
 
 /**
  * Derive ViewerSession PDA
- * Seeds: [b"viewer_session", viewer_pubkey, video_pda]
+ * Seeds: [b"viewer_session", viewer_pubkey, video_pda, session_nonce]
  * @param viewerPubkey - Viewer's wallet public key
  * @param videoPda - Video PDA
+ * @param sessionNonce - Index of the viewer's concurrent session (default: 0)
  */
 export function deriveViewerSessionPda(
   viewerPubkey: PublicKey,
-  videoPda: PublicKey
+  videoPda: PublicKey,
+  sessionNonce = 0
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [VIEWER_SESSION_SEED, viewerPubkey.toBuffer(), videoPda.toBuffer()],
+    [
+      VIEWER_SESSION_SEED,
+      viewerPubkey.toBuffer(),
+      videoPda.toBuffer(),
+      Buffer.from([sessionNonce]),
+    ],
     PROGRAM_ID
   );
 }
@@ -0,0 +1,82 @@
+// =============================================================================
+// CCTP - USDC bridged with Circle's Cross-Chain Transfer Protocol
+// =============================================================================
+// A depositor burns USDC on the source chain with a session escrow as the
+// mint recipient. With Circle's attestation, the message is received on
+// Solana through the MessageTransmitter program, which (via the
+// TokenMessengerMinter) mints the USDC straight into the escrow. We call
+// receive_message ourselves so the mint and the session credit happen
+// atomically. The CCTP accounts are forwarded from our remaining accounts in
+// the order receive_message expects; no Circle SDK dependency is needed.
+// =============================================================================
+
+use crate::errors::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::pubkey;
+
+pub const MESSAGE_TRANSMITTER_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPmbSD7gX1bxKPAmg77w8oFzNFpaQiQUWD43TKaecd");
+pub const SOLANA_CCTP_DOMAIN: u32 = 5;
+
+// Message header: version, source domain, destination domain, nonce, sender,
+// recipient, destination caller; then the burn message body: version, burn
+// token, mint recipient, amount, message sender (all big-endian)
+const DESTINATION_DOMAIN_OFFSET: usize = 8;
+const MESSAGE_BODY_OFFSET: usize = 116;
+const MINT_RECIPIENT_OFFSET: usize = MESSAGE_BODY_OFFSET + 36;
+const BURN_MESSAGE_LEN: usize = MESSAGE_BODY_OFFSET + 164;
+
+/// Check that a CCTP message is a burn destined for Solana that mints to `mint_recipient`.
+pub fn check_burn_message(message: &[u8], mint_recipient: &Pubkey) -> Result<()> {
+    require!(
+        message.len() >= BURN_MESSAGE_LEN,
+        StreamingError::InvalidCctpMessage
+    );
+
+    let mut destination_domain = [0u8; 4];
+    destination_domain
+        .copy_from_slice(&message[DESTINATION_DOMAIN_OFFSET..DESTINATION_DOMAIN_OFFSET + 4]);
+    require!(
+        u32::from_be_bytes(destination_domain) == SOLANA_CCTP_DOMAIN
+            && message[MINT_RECIPIENT_OFFSET..MINT_RECIPIENT_OFFSET + 32]
+                == mint_recipient.to_bytes(),
+        StreamingError::InvalidCctpMessage
+    );
+
+    Ok(())
+}
+
+/// Receive an attested CCTP message through the MessageTransmitter, forwarding
+/// `cctp_accounts` (payer, caller, ..., token messenger accounts) as is.
+pub fn receive_message<'info>(
+    message_transmitter: &AccountInfo<'info>,
+    cctp_accounts: &[AccountInfo<'info>],
+    message: Vec<u8>,
+    attestation: Vec<u8>,
+) -> Result<()> {
+    let mut data = hash(b"global:receive_message").to_bytes()[..8].to_vec();
+    (message, attestation).serialize(&mut data)?;
+
+    let accounts = cctp_accounts
+        .iter()
+        .map(|account| {
+            if account.is_writable {
+                AccountMeta::new(account.key(), account.is_signer)
+            } else {
+                AccountMeta::new_readonly(account.key(), account.is_signer)
+            }
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: message_transmitter.key(),
+        accounts,
+        data,
+    };
+
+    let mut account_infos = cctp_accounts.to_vec();
+    account_infos.push(message_transmitter.clone());
+    invoke(&ix, &account_infos).map_err(Into::into)
+}
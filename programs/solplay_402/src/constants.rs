@@ -7,24 +7,141 @@ pub const PLATFORM_SEED: &[u8] = b"platform";
 pub const VIDEO_SEED: &[u8] = b"video";
 pub const VIEWER_SESSION_SEED: &[u8] = b"viewer_session";
 pub const CREATOR_EARNINGS_SEED: &[u8] = b"creator_earnings";
+pub const QUALITY_REPORT_SEED: &[u8] = b"quality_report";
+pub const CREATOR_TIER_SEED: &[u8] = b"creator_tiers";
+pub const PENDING_SETTLEMENT_SEED: &[u8] = b"pending_settlement";
+pub const COMMON_ACCOUNTS_SEED: &[u8] = b"common_accounts";
+pub const FEE_RECIPIENTS_SEED: &[u8] = b"fee_recipients";
+pub const COUPON_SEED: &[u8] = b"coupon";
+pub const ALLOWED_VIEWER_SEED: &[u8] = b"allowed_viewer";
+pub const SESSION_ESCROW_SEED: &[u8] = b"session_escrow";
+pub const GIFT_CARD_SEED: &[u8] = b"gift_card";
+pub const GIFT_CARD_VAULT_SEED: &[u8] = b"gift_card_vault";
+pub const REWARDS_POOL_SEED: &[u8] = b"rewards_pool";
+pub const REWARDS_VAULT_SEED: &[u8] = b"rewards_vault";
+pub const VIEWER_REWARDS_SEED: &[u8] = b"viewer_rewards";
+pub const VIEWER_PROFILE_SEED: &[u8] = b"viewer_profile";
+pub const BADGE_MINT_SEED: &[u8] = b"badge_mint";
+pub const AD_CAMPAIGN_SEED: &[u8] = b"ad_campaign";
+pub const AD_CAMPAIGN_VAULT_SEED: &[u8] = b"ad_campaign_vault";
+pub const PROMOTION_SEED: &[u8] = b"promotion";
+pub const PROMOTION_VAULT_SEED: &[u8] = b"promotion_vault";
+pub const FUNDING_CAMPAIGN_SEED: &[u8] = b"funding_campaign";
+pub const FUNDING_VAULT_SEED: &[u8] = b"funding_vault";
+pub const PLEDGE_SEED: &[u8] = b"pledge";
+pub const VIDEO_RENDITIONS_SEED: &[u8] = b"video_renditions";
+pub const DELIVERY_CHALLENGE_SEED: &[u8] = b"delivery_challenge";
+pub const DISPUTE_SEED: &[u8] = b"dispute";
+pub const DISPUTE_RESERVE_SEED: &[u8] = b"dispute_reserve";
+pub const OPERATOR_BOND_SEED: &[u8] = b"operator_bond";
+pub const OPERATOR_BOND_VAULT_SEED: &[u8] = b"operator_bond_vault";
+pub const PAYOUT_VAULT_SEED: &[u8] = b"payout_vault";
+pub const MERKLE_BATCH_SEED: &[u8] = b"merkle_batch";
+pub const BATCH_LEAF_DISPUTE_SEED: &[u8] = b"batch_leaf_dispute";
+pub const EARNINGS_BUCKET_SEED: &[u8] = b"earnings_bucket";
+pub const PLATFORM_STATS_SEED: &[u8] = b"platform_stats";
+pub const VIEWED_MARKER_SEED: &[u8] = b"viewed";
+pub const WATCH_HISTORY_SEED: &[u8] = b"watch_history";
+pub const SPONSORSHIP_VAULT_SEED: &[u8] = b"sponsorship_vault";
+pub const CROSS_CHAIN_EMITTER_SEED: &[u8] = b"cross_chain_emitter";
+pub const CROSS_CHAIN_VAULT_SEED: &[u8] = b"cross_chain_vault";
+pub const CROSS_CHAIN_RECEIPT_SEED: &[u8] = b"cross_chain_receipt";
+pub const X402_PROOF_SEED: &[u8] = b"x402_proof";
+pub const BANNED_VIEWER_SEED: &[u8] = b"banned_viewer";
+pub const SUSPENDED_CREATOR_SEED: &[u8] = b"suspended_creator";
+pub const TRIAL_VAULT_SEED: &[u8] = b"trial_vault";
+pub const TRIAL_MARKER_SEED: &[u8] = b"trial";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+pub const CREATOR_TEAM_SEED: &[u8] = b"creator_team";
+pub const CREATOR_PROFILE_SEED: &[u8] = b"creator_profile";
+pub const CREATOR_STAKE_SEED: &[u8] = b"creator_stake";
+pub const CREATOR_STAKE_VAULT_SEED: &[u8] = b"creator_stake_vault";
+pub const EMISSION_SCHEDULE_SEED: &[u8] = b"emission_schedule";
+pub const EMISSION_VAULT_SEED: &[u8] = b"emission_vault";
+pub const EMISSION_BALANCE_SEED: &[u8] = b"emission_balance";
+pub const MATCHING_ROUND_SEED: &[u8] = b"matching_round";
+pub const MATCHING_VAULT_SEED: &[u8] = b"matching_vault";
+pub const MATCHING_ENTRY_SEED: &[u8] = b"matching_entry";
+pub const MATCHING_SUPPORT_SEED: &[u8] = b"matching_support";
+pub const SUBTITLE_BOUNTY_SEED: &[u8] = b"subtitle_bounty";
+pub const SUBTITLE_BOUNTY_VAULT_SEED: &[u8] = b"subtitle_bounty_vault";
+pub const VIDEO_TRACKS_SEED: &[u8] = b"video_tracks";
+pub const AGE_ATTESTATION_SEED: &[u8] = b"age_attestation";
+pub const ARCHIVED_VIDEO_SEED: &[u8] = b"archived_video";
+pub const ORGANIZATION_SEED: &[u8] = b"organization";
+pub const ORGANIZATION_TREASURY_SEED: &[u8] = b"organization_treasury";
+pub const CHANNEL_SESSION_SEED: &[u8] = b"channel_session";
+
+// Domain prefixes of off-chain signed messages
+pub const SIGNED_APPROVAL_DOMAIN: &[u8] = b"solplay_402:approve";
+pub const SETTLEMENT_VOUCHER_DOMAIN: &[u8] = b"solplay_402:settle";
+pub const RENEWAL_CONSENT_DOMAIN: &[u8] = b"solplay_402:renew";
+pub const X402_PAYMENT_DOMAIN: &[u8] = b"solplay_402:x402";
+pub const CONTENT_KEY_DOMAIN: &[u8] = b"solplay_402:key";
+pub const GIFT_CARD_REDEEM_DOMAIN: &[u8] = b"solplay_402:redeem";
+pub const WATERMARK_ID_DOMAIN: &[u8] = b"solplay_402:watermark";
 
 // Limits and constraints
 // These limits protect against event serialization bloat and tx/log size failures
 pub const MAX_VIDEO_ID_LENGTH: usize = 64; // Video identifier max length
 pub const MAX_IPFS_HASH_LENGTH: usize = 128; // IPFS CID max length (CIDv0=46, CIDv1=59, buffer for future)
 pub const MAX_TITLE_LENGTH: usize = 200; // Video title max length
-pub const MAX_DESCRIPTION_LENGTH: usize = 1000; // Video description max length (not in events)
+pub const MAX_DESCRIPTION_LENGTH: usize = 1000; // Legacy on-chain description max length (pre-metadata_uri)
+pub const MAX_METADATA_URI_LENGTH: usize = 200; // Off-chain metadata (description etc.) URI max length
 pub const MAX_CHUNKS_PER_APPROVAL: u32 = 1000; // Max chunks per single approval
+pub const MAX_SECONDS_PER_APPROVAL: u32 = 14_400; // Max seconds per single approval (4 hours, per-second billing)
 pub const MAX_TOTAL_CHUNKS: u32 = 10000; // Max chunks per video
+pub const MAX_VIDEO_BATCH: usize = 10; // Max videos per create_videos_batch
+pub const LIVE_CHUNK_LOOKAHEAD: u32 = 10; // Chunks a live stream may be paid ahead of append_chunks
+pub const MAX_CREATOR_TIERS: usize = 5; // Max volume fee tiers
+pub const MAX_QUALITY_TIERS: usize = 4; // Max per-resolution price tiers per video
+pub const MAX_PRICE_BREAKS: usize = 4; // Max brackets in a video's pricing curve
+pub const MAX_PRICE_MULTIPLIER_BPS: u16 = 50_000; // Highest pricing curve multiplier (5x)
+pub const MAX_DISCOUNT_WINDOWS: usize = 4; // Max recurring happy-hour windows per video
+pub const MAX_COMPLETION_REBATE_BPS: u16 = 5_000; // Highest completion rebate (50% back)
+pub const MAX_QUALITY_LABEL_LENGTH: usize = 8; // Quality tier label max length (e.g. "1080p")
+pub const MAX_LANGUAGE_LENGTH: usize = 16; // BCP 47 language tag max length (e.g. "pt-BR")
+pub const MAX_RENDITIONS: usize = 4; // Max HLS renditions per video
+pub const MAX_VIDEO_TRACKS: usize = 8; // Max subtitle and alternate audio tracks per video
+pub const MAX_POLICY_REGIONS: usize = 16; // Max region codes in a video's region policy
+pub const SETTLEMENT_ID_HISTORY: usize = 8; // Recent settlement idempotency keys kept per session
+pub const MAX_MERKLE_PROOF_DEPTH: usize = 14; // Merkle proof depth (2^14 > MAX_TOTAL_CHUNKS; caps batch leaves)
+pub const SECONDS_PER_DAY: i64 = 86_400; // Earnings bucket granularity
+pub const SECONDS_PER_HOUR: i64 = 3_600; // Refund policy window granularity
+pub const WATCH_HISTORY_LENGTH: usize = 10; // Recent videos kept in a viewer's watch history
+pub const MAX_FEE_RECIPIENTS: usize = 5; // Max platform fee split recipients
+pub const MAX_AD_CAMPAIGN_CREATORS: usize = 8; // Max creators an ad campaign may subsidize
+pub const MAX_TEAM_MEMBERS: usize = 8; // Max delegated keys on a creator team
+pub const MAX_ORGANIZATION_SEATS: usize = 64; // Max member wallets funded by one organization
+pub const AUTO_RENEW_THRESHOLD_PERCENT: u64 = 10; // Renew once remaining approval drops to this share of renewal_chunks
+pub const MAX_QUALITY_REPORTS_PER_SESSION: u8 = 10; // Caps report spam per session
+pub const BADGE_CHUNK_THRESHOLDS: [u64; 4] = [100, 1_000, 10_000, 100_000]; // Lifetime chunks per badge level
 
 // Time constants (in seconds)
 pub const SESSION_EXPIRY_DURATION: i64 = 24 * 60 * 60; // 24 hours
 pub const SESSION_INACTIVITY_DURATION: i64 = 60 * 60; // 1 hour
+pub const SIGNED_APPROVAL_MAX_VALIDITY: i64 = 30 * 24 * 60 * 60; // Furthest out a signed approval's valid_until may be (30 days)
+pub const APPROVAL_RATE_WINDOW: i64 = 60 * 60; // Per-viewer approval rate-limit window (1 hour)
+pub const DEFAULT_FEE_CHANGE_DELAY: i64 = 7 * 24 * 60 * 60; // 7 days notice before a fee change applies
+pub const MAX_DISPUTE_WINDOW: i64 = 30 * 24 * 60 * 60; // Longest configurable settlement dispute window
+pub const MAX_PAYOUT_MATURATION_PERIOD: i64 = 30 * 24 * 60 * 60; // Longest configurable creator payout maturation
+pub const MIN_BOOST_LOCK_DURATION: i64 = 7 * 24 * 60 * 60; // Shortest creator stake lock
+pub const MAX_BOOST_LOCK_DURATION: i64 = 365 * 24 * 60 * 60; // Lock earning full stake weight
 
 // Fee constants
 pub const BASIS_POINTS: u64 = 10000; // 100.00% = 10000 basis points
 pub const MAX_PLATFORM_FEE_BPS: u64 = 1000; // Max 10% platform fee
 pub const DEFAULT_PLATFORM_FEE_BPS: u64 = 250; // Default 2.5% platform fee
+pub const MAX_BOOST_FEE_DISCOUNT_BPS: u16 = 100; // Largest fee discount creator staking can earn
 
 // Minimum pricing
 pub const MIN_PRICE_PER_CHUNK: u64 = 1000; // 0.001 USDC (assuming 6 decimals)
+
+// Content flags (bitfield on Video::content_flags)
+pub const CONTENT_FLAG_VIOLENCE: u16 = 1 << 0;
+pub const CONTENT_FLAG_LANGUAGE: u16 = 1 << 1;
+pub const CONTENT_FLAG_NUDITY: u16 = 1 << 2;
+pub const CONTENT_FLAG_DRUGS: u16 = 1 << 3;
+pub const CONTENT_FLAG_GAMBLING: u16 = 1 << 4;
+pub const CONTENT_FLAG_FLASHING_LIGHTS: u16 = 1 << 5;
+pub const CONTENT_FLAGS_MASK: u16 = (1 << 6) - 1;
@@ -101,4 +101,447 @@ pub enum StreamingError {
 
     #[msg("Settlement timestamp is in the future")]
     SettlementInFuture,
+
+    #[msg("No pending platform fee change")]
+    NoPendingFeeChange,
+
+    #[msg("Platform fee change timelock has not elapsed")]
+    FeeChangeTimelockActive,
+
+    #[msg("Reported chunk range is invalid or includes unpaid chunks")]
+    InvalidChunkRange,
+
+    #[msg("Maximum quality reports for this session reached")]
+    TooManyQualityReports,
+
+    #[msg("Quality report has already been verified")]
+    QualityReportAlreadyVerified,
+
+    #[msg("Basis points value exceeds 100%")]
+    InvalidBasisPoints,
+
+    #[msg("Too many creator fee tiers")]
+    TooManyCreatorTiers,
+
+    #[msg("Creator tiers must have ascending thresholds and fees within the platform maximum")]
+    InvalidCreatorTiers,
+
+    #[msg("Creator tier config account is required when tiers are enabled")]
+    CreatorTierConfigRequired,
+
+    #[msg("Session changed since the settlement was prepared")]
+    StaleSettlement,
+
+    #[msg("Fee recipient weights must be non-zero and sum to 100%")]
+    InvalidFeeRecipients,
+
+    #[msg("Fee recipient config account is required when fee splitting is enabled")]
+    FeeRecipientConfigRequired,
+
+    #[msg("Fee recipient accounts do not match the configured recipients")]
+    FeeRecipientAccountMismatch,
+
+    #[msg("Token mint account is required when platform fees are burned")]
+    TokenMintRequiredForBurn,
+
+    #[msg("Coupon parameters are invalid")]
+    InvalidCoupon,
+
+    #[msg("Coupon is expired or fully redeemed")]
+    CouponNotRedeemable,
+
+    #[msg("Coupon does not apply to this video")]
+    CouponNotApplicable,
+
+    #[msg("Gated price must not exceed the video price")]
+    InvalidGatedPrice,
+
+    #[msg("NFT ownership proof could not be verified for this video's gate")]
+    NftGateVerificationFailed,
+
+    #[msg("Viewer is not on this private video's allowlist")]
+    ViewerNotAllowed,
+
+    #[msg("Gifted sessions are paid from the session escrow, which must be provided")]
+    SessionEscrowRequired,
+
+    #[msg("Gifted sessions cannot be extended through delegation")]
+    GiftedSessionNotExtendable,
+
+    #[msg("Cannot gift a session to yourself")]
+    InvalidGiftRecipient,
+
+    #[msg("Gift card amount must be greater than zero")]
+    InvalidGiftCardAmount,
+
+    #[msg("Redemption code does not match the gift card")]
+    InvalidGiftCardCode,
+
+    #[msg("No rewards available to claim")]
+    NoRewardsToClaim,
+
+    #[msg("Invalid badge level")]
+    InvalidBadgeLevel,
+
+    #[msg("Viewer has not reached the milestone for this badge")]
+    BadgeMilestoneNotReached,
+
+    #[msg("Video is not opted into ad-supported viewing")]
+    VideoNotAdSupported,
+
+    #[msg("Invalid ad campaign parameters")]
+    InvalidAdCampaign,
+
+    #[msg("Ad campaign vault does not match the campaign")]
+    AdCampaignVaultMismatch,
+
+    #[msg("Invalid promotion parameters")]
+    InvalidPromotion,
+
+    #[msg("Promotion is not active")]
+    PromotionNotActive,
+
+    #[msg("Invalid funding campaign parameters")]
+    InvalidFundingCampaign,
+
+    #[msg("Funding campaign is closed to new pledges")]
+    FundingCampaignClosed,
+
+    #[msg("Funding campaign has not succeeded")]
+    FundingCampaignNotSuccessful,
+
+    #[msg("Pledges cannot be refunded for this campaign yet")]
+    RefundNotAvailable,
+
+    #[msg("Video has not been published yet")]
+    VideoNotYetPublished,
+
+    #[msg("Video is no longer published")]
+    VideoUnpublished,
+
+    #[msg("Unpublish time must be after the publish time")]
+    InvalidPublishWindow,
+
+    #[msg("Video is not a live stream")]
+    NotLiveStream,
+
+    #[msg("Live stream has already been finalized")]
+    LiveStreamFinalized,
+
+    #[msg("Settlement units do not match the session's billing mode")]
+    BillingModeMismatch,
+
+    #[msg("Per-second billing requires a non-zero price per second")]
+    InvalidPricePerSecond,
+
+    #[msg("Too many quality tiers or invalid tier label/price")]
+    InvalidQualityTiers,
+
+    #[msg("Quality tier does not exist or does not match the session")]
+    InvalidQualityTier,
+
+    #[msg("Too many renditions or invalid rendition label/CID/chunk count")]
+    InvalidRenditions,
+
+    #[msg("Video has no chunk manifest commitment")]
+    NoChunkManifest,
+
+    #[msg("Chunk proof does not match the video's manifest")]
+    InvalidChunkProof,
+
+    #[msg("Received chunk matches the manifest - nothing to challenge")]
+    ChunkHashMatches,
+
+    #[msg("Settlement disputes are disabled")]
+    DisputesDisabled,
+
+    #[msg("Dispute window for this settlement has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Invalid dispute: unknown settlement or too many disputed chunks")]
+    InvalidDispute,
+
+    #[msg("Dispute has already been resolved")]
+    DisputeAlreadyResolved,
+
+    #[msg("Dispute window exceeds the maximum allowed")]
+    InvalidDisputeWindow,
+
+    #[msg("Settlement requires a bonded operator")]
+    OperatorBondRequired,
+
+    #[msg("Operator bond is below the platform minimum")]
+    InsufficientOperatorBond,
+
+    #[msg("Operator bond is locked until the dispute window passes")]
+    OperatorBondLocked,
+
+    #[msg("Operator bond does not belong to this operator")]
+    OperatorBondMismatch,
+
+    #[msg("Settlement with this id was already applied")]
+    DuplicateSettlement,
+
+    #[msg("Payout vault is required when settlement netting is enabled")]
+    PayoutVaultRequired,
+
+    #[msg("Netting does not support fee burning or fee splitting")]
+    NettingUnsupported,
+
+    #[msg("Invalid payout accounts")]
+    InvalidPayoutAccounts,
+
+    #[msg("No netted earnings to claim")]
+    NoPayoutToClaim,
+
+    #[msg("Creator token account is required unless settlement netting is enabled")]
+    CreatorTokenAccountRequired,
+
+    #[msg("Invalid Merkle batch")]
+    InvalidMerkleBatch,
+
+    #[msg("Batch leaf proof does not match the batch root")]
+    InvalidBatchLeafProof,
+
+    #[msg("Merkle batches do not support fee burning or fee splitting")]
+    BatchFeeRoutingUnsupported,
+
+    #[msg("Metadata URI exceeds maximum length")]
+    MetadataUriTooLong,
+
+    #[msg("Video account is not in the legacy layout")]
+    NotLegacyVideo,
+
+    #[msg("Invalid invariant audit accounts")]
+    InvalidAuditAccounts,
+
+    #[msg("Earnings bucket is not for the current day")]
+    EarningsBucketDayMismatch,
+
+    #[msg("Session must be transferred to a different wallet")]
+    InvalidSessionTransfer,
+
+    #[msg("Gifted or disputed sessions cannot be transferred")]
+    SessionNotTransferable,
+
+    #[msg("Invalid auto-renew settings")]
+    InvalidRenewalBudget,
+
+    #[msg("Auto-renew is not enabled for this session")]
+    AutoRenewNotEnabled,
+
+    #[msg("Approval is not nearly exhausted or the renewal budget is spent")]
+    RenewalNotDue,
+
+    #[msg("Signed message was not verified by a matching signature instruction")]
+    InvalidSignedMessage,
+
+    #[msg("Signed approval is past its valid_until or valid_until is too far out")]
+    SignedApprovalExpired,
+
+    #[msg("Sponsored sessions must return their rent to the sponsorship vault")]
+    SponsorshipVaultRequired,
+
+    #[msg("Session key must differ from the viewer wallet")]
+    InvalidSessionKey,
+
+    #[msg("Settlement needs the viewer's signature or a valid EVM voucher")]
+    ViewerConsentRequired,
+
+    #[msg("No passkey is registered for this session")]
+    PasskeyNotRegistered,
+
+    #[msg("Invalid or unverified Wormhole VAA")]
+    InvalidVaa,
+
+    #[msg("Cross-chain payment does not match this session")]
+    CrossChainPaymentMismatch,
+
+    #[msg("Cross-chain emitter is disabled")]
+    CrossChainEmitterDisabled,
+
+    #[msg("CCTP message must be a burn to Solana minting into this session's escrow")]
+    InvalidCctpMessage,
+
+    #[msg("No USDC was minted into the session escrow")]
+    CctpMintNotReceived,
+
+    #[msg("Only the viewer's own prepaid sessions can be topped up")]
+    PrepaidSessionMismatch,
+
+    #[msg("Invalid x402 payment proof")]
+    InvalidX402Proof,
+
+    #[msg("x402 payment proof already settled")]
+    X402ProofAlreadySettled,
+
+    #[msg("Settlement exceeds the amount of its x402 payment proof")]
+    X402ProofAmountExceeded,
+
+    #[msg("Chunk is neither paid for nor covered by the session approval")]
+    ChunkNotPaid,
+
+    #[msg("Viewer is banned from the platform")]
+    ViewerBanned,
+
+    #[msg("Creator is suspended pending review")]
+    CreatorSuspended,
+
+    #[msg("Too many approvals from this viewer; try again later")]
+    ApprovalRateLimited,
+
+    #[msg("Viewer profile required while approvals are rate limited")]
+    ViewerProfileRequired,
+
+    #[msg("Trials are disabled or the video costs more than the trial spend cap")]
+    TrialUnavailable,
+
+    #[msg("Daily free chunk allowance exhausted")]
+    FreeChunkAllowanceExceeded,
+
+    #[msg("Insurance fund account required while the platform insures fees")]
+    InsuranceFundRequired,
+
+    #[msg("Too many team members, or a member is listed twice")]
+    InvalidTeamMembers,
+
+    #[msg("Creator suspension marker missing or mismatched")]
+    InvalidSuspensionMarker,
+
+    #[msg("Creator is not verified")]
+    CreatorNotVerified,
+
+    #[msg("Lock duration outside the allowed range")]
+    InvalidLockDuration,
+
+    #[msg("Stake is still locked")]
+    StakeLocked,
+
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+
+    #[msg("Invalid matching round parameters")]
+    InvalidMatchingRound,
+    #[msg("Matching round is not open")]
+    MatchingRoundNotOpen,
+    #[msg("Matching round has not ended")]
+    MatchingRoundNotEnded,
+    #[msg("Match already distributed")]
+    MatchAlreadyDistributed,
+    #[msg("Session has no paid activity during the matching round")]
+    NoSupportInRound,
+
+    #[msg("Invalid bounty parameters")]
+    InvalidBounty,
+
+    #[msg("Invalid track or too many tracks")]
+    InvalidTrack,
+    #[msg("Track not found")]
+    TrackNotFound,
+
+    #[msg("Duration and chunk duration must both be set or both be zero")]
+    InvalidVideoFormat,
+
+    #[msg("Invalid content flags")]
+    InvalidContentFlags,
+    #[msg("Viewer needs an age attestation to watch this video")]
+    AgeAttestationRequired,
+
+    #[msg("Region-restricted video requires a region attested by the platform")]
+    RegionAttestationRequired,
+    #[msg("Video is not licensed in the attested region")]
+    RegionNotPermitted,
+    #[msg("Invalid region policy")]
+    InvalidRegionPolicy,
+
+    #[msg("Video content is locked and cannot be changed")]
+    VideoImmutable,
+    #[msg("Live streams must be finalized before their metadata can be locked")]
+    CannotLockLiveStream,
+
+    #[msg("Content can't be replaced after chunks have been sold")]
+    ContentAlreadySold,
+
+    #[msg("Video must be deactivated and not live before archiving")]
+    VideoNotArchivable,
+
+    #[msg("Video batch is empty, too large or its accounts do not match")]
+    InvalidVideoBatch,
+
+    #[msg("Invalid pricing curve")]
+    InvalidPriceCurve,
+
+    #[msg("Invalid discount windows")]
+    InvalidDiscountWindows,
+
+    #[msg("Price per chunk above platform maximum")]
+    PriceTooHigh,
+
+    #[msg("Settlement interval must be between 0 and 1 hour")]
+    InvalidSettlementInterval,
+
+    #[msg("Session was settled too recently")]
+    SettlementTooFrequent,
+
+    #[msg("Completion rebate exceeds the maximum")]
+    InvalidCompletionRebate,
+
+    #[msg("Refund exceeds the creator's unclaimed earnings")]
+    RefundExceedsEarnings,
+
+    #[msg("Payout maturation period is out of range")]
+    InvalidMaturationPeriod,
+
+    #[msg("Refund policy window must be at least one hour")]
+    InvalidRefundPolicy,
+
+    #[msg("This video's refund policy offers no refunds")]
+    RefundsNotOffered,
+
+    #[msg("Settlements of this video must be co-signed by an operator and the viewer")]
+    SettlementCosignRequired,
+
+    #[msg("Settlement exceeds the platform's per-settlement chunk cap")]
+    SettlementTooLarge,
+
+    #[msg("Session has reached its hourly settlement value cap")]
+    SettlementRateLimited,
+
+    #[msg("Streaming delegation is missing or too small; re-approve to continue")]
+    DelegationMissing,
+
+    #[msg("Session has a prepared settlement that must be executed or cancelled first")]
+    UnsettledConsumption,
+
+    #[msg("Session is still active; extend it with approve_streaming_delegate")]
+    SessionStillActive,
+
+    #[msg("Organization seats must be between 1 and the maximum")]
+    InvalidSeatCount,
+
+    #[msg("Every organization seat is taken")]
+    OrganizationSeatsFull,
+
+    #[msg("Wallet is already a member of the organization")]
+    AlreadyOrganizationMember,
+
+    #[msg("Wallet is not a member of the organization")]
+    NotOrganizationMember,
+
+    #[msg("Video does not belong to the channel session's creator")]
+    ChannelMismatch,
+
+    #[msg(
+        "Video needs a per-video session (private, age-gated, region-restricted or not per-chunk)"
+    )]
+    ChannelVideoNotEligible,
+
+    #[msg("Channel session budget and price cap must be positive")]
+    InvalidChannelBudget,
+
+    #[msg("Ad campaign does not subsidize this video's creator")]
+    AdCampaignCreatorNotTargeted,
+
+    #[msg("Trial sessions settle only when co-signed by the viewer and a non-creator operator")]
+    TrialSettlementCosignRequired,
 }
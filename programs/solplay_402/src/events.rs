@@ -12,6 +12,10 @@
 // - title: 200 chars
 // =============================================================================
 
+use crate::state::{
+    BillingMode, ContentRating, DiscountWindow, DisputeStatus, InvariantKind, MediaFormat,
+    PasskeyAction, PriceBreak, QualityIssueCode, RefundPolicy, RegionPolicy, TrackKind,
+};
 use anchor_lang::prelude::*;
 
 #[event]
@@ -47,6 +51,9 @@ pub struct VideoUpdated {
     pub creator: Pubkey,
     pub price_per_chunk: Option<u64>,
     pub is_active: Option<bool>,
+    pub thumbnail_cid: Option<String>,
+    pub poster_cid: Option<String>,
+    pub ipfs_hash: Option<String>, // Replacement content hash (pre-sale only)
     pub timestamp: i64,
 }
 
@@ -57,6 +64,7 @@ pub struct DelegationApproved {
     pub viewer_session: Pubkey,
     pub max_approved_chunks: u32,
     pub locked_price_per_chunk: u64,
+    pub quality_tier: Option<u8>,
     pub is_reapproval: bool,
     pub timestamp: i64,
 }
@@ -71,7 +79,12 @@ pub struct ChunkPaid {
     pub amount_paid: u64,
     pub platform_fee: u64,
     pub creator_amount: u64,
+    pub penalty_withheld: u64, // Quality refund recovered from the creator share
+    pub fee_burned: u64,       // Portion of platform_fee burned
+    pub completion_rebate: u64, // Rebate netted off the payment of the video's last chunk
     pub chunks_remaining: u32,
+    pub watermark_id: [u8; 16], // Session's forensic watermark
+    pub client_tag: [u8; 32],   // Client app/device tag of the session (zero = untagged)
     pub timestamp: i64,
 }
 
@@ -92,6 +105,20 @@ pub struct SessionClosed {
     pub viewer_session: Pubkey,
     pub chunks_consumed: u32,
     pub total_spent: u64,
+    pub escrow_refunded: u64, // Unspent gift escrow returned to the gifter
+    pub timestamp: i64,
+}
+
+// Event emitted when closing a session returns its unwatched value: escrow
+// tokens refunded to the gifter, or allowance released from the viewer's
+// delegation
+#[event]
+pub struct RefundIssued {
+    pub viewer_session: Pubkey,
+    pub video: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub from_escrow: bool,
     pub timestamp: i64,
 }
 
@@ -117,8 +144,1170 @@ pub struct SessionSettled {
     pub total_payment: u64,        // Total tokens paid (before split)
     pub platform_fee: u64,         // 10% platform fee
     pub creator_amount: u64,       // 90% to creator
+    pub penalty_withheld: u64,     // Quality refund recovered from the creator share
+    pub fee_burned: u64,           // Portion of platform_fee burned
     pub chunks_consumed: u32,      // Total chunks consumed after settlement
     pub chunks_remaining: u32,     // Chunks left in approval
     pub settlement_timestamp: i64, // When settlement was requested
+    pub x402_proof: Pubkey,        // Payment proof backing the settlement (default = none)
+    pub watermark_id: [u8; 16],    // Session's forensic watermark
+    pub client_tag: [u8; 32],      // Client app/device tag of the session (zero = untagged)
+    pub fee_basis_points: u16,     // Fee rate applied, after any batch discount
+    pub batch_discount_bps: u16,   // Fee reduction for a large batch (0 = none)
+    pub completion_rebate: u64,    // Rebate netted off the viewer's payment on finishing the video
     pub timestamp: i64,            // When settlement was processed on-chain
 }
+
+// Event emitted when the platform authority proposes a new fee
+// Creators can watch this to see the take rate before it applies
+#[event]
+pub struct FeeChangeProposed {
+    pub platform: Pubkey,
+    pub current_fee_basis_points: u16,
+    pub proposed_fee_basis_points: u16,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeChangeExecuted {
+    pub platform: Pubkey,
+    pub previous_fee_basis_points: u16,
+    pub new_fee_basis_points: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformConfigUpdated {
+    pub platform: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QualityIssueReported {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub quality_report: Pubkey,
+    pub chunk_start: u32,
+    pub chunk_end: u32,
+    pub issue_code: QualityIssueCode,
+    pub timestamp: i64,
+}
+
+// Event emitted when the platform verifies a quality report
+// refund_amount is zero until the session reaches the platform's report threshold
+#[event]
+pub struct QualityReportVerified {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub quality_report: Pubkey,
+    pub verified_reports: u8,
+    pub refund_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorTiersUpdated {
+    pub platform: Pubkey,
+    pub creator_tiers: Pubkey,
+    pub tier_count: u8,
+    pub timestamp: i64,
+}
+
+// Event emitted when a settlement batch is prepared (two-phase settlement)
+// The matching SessionSettled event is emitted by execute_settlement
+#[event]
+pub struct SettlementPrepared {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub pending_settlement: Pubkey,
+    pub chunk_count: u32,
+    pub total_payment: u64,
+    pub platform_fee: u64,
+    pub creator_amount: u64,
+    pub merkle_root: [u8; 32],
+    pub settlement_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementCancelled {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub pending_settlement: Pubkey,
+    pub chunk_count: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when the static account registry changes
+// Clients should rebuild or extend their address lookup tables
+#[event]
+pub struct CommonAccountsUpdated {
+    pub common_accounts: Pubkey,
+    pub treasury: Pubkey,
+    pub lookup_table: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeRecipientsUpdated {
+    pub platform: Pubkey,
+    pub fee_recipients: Pubkey,
+    pub recipient_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouponCreated {
+    pub coupon: Pubkey,
+    pub issuer: Pubkey,
+    pub creator: Pubkey, // Default pubkey for platform-wide coupons
+    pub discount_bps: u16,
+    pub max_uses: u32,
+    pub expiry: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouponRedeemed {
+    pub coupon: Pubkey,
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub original_price_per_chunk: u64,
+    pub discounted_price_per_chunk: u64,
+    pub uses: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoGateUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub gate_collection: Option<Pubkey>,
+    pub gated_price_per_chunk: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoFormatUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub duration_seconds: u32,
+    pub chunk_duration_ms: u32,
+    pub media_format: MediaFormat,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRatingUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub rating: ContentRating,
+    pub content_flags: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AgeAttested {
+    pub viewer: Pubkey,
+    pub attestor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AgeAttestationRevoked {
+    pub viewer: Pubkey,
+    pub attestor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RegionPolicyUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub region_policy: Option<RegionPolicy>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoMetadataLocked {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub ipfs_hash: String,
+    pub total_chunks: u32,
+    pub chunk_merkle_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoArchived {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub archived_video: Pubkey,
+    pub video_id: String,
+    pub total_chunks_served: u64,
+    pub total_earned: u64,
+    pub rent_refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompletionRebateUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub completion_rebate_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundPolicyUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub refund_policy: RefundPolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementCosignUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub required: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoVisibilityUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub is_private: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ViewerAllowed {
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionGifted {
+    pub gifter: Pubkey,
+    pub recipient: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub max_chunks: u32,
+    pub price_per_chunk: u64,
+    pub escrow_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GiftCardCreated {
+    pub gift_card: Pubkey,
+    pub issuer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GiftCardRedeemed {
+    pub gift_card: Pubkey,
+    pub issuer: Pubkey,
+    pub redeemer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsPoolConfigured {
+    pub authority: Pubkey,
+    pub reward_per_chunk: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsPoolFunded {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsAccrued {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub chunk_count: u32,
+    pub reward: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub viewer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeClaimed {
+    pub viewer: Pubkey,
+    pub badge_mint: Pubkey,
+    pub level: u8,
+    pub chunks_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoAdModeUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub ad_supported: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdCampaignCreated {
+    pub ad_campaign: Pubkey,
+    pub advertiser: Pubkey,
+    pub campaign_id: u64,
+    pub subsidy_per_chunk: u64,
+    pub deposit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdImpressionsRecorded {
+    pub ad_campaign: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub impressions: u32,
+    pub subsidy: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdCampaignClosed {
+    pub ad_campaign: Pubkey,
+    pub advertiser: Pubkey,
+    pub refunded: u64,
+    pub impressions: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PromotionCreated {
+    pub promotion: Pubkey,
+    pub video: Pubkey,
+    pub sponsor: Pubkey,
+    pub budget: u64,
+    pub bid_per_impression: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PromotedImpressionsRecorded {
+    pub promotion: Pubkey,
+    pub video: Pubkey,
+    pub impressions: u64,
+    pub amount_charged: u64,
+    pub remaining_budget: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PromotionEnded {
+    pub promotion: Pubkey,
+    pub video: Pubkey,
+    pub sponsor: Pubkey,
+    pub refunded: u64,
+    pub impressions: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundingCampaignCreated {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub video_id: String,
+    pub goal: u64,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PledgeMade {
+    pub campaign: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    pub total_pledged: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundingCampaignClaimed {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PledgeRefunded {
+    pub campaign: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PublishWindowUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub publish_at: i64,
+    pub unpublish_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiveStreamStarted {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub total_chunks: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiveChunksAppended {
+    pub video: Pubkey,
+    pub count: u32,
+    pub total_chunks: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiveStreamFinalized {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub total_chunks: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BillingModeUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub billing_mode: BillingMode,
+    pub price_per_second: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QualityTiersUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub tier_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DiscountWindowsUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub discount_windows: Vec<DiscountWindow>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceCurveUpdated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub price_breaks: Vec<PriceBreak>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RenditionsUpdated {
+    pub video: Pubkey,
+    pub video_renditions: Pubkey,
+    pub rendition_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DeliveryChallenged {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub delivery_challenge: Pubkey,
+    pub chunk_index: u32,
+    pub expected_hash: [u8; 32],
+    pub received_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementDisputed {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub dispute: Pubkey,
+    pub nonce: u32,
+    pub disputed_chunks: u32,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub dispute: Pubkey,
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub arbiter: Pubkey,
+    pub status: DisputeStatus,
+    pub refund_amount: u64,
+    pub clawed_back: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorBondStaked {
+    pub operator: Pubkey,
+    pub operator_bond: Pubkey,
+    pub amount: u64,
+    pub total_bonded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorBondWithdrawn {
+    pub operator: Pubkey,
+    pub operator_bond: Pubkey,
+    pub amount: u64,
+    pub total_bonded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorBondSlashed {
+    pub operator: Pubkey,
+    pub operator_bond: Pubkey,
+    pub dispute: Pubkey,
+    pub viewer: Pubkey,
+    pub amount: u64,
+    pub remaining_bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NettingModeUpdated {
+    pub platform: Pubkey,
+    pub payout_vault: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayoutsFlushed {
+    pub creators_paid: u16,
+    pub creator_total: u64,
+    pub platform_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MerkleBatchSettled {
+    pub video: Pubkey,
+    pub operator: Pubkey,
+    pub batch: Pubkey,
+    pub batch_id: u64,
+    pub root: [u8; 32],
+    pub leaf_count: u32,
+    pub total_chunks: u64,
+    pub total_amount: u64,
+    pub platform_fee: u64,
+    pub creator_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchLeafDisputed {
+    pub batch: Pubkey,
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub leaf_index: u32,
+    pub chunk_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoMetadataMigrated {
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub metadata_uri: String, // Max 200 chars (enforced)
+    pub metadata_hash: [u8; 32],
+    pub rent_refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvariantViolation {
+    pub kind: InvariantKind,
+    pub account: Pubkey, // Account the broken identity was observed on
+    pub expected: u64,
+    pub actual: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformStatsSnapshotted {
+    pub epoch: u64,
+    pub total_videos: u64,
+    pub total_sessions: u64,
+    pub total_revenue: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionTransferred {
+    pub old_viewer: Pubkey,
+    pub new_viewer: Pubkey,
+    pub video: Pubkey,
+    pub old_session: Pubkey,
+    pub new_session: Pubkey,
+    pub max_approved_chunks: u32,
+    pub chunks_consumed: u32,
+    pub delegated_amount: u64, // Remaining allowance delegated by the new wallet
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AutoRenewUpdated {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub auto_renew: bool,
+    pub renewal_chunks: u32,
+    pub renewal_budget_chunks: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionRenewed {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub chunks_consumed: u32,
+    pub max_approved_chunks: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApprovalRenewed {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub renewed_chunks: u32,
+    pub max_approved_chunks: u32,
+    pub renewal_budget_chunks: u32, // Budget left after this renewal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignedApprovalRelayed {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub relayer: Pubkey, // Paid the transaction fee and session rent
+    pub max_chunks: u32,
+    pub price_per_chunk: u64,
+    pub valid_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SponsorshipVaultConfigured {
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SponsorshipVaultFunded {
+    pub funder: Pubkey,
+    pub amount: u64, // Lamports
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionRentSponsored {
+    pub viewer_session: Pubkey,
+    pub rent_payer: Pubkey, // Reimbursed from the vault
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionKeySet {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub session_key: Pubkey, // Default = removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvmAddressSet {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub evm_address: [u8; 20], // Zero = removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PasskeySet {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub passkey: [u8; 33], // Zero = removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PasskeyConsented {
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub action: PasskeyAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrossChainEmitterRegistered {
+    pub authority: Pubkey,
+    pub chain_id: u16,
+    pub emitter_address: [u8; 32],
+    pub core_bridge: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrossChainPaymentCredited {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub chain_id: u16,
+    pub sequence: u64,
+    pub amount: u64,
+    pub max_chunks: u32,
+    pub escrow_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CctpDepositCredited {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub amount: u64, // USDC minted into the escrow
+    pub added_chunks: u32,
+    pub max_approved_chunks: u32,
+    pub is_new_session: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct X402ProofVerified {
+    pub viewer_session: Pubkey,
+    pub x402_proof: Pubkey,
+    pub signer: Pubkey,
+    pub amount: u64,
+    pub resource_hash: [u8; 32],
+    pub nonce: [u8; 16],
+    pub timestamp: i64,
+}
+
+// Watched by the license server, which issues the chunk's decryption key
+#[event]
+pub struct KeyReleaseAuthorized {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub chunk_index: u32,
+    pub key_id: [u8; 16],         // Content key id (KID) of the chunk
+    pub quality_tier: Option<u8>, // Rendition the session pays for
+    pub paid: bool,               // Already paid (false = covered by the remaining approval)
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ViewerBanned {
+    pub authority: Pubkey,
+    pub viewer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ViewerUnbanned {
+    pub authority: Pubkey,
+    pub viewer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorSuspended {
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SuspensionLifted {
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub suspended_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VideoTakenDown {
+    pub authority: Pubkey,
+    pub video: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrialVaultFunded {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub vault_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrialSessionStarted {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub max_chunks: u32,
+    pub price_per_chunk: u64,
+    pub escrow_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FreeChunksClaimed {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub chunks: u32,
+    pub escrow_amount: u64,
+    pub used_today: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceFundConfigured {
+    pub authority: Pubkey,
+    pub insurance_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ViewerCompensated {
+    pub dispute: Pubkey,
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub signer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayoutAddressSet {
+    pub creator: Pubkey,
+    pub video: Pubkey,
+    pub payout_address: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorTeamUpdated {
+    pub creator: Pubkey,
+    pub creator_team: Pubkey,
+    pub member_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorVerified {
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerificationRevoked {
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub verified_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BoostStaked {
+    pub creator: Pubkey,
+    pub creator_stake: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub weight: u64,
+    pub lock_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BoostUnstaked {
+    pub creator: Pubkey,
+    pub creator_stake: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionsConfigured {
+    pub authority: Pubkey,
+    pub reward_mint: Pubkey,
+    pub emission_per_chunk: u64,
+    pub halving_interval: i64,
+    pub creator_share_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionsFunded {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionsAccrued {
+    pub viewer_session: Pubkey,
+    pub creator: Pubkey,
+    pub viewer: Pubkey,
+    pub chunk_count: u32,
+    pub creator_amount: u64,
+    pub viewer_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionsClaimed {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingRoundCreated {
+    pub round: Pubkey,
+    pub sponsor: Pubkey,
+    pub round_id: u64,
+    pub pool: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingSupportRecorded {
+    pub round: Pubkey,
+    pub creator: Pubkey,
+    pub supporter: Pubkey,
+    pub supporters: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchDistributed {
+    pub round: Pubkey,
+    pub creator: Pubkey,
+    pub supporters: u32,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingRoundClosed {
+    pub round: Pubkey,
+    pub sponsor: Pubkey,
+    pub distributed: u64,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyPosted {
+    pub bounty: Pubkey,
+    pub video: Pubkey,
+    pub poster: Pubkey,
+    pub language: String,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyClaimed {
+    pub bounty: Pubkey,
+    pub video: Pubkey,
+    pub language: String,
+    pub contributor: Pubkey,
+    pub subtitle_cid: String,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyCancelled {
+    pub bounty: Pubkey,
+    pub video: Pubkey,
+    pub poster: Pubkey,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackAdded {
+    pub video: Pubkey,
+    pub kind: TrackKind,
+    pub language: String,
+    pub cid: String,
+    pub added_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackRemoved {
+    pub video: Pubkey,
+    pub kind: TrackKind,
+    pub language: String,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ViewerRefunded {
+    pub creator: Pubkey,
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub viewer_session: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorPayoutClaimed {
+    pub creator: Pubkey,
+    pub video: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationCreated {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub seats: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationFunded {
+    pub organization: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub balance: u64, // Treasury balance after funding
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationFundsWithdrawn {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationMemberAdded {
+    pub organization: Pubkey,
+    pub member: Pubkey,
+    pub member_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationMemberRemoved {
+    pub organization: Pubkey,
+    pub member: Pubkey,
+    pub member_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationSessionStarted {
+    pub organization: Pubkey,
+    pub member: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey,
+    pub max_chunks: u32,
+    pub price_per_chunk: u64,
+    pub escrow_amount: u64, // Moved from the treasury into the session escrow
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChannelSessionApproved {
+    pub viewer: Pubkey,
+    pub creator: Pubkey,
+    pub channel_session: Pubkey,
+    pub max_spend: u64,
+    pub max_price_per_chunk: u64,
+    pub is_reapproval: bool, // Budget added to a live session
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChannelChunkPaid {
+    pub viewer: Pubkey,
+    pub creator: Pubkey,
+    pub video: Pubkey,
+    pub channel_session: Pubkey,
+    pub chunk_index: u32,
+    pub amount_paid: u64,
+    pub platform_fee: u64,
+    pub creator_amount: u64,
+    pub penalty_withheld: u64,
+    pub fee_burned: u64,
+    pub spend_remaining: u64, // Budget left across the catalog
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChannelSessionClosed {
+    pub viewer: Pubkey,
+    pub creator: Pubkey,
+    pub channel_session: Pubkey,
+    pub chunks_consumed: u32,
+    pub total_spent: u64,
+    pub timestamp: i64,
+}
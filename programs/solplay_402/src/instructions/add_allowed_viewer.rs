@@ -0,0 +1,61 @@
+// =============================================================================
+// Add Allowed Viewer Instruction
+// =============================================================================
+// Creates a per-viewer allowlist PDA for a video. The entry is checked by
+// approve_streaming_delegate whenever the video is private.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(viewer: Pubkey)]
+pub struct AddAllowedViewer<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = AllowedViewer::LEN,
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump
+    )]
+    pub allowed_viewer: Account<'info, AllowedViewer>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_allowed_viewer(ctx: Context<AddAllowedViewer>, viewer: Pubkey) -> Result<()> {
+    let allowed_viewer = &mut ctx.accounts.allowed_viewer;
+    let clock = Clock::get()?;
+
+    allowed_viewer.video = ctx.accounts.video.key();
+    allowed_viewer.viewer = viewer;
+    allowed_viewer.added_at = clock.unix_timestamp;
+    allowed_viewer.bump = ctx.bumps.allowed_viewer;
+
+    emit!(ViewerAllowed {
+        video: allowed_viewer.video,
+        viewer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Viewer {} allowed for video {}",
+        viewer,
+        ctx.accounts.video.video_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// =============================================================================
+// Add Organization Member Instruction
+// =============================================================================
+// The admin gives a wallet a seat, letting it open sessions paid from the
+// organization's shared budget. Fails once every seat is taken.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddOrganizationMember<'info> {
+    #[account(
+        mut,
+        seeds = [ORGANIZATION_SEED, admin.key().as_ref()],
+        bump = organization.bump,
+        has_one = admin @ StreamingError::Unauthorized
+    )]
+    pub organization: Box<Account<'info, Organization>>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn add_organization_member(ctx: Context<AddOrganizationMember>, member: Pubkey) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let clock = Clock::get()?;
+
+    require!(
+        !organization.is_member(&member),
+        StreamingError::AlreadyOrganizationMember
+    );
+    require!(
+        organization.members.len() < organization.seats as usize,
+        StreamingError::OrganizationSeatsFull
+    );
+
+    organization.members.push(member);
+    let member_count = organization.members.len() as u16;
+
+    emit!(OrganizationMemberAdded {
+        organization: organization.key(),
+        member,
+        member_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Organization member added: {}", member);
+
+    Ok(())
+}
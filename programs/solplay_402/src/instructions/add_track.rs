@@ -0,0 +1,89 @@
+// =============================================================================
+// Add Track Instruction
+// =============================================================================
+// Registers a subtitle or alternate audio track on a video, replacing any
+// existing track of the same kind and language. Callable by the creator or by
+// a manager or uploader on the creator's team.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddTrack<'info> {
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = VideoTracks::LEN,
+        seeds = [VIDEO_TRACKS_SEED, video.key().as_ref()],
+        bump
+    )]
+    pub video_tracks: Account<'info, VideoTracks>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    /// Creator's team (required when a team member signs)
+    #[account(
+        seeds = [CREATOR_TEAM_SEED, video.creator.as_ref()],
+        bump = creator_team.bump
+    )]
+    pub creator_team: Option<Account<'info, CreatorTeam>>,
+
+    /// Video creator, or a manager or uploader on the creator's team
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_track(ctx: Context<AddTrack>, track: MediaTrack) -> Result<()> {
+    require!(track.is_valid(), StreamingError::InvalidTrack);
+
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == ctx.accounts.video.creator
+            || ctx
+                .accounts
+                .creator_team
+                .as_ref()
+                .is_some_and(|team| team.can_manage_tracks(&signer)),
+        StreamingError::Unauthorized
+    );
+
+    let video_tracks = &mut ctx.accounts.video_tracks;
+    let clock = Clock::get()?;
+
+    match video_tracks.find(track.kind, &track.language) {
+        Some(index) => video_tracks.tracks[index] = track.clone(),
+        None => {
+            require!(
+                video_tracks.tracks.len() < MAX_VIDEO_TRACKS,
+                StreamingError::InvalidTrack
+            );
+            video_tracks.tracks.push(track.clone());
+        }
+    }
+    video_tracks.video = ctx.accounts.video.key();
+    video_tracks.updated_at = clock.unix_timestamp;
+    video_tracks.bump = ctx.bumps.video_tracks;
+
+    emit!(TrackAdded {
+        video: video_tracks.video,
+        kind: track.kind,
+        language: track.language,
+        cid: track.cid,
+        added_by: signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Track added: {} tracks", video_tracks.tracks.len());
+
+    Ok(())
+}
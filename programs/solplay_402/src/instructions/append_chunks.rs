@@ -0,0 +1,54 @@
+// =============================================================================
+// Append Chunks Instruction
+// =============================================================================
+// Grows a live stream's chunk count as new HLS segments are published.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AppendChunks<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized,
+        constraint = !video.is_immutable @ StreamingError::VideoImmutable
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn append_chunks(ctx: Context<AppendChunks>, count: u32) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    require!(video.is_live, StreamingError::NotLiveStream);
+    require!(count > 0, StreamingError::InvalidChunkCount);
+
+    let total_chunks = video
+        .total_chunks
+        .checked_add(count)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    require!(
+        total_chunks <= MAX_TOTAL_CHUNKS,
+        StreamingError::InvalidChunkCount
+    );
+    video.total_chunks = total_chunks;
+
+    emit!(LiveChunksAppended {
+        video: video.key(),
+        count,
+        total_chunks,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Appended {} chunks (total: {})", count, total_chunks);
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+// =============================================================================
+// Approve Channel Session Instruction
+// =============================================================================
+// A channel pass: one approval scoped to a creator rather than a single video,
+// so viewers hopping between episodes don't re-approve for each one. The
+// viewer approves a token budget (`max_spend`) and the highest chunk price
+// they accept; pay_channel_chunk then pays for chunks of any of the creator's
+// eligible videos out of that budget. Distinct from subscriptions - every
+// chunk is still paid for at the video's price.
+//
+// Approving again while the session is live adds to its budget; once it has
+// expired or gone inactive, approving restarts it with a fresh budget. Both
+// count towards the viewer's approval rate limits.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, ApproveChecked, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ApproveChannelSession<'info> {
+    #[account(
+        init_if_needed,
+        payer = viewer,
+        space = ChannelSession::LEN,
+        seeds = [CHANNEL_SESSION_SEED, viewer.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub channel_session: Account<'info, ChannelSession>,
+
+    /// CHECK: Creator whose catalog the session covers; only the address is used
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Viewer's token account (delegated to the platform PDA)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == viewer.key(),
+        constraint = viewer_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to approve
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.key().as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, creator.key().as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// Viewer's profile (required while the platform rate limits approvals)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer.key().as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Box<Account<'info, ViewerProfile>>>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_channel_session(
+    ctx: Context<ApproveChannelSession>,
+    max_spend: u64,
+    max_price_per_chunk: u64,
+) -> Result<()> {
+    require!(
+        max_spend > 0 && max_price_per_chunk > 0,
+        StreamingError::InvalidChannelBudget
+    );
+
+    let channel_session = &mut ctx.accounts.channel_session;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    // Keep the share of the delegation held by the viewer's other sessions
+    let other_sessions_allowance =
+        if ctx.accounts.viewer_token_account.delegate == COption::Some(platform.key()) {
+            ctx.accounts
+                .viewer_token_account
+                .delegated_amount
+                .saturating_sub(channel_session.remaining_allowance())
+        } else {
+            0
+        };
+
+    let is_reapproval = channel_session.session_start != 0
+        && !channel_session.is_expired(clock.unix_timestamp)
+        && !channel_session.is_inactive(clock.unix_timestamp);
+
+    // Per-viewer hourly limits on new sessions and approved chunks, counting
+    // the chunks the budget buys at the highest accepted price
+    if platform.has_approval_rate_limits() {
+        let viewer_profile = ctx
+            .accounts
+            .viewer_profile
+            .as_mut()
+            .ok_or(StreamingError::ViewerProfileRequired)?;
+        let chunks = u32::try_from(max_spend.div_ceil(max_price_per_chunk)).unwrap_or(u32::MAX);
+        viewer_profile.record_approval(platform, !is_reapproval, chunks, clock.unix_timestamp)?;
+    }
+    if is_reapproval {
+        channel_session.max_spend = channel_session
+            .max_spend
+            .checked_add(max_spend)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    } else {
+        channel_session.set_inner(ChannelSession {
+            viewer: ctx.accounts.viewer.key(),
+            creator: ctx.accounts.creator.key(),
+            max_spend,
+            max_price_per_chunk,
+            total_spent: 0,
+            chunks_consumed: 0,
+            session_start: clock.unix_timestamp,
+            last_activity: clock.unix_timestamp,
+            bump: ctx.bumps.channel_session,
+        });
+        platform.total_sessions = platform
+            .total_sessions
+            .checked_add(1)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+    channel_session.max_price_per_chunk = max_price_per_chunk;
+    channel_session.last_activity = clock.unix_timestamp;
+
+    let approval_amount = channel_session.remaining_allowance();
+    require!(
+        ctx.accounts.viewer_token_account.amount >= approval_amount,
+        StreamingError::InsufficientBalanceForApproval
+    );
+    let delegated_amount = approval_amount
+        .checked_add(other_sessions_allowance)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let cpi_accounts = ApproveChecked {
+        to: ctx.accounts.viewer_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        delegate: platform.to_account_info(),
+        authority: ctx.accounts.viewer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::approve_checked(cpi_ctx, delegated_amount, ctx.accounts.token_mint.decimals)?;
+
+    emit!(ChannelSessionApproved {
+        viewer: channel_session.viewer,
+        creator: channel_session.creator,
+        channel_session: channel_session.key(),
+        max_spend: channel_session.max_spend,
+        max_price_per_chunk,
+        is_reapproval,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Channel session approved: {} tokens @ up to {} tokens/chunk",
+        channel_session.max_spend,
+        max_price_per_chunk
+    );
+
+    Ok(())
+}
@@ -1,26 +1,45 @@
 // =============================================================================
 // Approve Streaming Delegate Instruction
 // =============================================================================
+// Approvals for region-restricted videos are co-signed by the backend
+// operator (platform authority) or the registered region attestor, vouching
+// for the viewer's `region`; the region is recorded on the session.
+// =============================================================================
 
 use crate::constants::*;
 use crate::errors::*;
 use crate::events::*;
+use crate::nft_gate::*;
+use crate::payments::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use anchor_spl::token::{self, ApproveChecked, Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
+#[instruction(max_chunks: u32, quality_tier: Option<u8>, session_nonce: u8, region: Option<[u8; 2]>)]
 pub struct ApproveDelegate<'info> {
     #[account(
         init_if_needed,
         payer = viewer,
         space = ViewerSession::LEN,
-        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref()],
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[session_nonce]],
         bump
     )]
     pub viewer_session: Account<'info, ViewerSession>,
 
+    /// First-view marker; counts the viewer towards the video's unique viewers
+    #[account(
+        init_if_needed,
+        payer = viewer,
+        space = ViewedMarker::LEN,
+        seeds = [VIEWED_MARKER_SEED, video.key().as_ref(), viewer.key().as_ref()],
+        bump
+    )]
+    pub viewed_marker: Box<Account<'info, ViewedMarker>>,
+
     #[account(
+        mut,
         seeds = [VIDEO_SEED, video.video_id.as_bytes()],
         bump = video.bump,
         constraint = video.is_active @ StreamingError::VideoNotActive
@@ -64,6 +83,64 @@ pub struct ApproveDelegate<'info> {
     )]
     pub platform_token_account: Account<'info, TokenAccount>,
 
+    /// Optional promo coupon - discounts the price locked into a new session
+    #[account(
+        mut,
+        seeds = [COUPON_SEED, coupon.code_hash.as_ref()],
+        bump = coupon.bump
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,
+
+    /// Allowlist entry (required when the video is private)
+    #[account(
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), viewer.key().as_ref()],
+        bump = allowed_viewer.bump
+    )]
+    pub allowed_viewer: Option<Account<'info, AllowedViewer>>,
+
+    /// Age attestation (required when the video is rated mature)
+    #[account(
+        seeds = [AGE_ATTESTATION_SEED, viewer.key().as_ref()],
+        bump = age_attestation.bump
+    )]
+    pub age_attestation: Option<Box<Account<'info, AgeAttestation>>>,
+
+    /// Rent sponsorship vault (reimburses the new session's rent when enabled)
+    #[account(
+        mut,
+        seeds = [SPONSORSHIP_VAULT_SEED],
+        bump = sponsorship_vault.bump
+    )]
+    pub sponsorship_vault: Option<Box<Account<'info, SponsorshipVault>>>,
+
+    /// Viewer's profile (required while the platform rate limits approvals)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer.key().as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Box<Account<'info, ViewerProfile>>>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to approve
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.key().as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, video.creator.as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// Region attestor co-signing the viewer's region (required when the
+    /// video is region-restricted)
+    pub region_attestor: Option<Signer<'info>>,
+
     #[account(mut)]
     pub viewer: Signer<'info>,
 
@@ -71,37 +148,201 @@ pub struct ApproveDelegate<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32) -> Result<()> {
+pub fn approve_streaming_delegate<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApproveDelegate<'info>>,
+    max_chunks: u32,
+    quality_tier: Option<u8>,
+    session_nonce: u8,
+    region: Option<[u8; 2]>,
+    client_tag: Option<[u8; 32]>,
+) -> Result<()> {
     require!(
-        max_chunks > 0 && max_chunks <= MAX_CHUNKS_PER_APPROVAL,
+        max_chunks > 0 && max_chunks <= ctx.accounts.video.max_units_per_approval(),
         StreamingError::MaxChunksPerApprovalExceeded
     );
 
+    // Private videos are restricted to allowlisted viewers
+    require!(
+        !ctx.accounts.video.is_private || ctx.accounts.allowed_viewer.is_some(),
+        StreamingError::ViewerNotAllowed
+    );
+    require!(
+        !ctx.accounts.video.requires_age_attestation() || ctx.accounts.age_attestation.is_some(),
+        StreamingError::AgeAttestationRequired
+    );
+    let attested_region = ctx.accounts.video.check_region(
+        &ctx.accounts.platform,
+        ctx.accounts
+            .region_attestor
+            .as_ref()
+            .map(|attestor| attestor.key())
+            .as_ref(),
+        region,
+    )?;
+
+    let clock = Clock::get()?;
+
+    // Count first-time viewers of the video
+    let video_key = ctx.accounts.video.key();
+    ctx.accounts.viewed_marker.record_view(
+        &mut ctx.accounts.video,
+        video_key,
+        ctx.accounts.viewer.key(),
+        clock.unix_timestamp,
+        ctx.bumps.viewed_marker,
+    )?;
+
+    // Per-viewer hourly limits on new sessions and approved chunks
+    if ctx.accounts.platform.has_approval_rate_limits() {
+        let viewer_profile = ctx
+            .accounts
+            .viewer_profile
+            .as_mut()
+            .ok_or(StreamingError::ViewerProfileRequired)?;
+        viewer_profile.record_approval(
+            &ctx.accounts.platform,
+            ctx.accounts.viewer_session.session_start == 0,
+            max_chunks,
+            clock.unix_timestamp,
+        )?;
+    }
+
     let viewer_session = &mut ctx.accounts.viewer_session;
     let video = &ctx.accounts.video;
     let platform = &mut ctx.accounts.platform;
-    let clock = Clock::get()?;
     let is_new_session = viewer_session.session_start == 0;
 
+    // The viewer's token account has a single delegation shared by all of their
+    // concurrent sessions; keep the other sessions' share when re-approving.
+    let other_sessions_allowance =
+        if ctx.accounts.viewer_token_account.delegate == COption::Some(platform.key()) {
+            ctx.accounts
+                .viewer_token_account
+                .delegated_amount
+                .saturating_sub(viewer_session.remaining_allowance()?)
+        } else {
+            0
+        };
+
+    // Scheduled premieres / limited-time drops
+    video.check_publish_window(clock.unix_timestamp)?;
+
     // CRITICAL FIX: Calculate actual delegation amount needed
     let approval_amount_u128: u128;
 
     if is_new_session {
+        // NFT-gated pricing: holders prove collection ownership via remaining accounts
+        // (the gated price is per chunk, so it only applies to per-chunk billing)
+        let mut locked_price = match quality_tier {
+            // Quality tiers are priced per chunk
+            Some(_) => {
+                require!(
+                    video.billing_mode == BillingMode::PerChunk,
+                    StreamingError::BillingModeMismatch
+                );
+                video.chunk_price(quality_tier)?
+            }
+            None => video.unit_price(),
+        };
+        let list_price = locked_price;
+        if video.is_gated()
+            && video.billing_mode == BillingMode::PerChunk
+            && !ctx.remaining_accounts.is_empty()
+        {
+            verify_collection_ownership(
+                ctx.remaining_accounts,
+                &ctx.accounts.viewer.key(),
+                &video.gate_collection,
+            )?;
+            locked_price = locked_price.min(video.gated_price_per_chunk);
+        }
+
+        // Off-peak pricing: a happy-hour window open now is locked in
+        locked_price = video.happy_hour_price(locked_price, clock.unix_timestamp);
+
+        // Apply promo coupon (new sessions only - the discounted price is locked in)
+        if let Some(coupon) = ctx.accounts.coupon.as_mut() {
+            require!(
+                coupon.is_redeemable(clock.unix_timestamp),
+                StreamingError::CouponNotRedeemable
+            );
+            require!(
+                coupon.applies_to(video),
+                StreamingError::CouponNotApplicable
+            );
+
+            locked_price = coupon.discounted_price(locked_price)?;
+            coupon.uses += 1;
+
+            emit!(CouponRedeemed {
+                coupon: coupon.key(),
+                viewer: ctx.accounts.viewer.key(),
+                video: video.key(),
+                viewer_session: viewer_session.key(),
+                original_price_per_chunk: list_price,
+                discounted_price_per_chunk: locked_price,
+                uses: coupon.uses,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
         // New session - initialize
         viewer_session.viewer = ctx.accounts.viewer.key();
         viewer_session.video = video.key();
         viewer_session.max_approved_chunks = max_chunks;
         viewer_session.chunks_consumed = 0;
         viewer_session.total_spent = 0;
-        viewer_session.approved_price_per_chunk = video.price_per_chunk;
+        viewer_session.approved_price_per_chunk = locked_price;
+        viewer_session.list_price_per_chunk = list_price;
         viewer_session.session_start = clock.unix_timestamp;
         viewer_session.last_activity = clock.unix_timestamp;
         viewer_session.bump = ctx.bumps.viewer_session;
+        viewer_session.quality_reports = 0;
+        viewer_session.verified_quality_reports = 0;
+        viewer_session.funded_by = Pubkey::default();
+        viewer_session.billing_mode = video.billing_mode;
+        viewer_session.quality_tier = quality_tier;
+        viewer_session.settlement_count = 0;
+        viewer_session.last_settled_at = 0;
+        viewer_session.disputed_chunks = 0;
+        viewer_session.last_operator = Pubkey::default();
+        viewer_session.recent_settlement_ids = [[0u8; 16]; SETTLEMENT_ID_HISTORY];
+        viewer_session.last_watched_chunk = 0;
+        viewer_session.session_nonce = session_nonce;
+        viewer_session.auto_renew = false;
+        viewer_session.renewal_chunks = 0;
+        viewer_session.renewal_budget_chunks = 0;
+        viewer_session.rent_sponsored = false;
+        viewer_session.session_key = Pubkey::default();
+        viewer_session.evm_address = [0u8; 20];
+        viewer_session.passkey = [0u8; 33];
+        viewer_session.settlement_consent = [0u8; 32];
+        viewer_session.watermark_id = viewer_session.derive_watermark_id();
+        viewer_session.price_breaks = video.session_price_breaks(false);
+        viewer_session.refund_policy = video.refund_policy;
+        viewer_session.value_window_start = 0;
+        viewer_session.value_window_settled = 0;
+        viewer_session.client_tag = [0u8; 32];
+        viewer_session.refund_pool = Pubkey::default();
+        viewer_session.refunded_chunks = 0;
+
+        // Platform-sponsored rent for the new session account
+        if let Some(sponsorship_vault) = ctx.accounts.sponsorship_vault.as_mut() {
+            let rent_payer = ctx.accounts.viewer.to_account_info();
+            if let Some(lamports) =
+                sponsor_session_rent(sponsorship_vault, viewer_session, &rent_payer)?
+            {
+                emit!(SessionRentSponsored {
+                    viewer_session: viewer_session.key(),
+                    rent_payer: rent_payer.key(),
+                    lamports,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+        }
 
         // Calculate approval for new chunks
-        approval_amount_u128 = (video.price_per_chunk as u128)
-            .checked_mul(max_chunks as u128)
-            .ok_or(StreamingError::ArithmeticOverflow)?;
+        approval_amount_u128 = viewer_session.price_of(0, max_chunks)? as u128;
 
         // Update platform stats
         platform.total_sessions = platform
@@ -110,6 +351,14 @@ pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32
             .ok_or(StreamingError::ArithmeticOverflow)?;
     } else {
         // Re-approval - CRITICAL: validate session still valid
+        require!(
+            !viewer_session.is_gifted(),
+            StreamingError::GiftedSessionNotExtendable
+        );
+        require!(
+            quality_tier == viewer_session.quality_tier,
+            StreamingError::InvalidQualityTier
+        );
         require!(
             !viewer_session.is_expired(clock.unix_timestamp),
             StreamingError::SessionExpired
@@ -131,14 +380,25 @@ pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32
         let remaining_chunks_after_update =
             viewer_session.max_approved_chunks - viewer_session.chunks_consumed;
 
-        // Calculate TOTAL amount for delegation (all remaining chunks at current price)
-        approval_amount_u128 = (video.price_per_chunk as u128)
-            .checked_mul(remaining_chunks_after_update as u128)
-            .ok_or(StreamingError::ArithmeticOverflow)?;
+        // Calculate TOTAL amount for delegation (all remaining chunks plus any
+        // auto-renew budget, at the locked price and pricing curve)
+        approval_amount_u128 = viewer_session.price_of(
+            viewer_session.chunks_consumed,
+            remaining_chunks_after_update
+                .checked_add(viewer_session.renewal_budget_chunks)
+                .ok_or(StreamingError::ArithmeticOverflow)?,
+        )? as u128;
 
         viewer_session.last_activity = clock.unix_timestamp;
     }
 
+    // Record the region attested for this approval
+    viewer_session.attested_region = attested_region;
+    // Tag the session with the approving client (kept when not passed)
+    if let Some(client_tag) = client_tag {
+        viewer_session.client_tag = client_tag;
+    }
+
     // CRITICAL FIX: Safe u128 -> u64 conversion with validation
     let approval_amount =
         u64::try_from(approval_amount_u128).map_err(|_| StreamingError::ArithmeticOverflow)?;
@@ -148,6 +408,9 @@ pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32
         ctx.accounts.viewer_token_account.amount >= approval_amount,
         StreamingError::InsufficientBalanceForApproval
     );
+    let delegated_amount = approval_amount
+        .checked_add(other_sessions_allowance)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
 
     // Create approve_checked instruction for SPL token delegation
     // Delegate to platform PDA so it can execute transfers on behalf of viewer
@@ -160,7 +423,7 @@ pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-    token::approve_checked(cpi_ctx, approval_amount, ctx.accounts.token_mint.decimals)?;
+    token::approve_checked(cpi_ctx, delegated_amount, ctx.accounts.token_mint.decimals)?;
 
     emit!(DelegationApproved {
         viewer: ctx.accounts.viewer.key(),
@@ -168,6 +431,7 @@ pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32
         viewer_session: viewer_session.key(),
         max_approved_chunks: viewer_session.max_approved_chunks,
         locked_price_per_chunk: viewer_session.approved_price_per_chunk,
+        quality_tier: viewer_session.quality_tier,
         is_reapproval: !is_new_session,
         timestamp: clock.unix_timestamp,
     });
@@ -175,7 +439,7 @@ pub fn approve_streaming_delegate(ctx: Context<ApproveDelegate>, max_chunks: u32
     msg!(
         "Approved delegation: {} chunks @ {} tokens/chunk (total: {})",
         max_chunks,
-        video.price_per_chunk,
+        viewer_session.approved_price_per_chunk,
         approval_amount
     );
 
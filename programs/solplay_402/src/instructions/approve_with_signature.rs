@@ -0,0 +1,311 @@
+// =============================================================================
+// Approve With Signature Instruction (Gasless Session Start)
+// =============================================================================
+// Opens a session for a viewer without a transaction from their wallet. The
+// viewer signs a structured approval message off-chain (`signed_approval_message`)
+// and a relayer submits it behind an Ed25519 precompile instruction, paying
+// the transaction fee and the session rent.
+//
+// SPL delegations still need the token owner's signature, so the session
+// draws on a delegation the viewer has already granted the platform; the
+// signed message caps the chunks, price and validity of this session.
+//
+// Validity is the signer's explicit `valid_until`: submission only has to land
+// at or before it, with no tight window around the current clock, so approvals
+// can be signed offline (cold hardware wallets, DAO treasuries, transactions
+// on a durable nonce) and relayed days later. `valid_until` may be at most
+// SIGNED_APPROVAL_MAX_VALIDITY ahead of submission. The approval can open its
+// session more than once until then (e.g. after a close), always bounded by
+// the viewer's delegation; revoking the delegation voids it early.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::sig_verify::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+#[instruction(viewer: Pubkey, max_chunks: u32, session_nonce: u8)]
+pub struct ApproveWithSignature<'info> {
+    #[account(
+        init,
+        payer = relayer,
+        space = ViewerSession::LEN,
+        seeds = [VIEWER_SESSION_SEED, viewer.as_ref(), video.key().as_ref(), &[session_nonce]],
+        bump
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    /// First-view marker; counts the viewer towards the video's unique viewers
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = ViewedMarker::LEN,
+        seeds = [VIEWED_MARKER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump
+    )]
+    pub viewed_marker: Box<Account<'info, ViewedMarker>>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (must already be delegated to the platform)
+    #[account(
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = viewer_token_account.owner == viewer
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    /// Allowlist entry (required when the video is private)
+    #[account(
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump = allowed_viewer.bump
+    )]
+    pub allowed_viewer: Option<Account<'info, AllowedViewer>>,
+
+    /// Age attestation (required when the video is rated mature)
+    #[account(
+        seeds = [AGE_ATTESTATION_SEED, viewer.as_ref()],
+        bump = age_attestation.bump
+    )]
+    pub age_attestation: Option<Box<Account<'info, AgeAttestation>>>,
+
+    /// Rent sponsorship vault (reimburses the new session's rent when enabled)
+    #[account(
+        mut,
+        seeds = [SPONSORSHIP_VAULT_SEED],
+        bump = sponsorship_vault.bump
+    )]
+    pub sponsorship_vault: Option<Box<Account<'info, SponsorshipVault>>>,
+
+    /// Viewer's profile (required while the platform rate limits approvals)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer.as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Box<Account<'info, ViewerProfile>>>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to approve
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, video.creator.as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, read to find the Ed25519 precompile check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_with_signature(
+    ctx: Context<ApproveWithSignature>,
+    viewer: Pubkey,
+    max_chunks: u32,
+    session_nonce: u8,
+    max_price_per_chunk: u64,
+    valid_until: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        valid_until >= clock.unix_timestamp
+            && valid_until <= clock.unix_timestamp + SIGNED_APPROVAL_MAX_VALIDITY,
+        StreamingError::SignedApprovalExpired
+    );
+    require!(
+        max_chunks > 0 && max_chunks <= ctx.accounts.video.max_units_per_approval(),
+        StreamingError::MaxChunksPerApprovalExceeded
+    );
+    require!(
+        !ctx.accounts.video.is_private || ctx.accounts.allowed_viewer.is_some(),
+        StreamingError::ViewerNotAllowed
+    );
+    require!(
+        !ctx.accounts.video.requires_age_attestation() || ctx.accounts.age_attestation.is_some(),
+        StreamingError::AgeAttestationRequired
+    );
+    // Region-restricted videos need an attested approval (approve_streaming_delegate)
+    require!(
+        ctx.accounts.video.region_policy.is_none(),
+        StreamingError::RegionAttestationRequired
+    );
+
+    // The viewer must have signed exactly these terms
+    let message = signed_approval_message(
+        &viewer,
+        &ctx.accounts.video.key(),
+        session_nonce,
+        max_chunks,
+        max_price_per_chunk,
+        valid_until,
+    );
+    verify_ed25519_signature(&ctx.accounts.instructions, &viewer, &message)?;
+
+    // Per-viewer hourly limits on new sessions and approved chunks
+    if ctx.accounts.platform.has_approval_rate_limits() {
+        let viewer_profile = ctx
+            .accounts
+            .viewer_profile
+            .as_mut()
+            .ok_or(StreamingError::ViewerProfileRequired)?;
+        viewer_profile.record_approval(
+            &ctx.accounts.platform,
+            true,
+            max_chunks,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // Count first-time viewers of the video
+    let video_key = ctx.accounts.video.key();
+    ctx.accounts.viewed_marker.record_view(
+        &mut ctx.accounts.video,
+        video_key,
+        viewer,
+        clock.unix_timestamp,
+        ctx.bumps.viewed_marker,
+    )?;
+
+    let video = &ctx.accounts.video;
+    video.check_publish_window(clock.unix_timestamp)?;
+
+    let price_per_chunk = video.unit_price();
+    require!(
+        price_per_chunk <= max_price_per_chunk,
+        StreamingError::PriceChangedSinceApproval
+    );
+
+    let approval_amount = u64::try_from(
+        (price_per_chunk as u128)
+            .checked_mul(max_chunks as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?,
+    )
+    .map_err(|_| StreamingError::ArithmeticOverflow)?;
+
+    // The existing delegation and balance must cover this session
+    let viewer_token_account = &ctx.accounts.viewer_token_account;
+    require!(
+        viewer_token_account.delegate == COption::Some(ctx.accounts.platform.key())
+            && viewer_token_account.delegated_amount >= approval_amount,
+        StreamingError::InsufficientApproval
+    );
+    require!(
+        viewer_token_account.amount >= approval_amount,
+        StreamingError::InsufficientBalanceForApproval
+    );
+
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    viewer_session.viewer = viewer;
+    viewer_session.video = video.key();
+    viewer_session.max_approved_chunks = max_chunks;
+    viewer_session.chunks_consumed = 0;
+    viewer_session.total_spent = 0;
+    viewer_session.approved_price_per_chunk = price_per_chunk;
+    viewer_session.list_price_per_chunk = price_per_chunk;
+    viewer_session.session_start = clock.unix_timestamp;
+    viewer_session.last_activity = clock.unix_timestamp;
+    viewer_session.bump = ctx.bumps.viewer_session;
+    viewer_session.quality_reports = 0;
+    viewer_session.verified_quality_reports = 0;
+    viewer_session.funded_by = Pubkey::default();
+    viewer_session.billing_mode = video.billing_mode;
+    viewer_session.quality_tier = None;
+    viewer_session.settlement_count = 0;
+    viewer_session.last_settled_at = 0;
+    viewer_session.disputed_chunks = 0;
+    viewer_session.last_operator = Pubkey::default();
+    viewer_session.recent_settlement_ids = [[0u8; 16]; SETTLEMENT_ID_HISTORY];
+    viewer_session.last_watched_chunk = 0;
+    viewer_session.session_nonce = session_nonce;
+    viewer_session.auto_renew = false;
+    viewer_session.renewal_chunks = 0;
+    viewer_session.renewal_budget_chunks = 0;
+    viewer_session.rent_sponsored = false;
+    viewer_session.session_key = Pubkey::default();
+    viewer_session.evm_address = [0u8; 20];
+    viewer_session.passkey = [0u8; 33];
+    viewer_session.settlement_consent = [0u8; 32];
+    viewer_session.watermark_id = viewer_session.derive_watermark_id();
+    viewer_session.attested_region = [0u8; 2];
+    viewer_session.price_breaks = video.session_price_breaks(true);
+    viewer_session.refund_policy = video.refund_policy;
+    viewer_session.value_window_start = 0;
+    viewer_session.value_window_settled = 0;
+    viewer_session.client_tag = [0u8; 32];
+    viewer_session.refund_pool = Pubkey::default();
+    viewer_session.refunded_chunks = 0;
+
+    // Reimburse the relayer's rent from the sponsorship vault
+    if let Some(sponsorship_vault) = ctx.accounts.sponsorship_vault.as_mut() {
+        let rent_payer = ctx.accounts.relayer.to_account_info();
+        if let Some(lamports) =
+            sponsor_session_rent(sponsorship_vault, viewer_session, &rent_payer)?
+        {
+            emit!(SessionRentSponsored {
+                viewer_session: viewer_session.key(),
+                rent_payer: rent_payer.key(),
+                lamports,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    let platform = &mut ctx.accounts.platform;
+    platform.total_sessions = platform
+        .total_sessions
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(SignedApprovalRelayed {
+        viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        relayer: ctx.accounts.relayer.key(),
+        max_chunks,
+        price_per_chunk,
+        valid_until,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Relayed signed approval for {}: {} chunks @ {} tokens/chunk",
+        viewer,
+        max_chunks,
+        price_per_chunk
+    );
+
+    Ok(())
+}
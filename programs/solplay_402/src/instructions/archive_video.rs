@@ -0,0 +1,98 @@
+// =============================================================================
+// Archive Video Instruction
+// =============================================================================
+// Retires a dead video: its essential stats are copied into a compact
+// ArchivedVideo stub and the full Video account is closed, refunding the
+// difference in rent to the creator. The CreatorEarnings account is left in
+// place, so earnings history and unclaimed payouts survive, and because it
+// stays initialised the video_id can never be reused by create_video.
+// Sessions still open on the video can be closed by their viewers, but can
+// no longer be settled.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ArchiveVideo<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized,
+        close = creator
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ArchivedVideo::LEN,
+        seeds = [ARCHIVED_VIDEO_SEED, video.key().as_ref()],
+        bump
+    )]
+    pub archived_video: Account<'info, ArchivedVideo>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn archive_video(ctx: Context<ArchiveVideo>) -> Result<()> {
+    let video = &ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    require!(
+        !video.is_active && (!video.is_live || video.live_finalized),
+        StreamingError::VideoNotArchivable
+    );
+
+    let archived_video = &mut ctx.accounts.archived_video;
+    archived_video.video = video.key();
+    archived_video.creator = video.creator;
+    archived_video.video_id = video.video_id.clone();
+    archived_video.ipfs_hash = video.ipfs_hash.clone();
+    archived_video.total_chunks = video.total_chunks;
+    archived_video.total_sessions = video.total_sessions;
+    archived_video.total_chunks_served = video.total_chunks_served;
+    archived_video.unique_viewers = video.unique_viewers;
+    archived_video.total_earned = ctx.accounts.creator_earnings.total_earned;
+    archived_video.created_at = video.created_at;
+    archived_video.archived_at = clock.unix_timestamp;
+    archived_video.bump = ctx.bumps.archived_video;
+
+    // Net rent returned once `close = creator` sweeps the Video account
+    let rent_refunded = video
+        .to_account_info()
+        .lamports()
+        .saturating_sub(archived_video.to_account_info().lamports());
+
+    emit!(VideoArchived {
+        video: video.key(),
+        creator: video.creator,
+        archived_video: archived_video.key(),
+        video_id: video.video_id.clone(),
+        total_chunks_served: video.total_chunks_served,
+        total_earned: archived_video.total_earned,
+        rent_refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Video {} archived, {} lamports of rent refunded",
+        video.video_id,
+        rent_refunded
+    );
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+// =============================================================================
+// Assert Invariants Instruction
+// =============================================================================
+// Permissionless accounting tripwire for auditors and monitoring bots. Checks
+// the identities below and emits an `InvariantViolation` event for each one
+// that is broken; the transaction itself succeeds either way.
+//   - per video: Video.total_chunks_served == CreatorEarnings.total_chunks_sold
+//   - per video: CreatorEarnings.disputed_amount <= total_earned
+//   - payout vault >= Platform.fee_owed + claimable of the passed creators
+//   - rewards vault >= RewardsPool.total_accrued - total_claimed
+//
+// Videos are passed as remaining accounts, in pairs:
+//   [2i]     the Video
+//   [2i + 1] its CreatorEarnings
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct AssertInvariants<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Payout vault (checked when provided)
+    #[account(
+        seeds = [PAYOUT_VAULT_SEED],
+        bump
+    )]
+    pub payout_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Rewards pool (checked with rewards_vault when provided)
+    #[account(
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump
+    )]
+    pub rewards_pool: Option<Account<'info, RewardsPool>>,
+
+    #[account(
+        seeds = [REWARDS_VAULT_SEED],
+        bump
+    )]
+    pub rewards_vault: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn assert_invariants<'info>(
+    ctx: Context<'_, '_, '_, 'info, AssertInvariants<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len().is_multiple_of(2),
+        StreamingError::InvalidAuditAccounts
+    );
+
+    let platform = &ctx.accounts.platform;
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mut violations: u16 = 0;
+    let mut report = |kind: InvariantKind, account: Pubkey, expected: u64, actual: u64| {
+        violations += 1;
+        emit!(InvariantViolation {
+            kind,
+            account,
+            expected,
+            actual,
+            timestamp,
+        });
+    };
+
+    let mut total_claimable: u64 = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (video_info, earnings_info) = (&pair[0], &pair[1]);
+        require!(
+            *video_info.owner == crate::ID && *earnings_info.owner == crate::ID,
+            StreamingError::InvalidAuditAccounts
+        );
+        let video = Video::try_deserialize(&mut &video_info.try_borrow_data()?[..])?;
+        let creator_earnings =
+            CreatorEarnings::try_deserialize(&mut &earnings_info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            creator_earnings.video,
+            video_info.key(),
+            StreamingError::InvalidAuditAccounts
+        );
+
+        if video.total_chunks_served != creator_earnings.total_chunks_sold {
+            report(
+                InvariantKind::ChunksSoldMismatch,
+                video_info.key(),
+                video.total_chunks_served,
+                creator_earnings.total_chunks_sold,
+            );
+        }
+        if creator_earnings.disputed_amount > creator_earnings.total_earned {
+            report(
+                InvariantKind::DisputedExceedsEarned,
+                earnings_info.key(),
+                creator_earnings.total_earned,
+                creator_earnings.disputed_amount,
+            );
+        }
+
+        total_claimable = total_claimable
+            .checked_add(creator_earnings.claimable)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+
+    if let Some(payout_vault) = ctx.accounts.payout_vault.as_ref() {
+        let owed = total_claimable
+            .checked_add(platform.fee_owed)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        if payout_vault.amount < owed {
+            report(
+                InvariantKind::PayoutVaultShortfall,
+                payout_vault.key(),
+                owed,
+                payout_vault.amount,
+            );
+        }
+    }
+
+    if let (Some(rewards_pool), Some(rewards_vault)) = (
+        ctx.accounts.rewards_pool.as_ref(),
+        ctx.accounts.rewards_vault.as_ref(),
+    ) {
+        let unclaimed = rewards_pool
+            .total_accrued
+            .saturating_sub(rewards_pool.total_claimed);
+        if rewards_vault.amount < unclaimed {
+            report(
+                InvariantKind::RewardsVaultShortfall,
+                rewards_vault.key(),
+                unclaimed,
+                rewards_vault.amount,
+            );
+        }
+    }
+
+    msg!(
+        "Invariants checked: {} videos, {} violations",
+        ctx.remaining_accounts.len() / 2,
+        violations
+    );
+
+    Ok(())
+}
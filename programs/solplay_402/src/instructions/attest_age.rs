@@ -0,0 +1,59 @@
+// =============================================================================
+// Attest Age Instruction
+// =============================================================================
+// Signed by the platform's age attestor (an off-chain KYC/age verification
+// service) once it has verified the viewer. The attestation lets the viewer
+// open sessions on mature-rated videos.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(viewer: Pubkey)]
+pub struct AttestAge<'info> {
+    #[account(
+        init,
+        payer = attestor,
+        space = AgeAttestation::LEN,
+        seeds = [AGE_ATTESTATION_SEED, viewer.as_ref()],
+        bump
+    )]
+    pub age_attestation: Account<'info, AgeAttestation>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.age_attestor != Pubkey::default()
+            && platform.age_attestor == attestor.key() @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn attest_age(ctx: Context<AttestAge>, viewer: Pubkey) -> Result<()> {
+    let age_attestation = &mut ctx.accounts.age_attestation;
+    let clock = Clock::get()?;
+
+    age_attestation.viewer = viewer;
+    age_attestation.attestor = ctx.accounts.attestor.key();
+    age_attestation.attested_at = clock.unix_timestamp;
+    age_attestation.bump = ctx.bumps.age_attestation;
+
+    emit!(AgeAttested {
+        viewer,
+        attestor: age_attestation.attestor,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Age attested for viewer {}", viewer);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// =============================================================================
+// Ban Viewer Instruction
+// =============================================================================
+// Cuts a wallet off (e.g. for chargeback fraud or piracy) without pausing the
+// platform: the ban marker PDA blocks approve_streaming_delegate and
+// approve_with_signature for that viewer. Existing sessions run out on their
+// own; unban_viewer lifts the ban.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(viewer: Pubkey)]
+pub struct BanViewer<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = BannedViewer::LEN,
+        seeds = [BANNED_VIEWER_SEED, viewer.as_ref()],
+        bump
+    )]
+    pub banned_viewer: Account<'info, BannedViewer>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn ban_viewer(ctx: Context<BanViewer>, viewer: Pubkey) -> Result<()> {
+    let banned_viewer = &mut ctx.accounts.banned_viewer;
+    let clock = Clock::get()?;
+
+    banned_viewer.viewer = viewer;
+    banned_viewer.banned_at = clock.unix_timestamp;
+    banned_viewer.bump = ctx.bumps.banned_viewer;
+
+    emit!(ViewerBanned {
+        authority: ctx.accounts.authority.key(),
+        viewer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Viewer {} banned", viewer);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// =============================================================================
+// Cancel Bounty Instruction
+// =============================================================================
+// The poster withdraws an unclaimed bounty: the reward is refunded and the
+// bounty and its escrow are closed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CancelBounty<'info> {
+    #[account(
+        mut,
+        seeds = [SUBTITLE_BOUNTY_SEED, bounty.video.as_ref(), poster.key().as_ref(), bounty.language.as_bytes()],
+        bump = bounty.bump,
+        has_one = poster @ StreamingError::Unauthorized,
+        has_one = vault @ StreamingError::InvalidBounty,
+        close = poster
+    )]
+    pub bounty: Account<'info, SubtitleBounty>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Poster's token account (receives the refund)
+    #[account(
+        mut,
+        constraint = poster_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = poster_token_account.owner == poster.key()
+    )]
+    pub poster_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    let refunded = ctx.accounts.vault.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.poster_token_account.to_account_info(),
+        refunded,
+    )?;
+    source.close(ctx.accounts.poster.to_account_info())?;
+
+    emit!(BountyCancelled {
+        bounty: bounty.key(),
+        video: bounty.video,
+        poster: bounty.poster,
+        refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Bounty cancelled: {} tokens refunded", refunded);
+
+    Ok(())
+}
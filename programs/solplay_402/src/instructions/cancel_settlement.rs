@@ -0,0 +1,52 @@
+// =============================================================================
+// Cancel Settlement Instruction
+// =============================================================================
+// Discards a prepared settlement that will not be executed (e.g. it went stale
+// after another settlement landed) and refunds its rent to the viewer.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [PENDING_SETTLEMENT_SEED, viewer_session.key().as_ref()],
+        bump = pending_settlement.bump,
+        constraint = pending_settlement.viewer_session == viewer_session.key() @ StreamingError::InvalidSession,
+        close = viewer
+    )]
+    pub pending_settlement: Account<'info, PendingSettlement>,
+
+    #[account(
+        constraint = viewer_session.viewer == viewer.key() @ StreamingError::Unauthorized
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+}
+
+pub fn cancel_settlement(ctx: Context<CancelSettlement>) -> Result<()> {
+    let pending_settlement = &ctx.accounts.pending_settlement;
+    let clock = Clock::get()?;
+
+    emit!(SettlementCancelled {
+        viewer: ctx.accounts.viewer.key(),
+        viewer_session: ctx.accounts.viewer_session.key(),
+        pending_settlement: pending_settlement.key(),
+        chunk_count: pending_settlement.chunk_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Prepared settlement cancelled: {} chunks",
+        pending_settlement.chunk_count
+    );
+
+    Ok(())
+}
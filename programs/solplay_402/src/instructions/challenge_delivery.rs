@@ -0,0 +1,108 @@
+// =============================================================================
+// Challenge Delivery Instruction
+// =============================================================================
+// Gives viewers recourse when a backend settles for chunks it never served
+// correctly. The viewer proves, against the video's chunk manifest, what the
+// committed hash of a chunk is and shows that the chunk they received hashes
+// to something else. A successful challenge records an open DeliveryChallenge
+// for the session; one challenge per chunk.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::merkle::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chunk_index: u32)]
+pub struct ChallengeDelivery<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = DeliveryChallenge::LEN,
+        seeds = [DELIVERY_CHALLENGE_SEED, viewer_session.key().as_ref(), &chunk_index.to_le_bytes()],
+        bump
+    )]
+    pub delivery_challenge: Account<'info, DeliveryChallenge>,
+
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn challenge_delivery(
+    ctx: Context<ChallengeDelivery>,
+    chunk_index: u32,
+    expected_hash: [u8; 32],
+    received_hash: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let video = &ctx.accounts.video;
+    let viewer_session = &ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    require!(video.has_chunk_manifest(), StreamingError::NoChunkManifest);
+    require!(
+        chunk_index < video.total_chunks,
+        StreamingError::InvalidChunkIndex
+    );
+    // Only chunks the viewer has actually paid for can be challenged
+    require!(
+        viewer_session.chunks_consumed > 0,
+        StreamingError::InvalidSession
+    );
+    require!(
+        verify_chunk_proof(
+            &video.chunk_merkle_root,
+            chunk_index,
+            &expected_hash,
+            &proof
+        ),
+        StreamingError::InvalidChunkProof
+    );
+    require!(
+        received_hash != expected_hash,
+        StreamingError::ChunkHashMatches
+    );
+
+    let delivery_challenge = &mut ctx.accounts.delivery_challenge;
+    delivery_challenge.viewer_session = viewer_session.key();
+    delivery_challenge.video = video.key();
+    delivery_challenge.viewer = ctx.accounts.viewer.key();
+    delivery_challenge.chunk_index = chunk_index;
+    delivery_challenge.expected_hash = expected_hash;
+    delivery_challenge.received_hash = received_hash;
+    delivery_challenge.opened_at = clock.unix_timestamp;
+    delivery_challenge.bump = ctx.bumps.delivery_challenge;
+
+    emit!(DeliveryChallenged {
+        viewer: delivery_challenge.viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        delivery_challenge: delivery_challenge.key(),
+        chunk_index,
+        expected_hash,
+        received_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Delivery challenged for chunk {}", chunk_index);
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+// =============================================================================
+// Claim Badge Instruction (Soulbound Milestone Badges)
+// =============================================================================
+// Mints a non-transferable Token-2022 badge to a viewer whose ViewerProfile
+// has reached a lifetime chunk milestone (see BADGE_CHUNK_THRESHOLDS).
+//
+// Each badge is its own mint, a PDA of (viewer, level), so a badge can only be
+// claimed once. The mint carries the NonTransferable extension, exactly one
+// token is minted to the viewer's associated token account, and the mint
+// authority is then removed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType, instruction::AuthorityType, state::Mint,
+};
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, SetAuthority, Token2022};
+use anchor_spl::token_interface::{
+    non_transferable_mint_initialize, NonTransferableMintInitialize,
+};
+
+#[derive(Accounts)]
+#[instruction(level: u8)]
+pub struct ClaimBadge<'info> {
+    #[account(
+        seeds = [VIEWER_PROFILE_SEED, viewer.key().as_ref()],
+        bump = viewer_profile.bump,
+        has_one = viewer @ StreamingError::Unauthorized
+    )]
+    pub viewer_profile: Account<'info, ViewerProfile>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// CHECK: Badge mint, created and initialized in the handler
+    #[account(
+        mut,
+        seeds = [BADGE_MINT_SEED, viewer.key().as_ref(), &[level]],
+        bump
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Viewer's associated token account for the badge (created and
+    /// validated by the associated token program)
+    #[account(mut)]
+    pub viewer_badge_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_badge(ctx: Context<ClaimBadge>, level: u8) -> Result<()> {
+    let chunks_threshold = *BADGE_CHUNK_THRESHOLDS
+        .get(level as usize)
+        .ok_or(StreamingError::InvalidBadgeLevel)?;
+    require!(
+        ctx.accounts.viewer_profile.total_chunks_watched >= chunks_threshold,
+        StreamingError::BadgeMilestoneNotReached
+    );
+
+    let clock = Clock::get()?;
+    let viewer_key = ctx.accounts.viewer.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let badge_mint = ctx.accounts.badge_mint.to_account_info();
+    let platform = ctx.accounts.platform.to_account_info();
+
+    let level_seed = [level];
+    let mint_seeds = &[
+        BADGE_MINT_SEED,
+        viewer_key.as_ref(),
+        &level_seed,
+        &[ctx.bumps.badge_mint],
+    ];
+    let platform_seeds = &[PLATFORM_SEED, &[ctx.accounts.platform.bump]];
+
+    // 1. Allocate the mint with room for the NonTransferable extension
+    // (fails if the badge was already claimed)
+    let space =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::NonTransferable])?;
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.viewer.to_account_info(),
+                to: badge_mint.clone(),
+            },
+            &[&mint_seeds[..]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &token_program.key(),
+    )?;
+
+    // 2. Initialize the extension, then the mint (platform PDA as authority)
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: badge_mint.clone(),
+        },
+    ))?;
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: badge_mint.clone(),
+            },
+        ),
+        0,
+        &platform.key(),
+        None,
+    )?;
+
+    // 3. Create the viewer's badge token account
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.viewer.to_account_info(),
+            associated_token: ctx.accounts.viewer_badge_account.to_account_info(),
+            authority: ctx.accounts.viewer.to_account_info(),
+            mint: badge_mint.clone(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    // 4. Mint the single badge token and lock the supply
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: badge_mint.clone(),
+                to: ctx.accounts.viewer_badge_account.to_account_info(),
+                authority: platform.clone(),
+            },
+            &[&platform_seeds[..]],
+        ),
+        1,
+    )?;
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            SetAuthority {
+                current_authority: platform,
+                account_or_mint: badge_mint,
+            },
+            &[&platform_seeds[..]],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    emit!(BadgeClaimed {
+        viewer: viewer_key,
+        badge_mint: ctx.accounts.badge_mint.key(),
+        level,
+        chunks_threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Badge level {} claimed ({} chunks watched)",
+        level,
+        ctx.accounts.viewer_profile.total_chunks_watched
+    );
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// =============================================================================
+// Claim Bounty Instruction
+// =============================================================================
+// Co-signed by the contributor and the video's creator, whose signature
+// approves the contributed track; the track must already be registered on the
+// video (add_track) in the bounty's language. The reward is paid to the
+// contributor and the bounty and its escrow are closed, returning rent to the
+// poster.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimBounty<'info> {
+    #[account(
+        mut,
+        seeds = [SUBTITLE_BOUNTY_SEED, video.key().as_ref(), poster.key().as_ref(), bounty.language.as_bytes()],
+        bump = bounty.bump,
+        has_one = video @ StreamingError::InvalidBounty,
+        has_one = poster @ StreamingError::InvalidBounty,
+        has_one = vault @ StreamingError::InvalidBounty,
+        close = poster
+    )]
+    pub bounty: Account<'info, SubtitleBounty>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    /// Video's tracks, holding the contributed subtitle track
+    #[account(
+        seeds = [VIDEO_TRACKS_SEED, video.key().as_ref()],
+        bump = video_tracks.bump
+    )]
+    pub video_tracks: Account<'info, VideoTracks>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Contributor's token account (receives the reward)
+    #[account(
+        mut,
+        constraint = contributor_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = contributor_token_account.owner == contributor.key()
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Bounty poster; receives the rent, bound by has_one on the bounty
+    #[account(mut)]
+    pub poster: UncheckedAccount<'info>,
+
+    pub contributor: Signer<'info>,
+
+    /// Video creator approving the contribution
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_bounty(ctx: Context<ClaimBounty>, subtitle_cid: String) -> Result<()> {
+    require!(
+        !subtitle_cid.is_empty() && subtitle_cid.len() <= MAX_IPFS_HASH_LENGTH,
+        StreamingError::InvalidBounty
+    );
+
+    let bounty = &ctx.accounts.bounty;
+    let video_tracks = &ctx.accounts.video_tracks;
+    let clock = Clock::get()?;
+
+    require!(
+        video_tracks
+            .find(TrackKind::Subtitle, &bounty.language)
+            .is_some_and(|index| video_tracks.tracks[index].cid == subtitle_cid),
+        StreamingError::TrackNotFound
+    );
+
+    let amount = ctx.accounts.vault.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.contributor_token_account.to_account_info(),
+        amount,
+    )?;
+    source.close(ctx.accounts.poster.to_account_info())?;
+
+    emit!(BountyClaimed {
+        bounty: bounty.key(),
+        video: bounty.video,
+        language: bounty.language.clone(),
+        contributor: ctx.accounts.contributor.key(),
+        subtitle_cid,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Bounty claimed: {} tokens for {} subtitles",
+        amount,
+        bounty.language
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// =============================================================================
+// Claim Creator Payout Instruction
+// =============================================================================
+// Pull-payment counterpart to flush_payouts: the creator withdraws the netted
+// earnings accumulated for a video from the payout vault whenever they like.
+// Earnings still inside the platform's payout maturation window, or held
+// under an open settlement dispute, stay behind.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimCreatorPayout<'info> {
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, creator_earnings.video.as_ref()],
+        bump = creator_earnings.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        mut,
+        seeds = [PAYOUT_VAULT_SEED],
+        bump
+    )]
+    pub payout_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Creator's payout token account (receives the payout)
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator_earnings.payout_owner()
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Creator suspension marker; payouts are frozen while it exists
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, creator.key().as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_creator_payout(ctx: Context<ClaimCreatorPayout>) -> Result<()> {
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let clock = Clock::get()?;
+
+    let amount = creator_earnings.take_claimable(clock.unix_timestamp)?;
+    require!(amount > 0, StreamingError::NoPayoutToClaim);
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.payout_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.creator_token_account.to_account_info(), amount)?;
+
+    emit!(CreatorPayoutClaimed {
+        creator: creator_earnings.creator,
+        video: creator_earnings.video,
+        amount,
+        total_claimed: creator_earnings.claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator payout claimed: {} tokens", amount);
+
+    Ok(())
+}
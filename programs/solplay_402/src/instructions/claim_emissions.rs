@@ -0,0 +1,88 @@
+// =============================================================================
+// Claim Emissions Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimEmissions<'info> {
+    #[account(
+        mut,
+        seeds = [EMISSION_SCHEDULE_SEED],
+        bump = emission_schedule.bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(
+        mut,
+        seeds = [EMISSION_VAULT_SEED],
+        bump
+    )]
+    pub emission_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [EMISSION_BALANCE_SEED, owner.key().as_ref()],
+        bump = emission_balance.bump,
+        has_one = owner @ StreamingError::Unauthorized
+    )]
+    pub emission_balance: Account<'info, EmissionBalance>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Owner's platform token account (receives the emissions)
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == emission_schedule.reward_mint @ StreamingError::InvalidTokenMint,
+        constraint = owner_token_account.owner == owner.key()
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_emissions(ctx: Context<ClaimEmissions>) -> Result<()> {
+    let emission_balance = &mut ctx.accounts.emission_balance;
+    let emission_schedule = &mut ctx.accounts.emission_schedule;
+    let clock = Clock::get()?;
+
+    let amount = emission_balance.claimable();
+    require!(amount > 0, StreamingError::NoRewardsToClaim);
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.emission_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.owner_token_account.to_account_info(), amount)?;
+
+    emission_balance.claimed = emission_balance.accrued;
+    emission_schedule.total_claimed = emission_schedule
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(EmissionsClaimed {
+        owner: ctx.accounts.owner.key(),
+        amount,
+        total_claimed: emission_balance.claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Emissions claimed: {} tokens", amount);
+
+    Ok(())
+}
@@ -0,0 +1,257 @@
+// =============================================================================
+// Claim Free Chunks Instruction (Freemium Allowance)
+// =============================================================================
+// Opens a session paid for by the platform's promo pool (the trial vault) for
+// up to `platform.free_chunks_per_day` chunks per viewer per UTC day. The
+// allowance is tracked on the viewer's profile. Like a trial session, the
+// escrow is funded from the vault, settlements are paid from the escrow, and
+// the unspent balance returns to the vault on close; chunks claimed but not
+// watched still count against the day's allowance.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(session_nonce: u8)]
+pub struct ClaimFreeChunks<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = ViewerSession::LEN,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[session_nonce]],
+        bump
+    )]
+    pub viewer_session: Box<Account<'info, ViewerSession>>,
+
+    /// Escrow holding the free chunks' funds, owned by the platform PDA
+    #[account(
+        init,
+        payer = viewer,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub session_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// Viewer's profile, which tracks the daily allowance
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer.key().as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Box<Account<'info, ViewerProfile>>,
+
+    /// Promo pool funding free chunks
+    #[account(
+        mut,
+        seeds = [TRIAL_VAULT_SEED],
+        bump
+    )]
+    pub trial_vault: Box<Account<'info, TokenAccount>>,
+
+    /// First-view marker; counts the viewer towards the video's unique viewers
+    #[account(
+        init_if_needed,
+        payer = viewer,
+        space = ViewedMarker::LEN,
+        seeds = [VIEWED_MARKER_SEED, video.key().as_ref(), viewer.key().as_ref()],
+        bump
+    )]
+    pub viewed_marker: Box<Account<'info, ViewedMarker>>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Box<Account<'info, Video>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Allowlist entry (required when the video is private)
+    #[account(
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), viewer.key().as_ref()],
+        bump = allowed_viewer.bump
+    )]
+    pub allowed_viewer: Option<Account<'info, AllowedViewer>>,
+
+    /// Age attestation (required when the video is rated mature)
+    #[account(
+        seeds = [AGE_ATTESTATION_SEED, viewer.key().as_ref()],
+        bump = age_attestation.bump
+    )]
+    pub age_attestation: Option<Box<Account<'info, AgeAttestation>>>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to claim
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.key().as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, video.creator.as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_free_chunks(
+    ctx: Context<ClaimFreeChunks>,
+    session_nonce: u8,
+    chunks: u32,
+) -> Result<()> {
+    require!(
+        chunks > 0 && chunks <= ctx.accounts.video.max_units_per_approval(),
+        StreamingError::MaxChunksPerApprovalExceeded
+    );
+    require!(
+        !ctx.accounts.video.is_private || ctx.accounts.allowed_viewer.is_some(),
+        StreamingError::ViewerNotAllowed
+    );
+    require!(
+        !ctx.accounts.video.requires_age_attestation() || ctx.accounts.age_attestation.is_some(),
+        StreamingError::AgeAttestationRequired
+    );
+    // Region-restricted videos need an attested approval (approve_streaming_delegate)
+    require!(
+        ctx.accounts.video.region_policy.is_none(),
+        StreamingError::RegionAttestationRequired
+    );
+
+    let clock = Clock::get()?;
+    let viewer = ctx.accounts.viewer.key();
+
+    // Count the chunks against today's allowance
+    ctx.accounts.viewer_profile.claim_free_chunks(
+        &ctx.accounts.platform,
+        chunks,
+        clock.unix_timestamp,
+    )?;
+
+    // Count first-time viewers of the video
+    let video_key = ctx.accounts.video.key();
+    ctx.accounts.viewed_marker.record_view(
+        &mut ctx.accounts.video,
+        video_key,
+        viewer,
+        clock.unix_timestamp,
+        ctx.bumps.viewed_marker,
+    )?;
+
+    let video = &ctx.accounts.video;
+    video.check_publish_window(clock.unix_timestamp)?;
+
+    let price_per_chunk = video.unit_price();
+    let escrow_amount = price_per_chunk
+        .checked_mul(chunks as u64)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    require!(
+        ctx.accounts.trial_vault.amount >= escrow_amount,
+        StreamingError::InsufficientBalance
+    );
+
+    // Fund the escrow from the promo pool
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.trial_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.session_escrow.to_account_info(), escrow_amount)?;
+
+    // Create the viewer's session at the current price
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    viewer_session.viewer = viewer;
+    viewer_session.video = video.key();
+    viewer_session.max_approved_chunks = chunks;
+    viewer_session.chunks_consumed = 0;
+    viewer_session.total_spent = 0;
+    viewer_session.approved_price_per_chunk = price_per_chunk;
+    viewer_session.list_price_per_chunk = price_per_chunk;
+    viewer_session.session_start = clock.unix_timestamp;
+    viewer_session.last_activity = clock.unix_timestamp;
+    viewer_session.bump = ctx.bumps.viewer_session;
+    viewer_session.quality_reports = 0;
+    viewer_session.verified_quality_reports = 0;
+    viewer_session.funded_by = ctx.accounts.platform.key();
+    viewer_session.billing_mode = video.billing_mode;
+    viewer_session.quality_tier = None;
+    viewer_session.settlement_count = 0;
+    viewer_session.last_settled_at = 0;
+    viewer_session.disputed_chunks = 0;
+    viewer_session.last_operator = Pubkey::default();
+    viewer_session.recent_settlement_ids = [[0u8; 16]; SETTLEMENT_ID_HISTORY];
+    viewer_session.last_watched_chunk = 0;
+    viewer_session.session_nonce = session_nonce;
+    viewer_session.auto_renew = false;
+    viewer_session.renewal_chunks = 0;
+    viewer_session.renewal_budget_chunks = 0;
+    viewer_session.rent_sponsored = false;
+    viewer_session.session_key = Pubkey::default();
+    viewer_session.evm_address = [0u8; 20];
+    viewer_session.passkey = [0u8; 33];
+    viewer_session.settlement_consent = [0u8; 32];
+    viewer_session.watermark_id = viewer_session.derive_watermark_id();
+    viewer_session.attested_region = [0u8; 2];
+    viewer_session.price_breaks = video.session_price_breaks(true);
+    viewer_session.refund_policy = video.refund_policy;
+    viewer_session.value_window_start = 0;
+    viewer_session.value_window_settled = 0;
+    viewer_session.client_tag = [0u8; 32];
+    viewer_session.refund_pool = ctx.accounts.trial_vault.key();
+    viewer_session.refunded_chunks = 0;
+
+    let platform = &mut ctx.accounts.platform;
+    platform.total_sessions = platform
+        .total_sessions
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(FreeChunksClaimed {
+        viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        chunks,
+        escrow_amount,
+        used_today: ctx.accounts.viewer_profile.free_chunks_used,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Free chunks claimed by {}: {} chunks, {} tokens in escrow",
+        viewer,
+        chunks,
+        escrow_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+// =============================================================================
+// Claim Or Refund Instruction (Crowdfunding)
+// =============================================================================
+// Settles a funding campaign for the claimant:
+// - Creator: once the goal is met and the video is published under the
+//   campaign's id before the deadline, the whole escrow is released.
+// - Backer: once the deadline has passed without that happening, the pledge
+//   is refunded and the pledge account closed.
+//
+// The video PDA for the campaign's id is always passed; an uninitialized
+// account proves the video was never published.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimOrRefund<'info> {
+    #[account(
+        mut,
+        seeds = [FUNDING_CAMPAIGN_SEED, campaign.video_id.as_bytes()],
+        bump = campaign.bump
+    )]
+    pub campaign: Account<'info, FundingCampaign>,
+
+    #[account(
+        mut,
+        seeds = [FUNDING_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Video PDA for the campaign's id, deserialized in the handler if
+    /// it has been created
+    #[account(
+        seeds = [VIDEO_SEED, campaign.video_id.as_bytes()],
+        bump
+    )]
+    pub video: UncheckedAccount<'info>,
+
+    /// Claimant's pledge (required for refunds)
+    #[account(
+        mut,
+        seeds = [PLEDGE_SEED, campaign.key().as_ref(), claimant.key().as_ref()],
+        bump = pledge.bump,
+        close = claimant
+    )]
+    pub pledge: Option<Account<'info, Pledge>>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Claimant's token account (receives the claim or refund)
+    #[account(
+        mut,
+        constraint = claimant_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = claimant_token_account.owner == claimant.key()
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_or_refund(ctx: Context<ClaimOrRefund>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Resolve the published video, if any
+    let video_info = ctx.accounts.video.to_account_info();
+    let published = if video_info.owner == &crate::ID && !video_info.data_is_empty() {
+        Some(Video::try_deserialize(
+            &mut &video_info.try_borrow_data()?[..],
+        )?)
+    } else {
+        None
+    };
+    let is_successful = ctx.accounts.campaign.is_successful(published.as_ref());
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.campaign_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    let campaign = &mut ctx.accounts.campaign;
+    let claimant = ctx.accounts.claimant.key();
+
+    if claimant == campaign.creator && ctx.accounts.pledge.is_none() {
+        require!(
+            is_successful && !campaign.claimed,
+            StreamingError::FundingCampaignNotSuccessful
+        );
+
+        let amount = ctx.accounts.campaign_vault.amount;
+        source.transfer(
+            ctx.accounts.claimant_token_account.to_account_info(),
+            amount,
+        )?;
+        campaign.claimed = true;
+
+        emit!(FundingCampaignClaimed {
+            campaign: campaign.key(),
+            creator: claimant,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Funding campaign claimed: {} tokens", amount);
+    } else {
+        let pledge = ctx
+            .accounts
+            .pledge
+            .as_ref()
+            .ok_or(StreamingError::RefundNotAvailable)?;
+        require!(
+            clock.unix_timestamp > campaign.deadline && !is_successful,
+            StreamingError::RefundNotAvailable
+        );
+
+        source.transfer(
+            ctx.accounts.claimant_token_account.to_account_info(),
+            pledge.amount,
+        )?;
+
+        emit!(PledgeRefunded {
+            campaign: campaign.key(),
+            backer: claimant,
+            amount: pledge.amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Pledge refunded: {} tokens", pledge.amount);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// =============================================================================
+// Claim Rewards Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    #[account(
+        mut,
+        seeds = [REWARDS_VAULT_SEED],
+        bump
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VIEWER_REWARDS_SEED, viewer.key().as_ref()],
+        bump = viewer_rewards.bump,
+        has_one = viewer @ StreamingError::Unauthorized
+    )]
+    pub viewer_rewards: Account<'info, ViewerRewards>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (receives the rewards)
+    #[account(
+        mut,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = viewer_token_account.owner == viewer.key()
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let viewer_rewards = &mut ctx.accounts.viewer_rewards;
+    let rewards_pool = &mut ctx.accounts.rewards_pool;
+    let clock = Clock::get()?;
+
+    let amount = viewer_rewards.claimable();
+    require!(amount > 0, StreamingError::NoRewardsToClaim);
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.rewards_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.viewer_token_account.to_account_info(), amount)?;
+
+    viewer_rewards.claimed = viewer_rewards.accrued;
+    rewards_pool.total_claimed = rewards_pool
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(RewardsClaimed {
+        viewer: ctx.accounts.viewer.key(),
+        amount,
+        total_claimed: viewer_rewards.claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Rewards claimed: {} tokens", amount);
+
+    Ok(())
+}
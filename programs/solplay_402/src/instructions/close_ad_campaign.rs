@@ -0,0 +1,83 @@
+// =============================================================================
+// Close Ad Campaign Instruction
+// =============================================================================
+// Ends a campaign, refunding the unspent budget to the advertiser and
+// reclaiming the rent of the campaign and its escrow.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CloseAdCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [AD_CAMPAIGN_SEED, advertiser.key().as_ref(), &ad_campaign.campaign_id.to_le_bytes()],
+        bump = ad_campaign.bump,
+        has_one = advertiser @ StreamingError::Unauthorized,
+        has_one = vault @ StreamingError::AdCampaignVaultMismatch,
+        close = advertiser
+    )]
+    pub ad_campaign: Account<'info, AdCampaign>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Advertiser's token account (receives the unspent budget)
+    #[account(
+        mut,
+        constraint = advertiser_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = advertiser_token_account.owner == advertiser.key()
+    )]
+    pub advertiser_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub advertiser: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn close_ad_campaign(ctx: Context<CloseAdCampaign>) -> Result<()> {
+    let ad_campaign = &ctx.accounts.ad_campaign;
+    let clock = Clock::get()?;
+
+    let refunded = ctx.accounts.vault.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.advertiser_token_account.to_account_info(),
+        refunded,
+    )?;
+    source.close(ctx.accounts.advertiser.to_account_info())?;
+
+    emit!(AdCampaignClosed {
+        ad_campaign: ad_campaign.key(),
+        advertiser: ad_campaign.advertiser,
+        refunded,
+        impressions: ad_campaign.impressions,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Ad campaign {} closed: {} tokens refunded",
+        ad_campaign.campaign_id,
+        refunded
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// =============================================================================
+// Close Channel Session Instruction
+// =============================================================================
+// Closes a channel session and returns its rent to the viewer. The viewer's
+// delegation is left as is; revoke_streaming_delegate releases it.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseChannelSession<'info> {
+    #[account(
+        mut,
+        seeds = [CHANNEL_SESSION_SEED, viewer.key().as_ref(), channel_session.creator.as_ref()],
+        bump = channel_session.bump,
+        constraint = channel_session.viewer == viewer.key() @ StreamingError::Unauthorized,
+        close = viewer
+    )]
+    pub channel_session: Account<'info, ChannelSession>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+}
+
+pub fn close_channel_session(ctx: Context<CloseChannelSession>) -> Result<()> {
+    let channel_session = &ctx.accounts.channel_session;
+    let clock = Clock::get()?;
+
+    emit!(ChannelSessionClosed {
+        viewer: channel_session.viewer,
+        creator: channel_session.creator,
+        channel_session: channel_session.key(),
+        chunks_consumed: channel_session.chunks_consumed,
+        total_spent: channel_session.total_spent,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Channel session closed. Chunks consumed: {}, Total spent: {}",
+        channel_session.chunks_consumed,
+        channel_session.total_spent
+    );
+
+    Ok(())
+}
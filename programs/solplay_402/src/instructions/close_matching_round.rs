@@ -0,0 +1,89 @@
+// =============================================================================
+// Close Matching Round Instruction
+// =============================================================================
+// Once every creator's match has been distributed, the sponsor recovers the
+// undistributed remainder (rounding dust, or the whole pool if nobody was
+// supported) and the round and its escrow are closed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CloseMatchingRound<'info> {
+    #[account(
+        mut,
+        seeds = [MATCHING_ROUND_SEED, sponsor.key().as_ref(), &matching_round.round_id.to_le_bytes()],
+        bump = matching_round.bump,
+        has_one = sponsor @ StreamingError::Unauthorized,
+        constraint = matching_round.vault == matching_vault.key() @ StreamingError::InvalidMatchingRound,
+        close = sponsor
+    )]
+    pub matching_round: Account<'info, MatchingRound>,
+
+    #[account(mut)]
+    pub matching_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Sponsor's token account (receives the remainder)
+    #[account(
+        mut,
+        constraint = sponsor_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = sponsor_token_account.owner == sponsor.key()
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn close_matching_round(ctx: Context<CloseMatchingRound>) -> Result<()> {
+    let matching_round = &ctx.accounts.matching_round;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= matching_round.end_time,
+        StreamingError::MatchingRoundNotEnded
+    );
+    require!(
+        matching_round.creators_matched == matching_round.creators,
+        StreamingError::MatchingRoundNotEnded
+    );
+
+    let refunded = ctx.accounts.matching_vault.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.matching_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.sponsor_token_account.to_account_info(),
+        refunded,
+    )?;
+    source.close(ctx.accounts.sponsor.to_account_info())?;
+
+    emit!(MatchingRoundClosed {
+        round: matching_round.key(),
+        sponsor: matching_round.sponsor,
+        distributed: matching_round.distributed,
+        refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Matching round closed: {} tokens refunded", refunded);
+
+    Ok(())
+}
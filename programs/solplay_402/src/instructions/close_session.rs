@@ -1,37 +1,180 @@
 // =============================================================================
 // Close Viewer Session Instruction (Cleanup & Rent Reclaim)
 // =============================================================================
+// Gifted sessions must also pass the session escrow and the gifter's token
+// account: the unspent escrow balance is refunded to the gifter and the
+// escrow is closed. Sessions funded from a pool (trials, free chunks,
+// cross-chain credits, organization treasuries) must pass that pool's token
+// account, recorded as the session's refund pool. Sessions whose rent was sponsored must pass
+// the sponsorship vault, which receives the rent instead of the viewer. The
+// video may already have been archived.
+//
+// Passing the viewer's token account (and the platform) releases the
+// session's unwatched allowance: the delegation is lowered by the value of
+// the approved but unconsumed chunks, or revoked when nothing else is left,
+// so viewers no longer need to remember revoke_streaming_delegate.
+// =============================================================================
 
 use crate::constants::*;
+use crate::errors::*;
 use crate::events::*;
+use crate::payments::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Approve, Revoke, Token, TokenAccount};
 
 #[derive(Accounts)]
 pub struct CloseViewerSession<'info> {
     #[account(
         mut,
-        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref()],
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
         bump = viewer_session.bump,
         constraint = viewer_session.viewer == viewer.key(),
         close = viewer
     )]
     pub viewer_session: Account<'info, ViewerSession>,
 
+    /// CHECK: Only the address is used (bound by the session seeds), so
+    /// sessions on an archived video can still be closed
+    pub video: UncheckedAccount<'info>,
+
     #[account(
-        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
-        bump = video.bump
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
     )]
-    pub video: Account<'info, Video>,
+    pub platform: Option<Account<'info, Platform>>,
+
+    /// Gift escrow (required for gifted sessions)
+    #[account(
+        mut,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump
+    )]
+    pub session_escrow: Option<Account<'info, TokenAccount>>,
+
+    /// Gifter's token account, or the session's refund pool (receives the
+    /// unspent escrow balance)
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == viewer_session.funded_by @ StreamingError::Unauthorized,
+        constraint = viewer_session.refund_pool == Pubkey::default()
+            || funder_token_account.key() == viewer_session.refund_pool @ StreamingError::Unauthorized
+    )]
+    pub funder_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Viewer's token account (its delegation is lowered by the unwatched allowance)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == viewer.key() @ StreamingError::Unauthorized
+    )]
+    pub viewer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Rent sponsorship vault (required for sponsored sessions)
+    #[account(
+        mut,
+        seeds = [SPONSORSHIP_VAULT_SEED],
+        bump = sponsorship_vault.bump
+    )]
+    pub sponsorship_vault: Option<Account<'info, SponsorshipVault>>,
 
     #[account(mut)]
     pub viewer: Signer<'info>,
+
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 pub fn close_viewer_session(ctx: Context<CloseViewerSession>) -> Result<()> {
     let viewer_session = &ctx.accounts.viewer_session;
     let clock = Clock::get()?;
 
+    // Refund unspent gift escrow to the gifter
+    let mut escrow_refunded = 0;
+    if viewer_session.is_gifted() {
+        let (Some(platform), Some(session_escrow), Some(funder_token_account), Some(token_program)) = (
+            ctx.accounts.platform.as_ref(),
+            ctx.accounts.session_escrow.as_ref(),
+            ctx.accounts.funder_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(StreamingError::SessionEscrowRequired);
+        };
+
+        let source = PaymentSource {
+            token_program: token_program.to_account_info(),
+            from: session_escrow.to_account_info(),
+            platform: platform.to_account_info(),
+            platform_bump: platform.bump,
+        };
+        escrow_refunded = session_escrow.amount;
+        source.transfer(funder_token_account.to_account_info(), escrow_refunded)?;
+        source.close(ctx.accounts.viewer.to_account_info())?;
+
+        emit!(RefundIssued {
+            viewer_session: viewer_session.key(),
+            video: ctx.accounts.video.key(),
+            recipient: viewer_session.funded_by,
+            amount: escrow_refunded,
+            from_escrow: true,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Release the unwatched allowance from the viewer's delegation
+    if let (Some(platform), Some(viewer_token_account), Some(token_program)) = (
+        ctx.accounts.platform.as_ref(),
+        ctx.accounts.viewer_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) {
+        let unwatched = viewer_session.remaining_allowance()?;
+        if unwatched > 0 && viewer_token_account.delegate == COption::Some(platform.key()) {
+            let released = unwatched.min(viewer_token_account.delegated_amount);
+            let remaining = viewer_token_account.delegated_amount - released;
+            let cpi_program = token_program.to_account_info();
+            if remaining == 0 {
+                token::revoke(CpiContext::new(
+                    cpi_program,
+                    Revoke {
+                        source: viewer_token_account.to_account_info(),
+                        authority: ctx.accounts.viewer.to_account_info(),
+                    },
+                ))?;
+            } else {
+                token::approve(
+                    CpiContext::new(
+                        cpi_program,
+                        Approve {
+                            to: viewer_token_account.to_account_info(),
+                            delegate: platform.to_account_info(),
+                            authority: ctx.accounts.viewer.to_account_info(),
+                        },
+                    ),
+                    remaining,
+                )?;
+            }
+
+            emit!(RefundIssued {
+                viewer_session: viewer_session.key(),
+                video: ctx.accounts.video.key(),
+                recipient: ctx.accounts.viewer.key(),
+                amount: released,
+                from_escrow: false,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    // Sponsored rent goes back to the vault (leaving nothing for the viewer)
+    if viewer_session.rent_sponsored {
+        let Some(sponsorship_vault) = ctx.accounts.sponsorship_vault.as_mut() else {
+            return err!(StreamingError::SponsorshipVaultRequired);
+        };
+        return_session_rent(
+            sponsorship_vault,
+            &ctx.accounts.viewer_session.to_account_info(),
+        )?;
+    }
+
     // Calculate refunded rent (lamports returned to viewer)
     let rent_lamports = ctx.accounts.viewer_session.to_account_info().lamports();
 
@@ -41,6 +184,7 @@ pub fn close_viewer_session(ctx: Context<CloseViewerSession>) -> Result<()> {
         viewer_session: viewer_session.key(),
         chunks_consumed: viewer_session.chunks_consumed,
         total_spent: viewer_session.total_spent,
+        escrow_refunded,
         timestamp: clock.unix_timestamp,
     });
 
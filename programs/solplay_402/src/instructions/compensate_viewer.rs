@@ -0,0 +1,113 @@
+// =============================================================================
+// Compensate Viewer Instruction
+// =============================================================================
+// Upholds an open settlement dispute and refunds the viewer from the insurance
+// fund. Unlike `resolve_dispute`, the creator's payout is left alone: the hold
+// is released and nothing is added to `penalty_outstanding`, so chargebacks
+// after payout don't have to be recovered from the creator. Either the
+// platform authority or the arbiter may compensate.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CompensateViewer<'info> {
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, dispute.viewer_session.as_ref(), &dispute.nonce.to_le_bytes()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ StreamingError::DisputeAlreadyResolved
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: Disputed session; its dispute count is released (unless it has
+    /// since been closed)
+    #[account(mut, address = dispute.viewer_session)]
+    pub viewer_session: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, dispute.video.as_ref()],
+        bump = creator_earnings.bump
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = signer.key() == platform.authority
+            || signer.key() == platform.arbiter @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump
+    )]
+    pub insurance_fund: Account<'info, TokenAccount>,
+
+    /// Viewer's token account (receives the compensation)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == dispute.viewer @ StreamingError::Unauthorized,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn compensate_viewer(ctx: Context<CompensateViewer>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let clock = Clock::get()?;
+
+    let amount = dispute.amount;
+    require!(
+        ctx.accounts.insurance_fund.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.insurance_fund.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.viewer_token_account.to_account_info(), amount)?;
+
+    // Release the hold; the creator keeps the disputed earnings
+    creator_earnings.disputed_amount = creator_earnings
+        .disputed_amount
+        .checked_sub(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    dispute.status = DisputeStatus::Compensated;
+    dispute.resolved_at = clock.unix_timestamp;
+    dispute.release_session(&ctx.accounts.viewer_session.to_account_info(), true)?;
+
+    emit!(ViewerCompensated {
+        dispute: dispute.key(),
+        viewer: dispute.viewer,
+        video: dispute.video,
+        signer: ctx.accounts.signer.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Viewer {} compensated {} from the insurance fund",
+        dispute.viewer,
+        amount
+    );
+
+    Ok(())
+}
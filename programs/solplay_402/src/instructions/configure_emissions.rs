@@ -0,0 +1,107 @@
+// =============================================================================
+// Configure Emissions Instruction
+// =============================================================================
+// Creates (on first call) the emission schedule and its vault for the
+// platform token `reward_mint`, and sets the schedule: tokens emitted per
+// settled chunk, halved every `halving_interval` seconds from the first
+// configuration, split between the video's creator and the viewer. Setting
+// the rate to zero pauses emissions.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ConfigureEmissions<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = EmissionSchedule::LEN,
+        seeds = [EMISSION_SCHEDULE_SEED],
+        bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    /// Vault holding unemitted platform tokens, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [EMISSION_VAULT_SEED],
+        bump,
+        token::mint = reward_mint,
+        token::authority = platform
+    )]
+    pub emission_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Platform token emitted as rewards
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_emissions(
+    ctx: Context<ConfigureEmissions>,
+    emission_per_chunk: u64,
+    halving_interval: i64,
+    creator_share_bps: u16,
+) -> Result<()> {
+    require!(
+        creator_share_bps as u64 <= BASIS_POINTS,
+        StreamingError::InvalidBasisPoints
+    );
+    require!(
+        halving_interval >= 0,
+        StreamingError::InvalidEmissionSchedule
+    );
+
+    let emission_schedule = &mut ctx.accounts.emission_schedule;
+    let reward_mint = ctx.accounts.reward_mint.key();
+    let clock = Clock::get()?;
+
+    if emission_schedule.reward_mint == Pubkey::default() {
+        emission_schedule.reward_mint = reward_mint;
+        emission_schedule.start_time = clock.unix_timestamp;
+    }
+    require_keys_eq!(
+        emission_schedule.reward_mint,
+        reward_mint,
+        StreamingError::InvalidTokenMint
+    );
+
+    emission_schedule.emission_per_chunk = emission_per_chunk;
+    emission_schedule.halving_interval = halving_interval;
+    emission_schedule.creator_share_bps = creator_share_bps;
+    emission_schedule.bump = ctx.bumps.emission_schedule;
+
+    emit!(EmissionsConfigured {
+        authority: ctx.accounts.authority.key(),
+        reward_mint,
+        emission_per_chunk,
+        halving_interval,
+        creator_share_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Emissions configured: {} tokens/chunk, creator share {} bps",
+        emission_per_chunk,
+        creator_share_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// =============================================================================
+// Configure Insurance Fund Instruction
+// =============================================================================
+// Sets the share of the (unburned) platform fee that settlements pay into the
+// insurance fund, a platform-owned token account created on first use. Upheld
+// disputes can then be refunded from the fund with `compensate_viewer`
+// instead of clawing the amount back from the creator.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ConfigureInsuranceFund<'info> {
+    /// Insurance fund, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [INSURANCE_FUND_SEED],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub insurance_fund: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_insurance_fund(
+    ctx: Context<ConfigureInsuranceFund>,
+    insurance_bps: u16,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        insurance_bps as u64 <= BASIS_POINTS,
+        StreamingError::InvalidBasisPoints
+    );
+    // Netted settlements can't route a fee share to the fund
+    require!(
+        insurance_bps == 0 || !platform.netting_enabled,
+        StreamingError::NettingUnsupported
+    );
+
+    platform.insurance_bps = insurance_bps;
+
+    emit!(InsuranceFundConfigured {
+        authority: ctx.accounts.authority.key(),
+        insurance_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Insurance fund share set to {} bps", insurance_bps);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// =============================================================================
+// Configure Rewards Pool Instruction
+// =============================================================================
+// Creates (on first call) the watch-to-earn rewards pool and its vault, and
+// sets the reward accrued to viewers per settled chunk. Setting the rate to
+// zero pauses accrual.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ConfigureRewardsPool<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RewardsPool::LEN,
+        seeds = [REWARDS_POOL_SEED],
+        bump
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    /// Vault holding undistributed rewards, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [REWARDS_VAULT_SEED],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_rewards_pool(
+    ctx: Context<ConfigureRewardsPool>,
+    reward_per_chunk: u64,
+) -> Result<()> {
+    let rewards_pool = &mut ctx.accounts.rewards_pool;
+    let clock = Clock::get()?;
+
+    rewards_pool.reward_per_chunk = reward_per_chunk;
+    rewards_pool.bump = ctx.bumps.rewards_pool;
+
+    emit!(RewardsPoolConfigured {
+        authority: ctx.accounts.authority.key(),
+        reward_per_chunk,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Rewards pool configured: {} tokens/chunk", reward_per_chunk);
+
+    Ok(())
+}
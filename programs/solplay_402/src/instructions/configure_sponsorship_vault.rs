@@ -0,0 +1,62 @@
+// =============================================================================
+// Configure Sponsorship Vault Instruction
+// =============================================================================
+// Creates (on first call) the platform's rent sponsorship vault and turns
+// sponsorship on or off. While enabled, new viewer sessions can pass the vault
+// to have their rent reimbursed from its lamports; the rent flows back to the
+// vault when the session is closed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfigureSponsorshipVault<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SponsorshipVault::LEN,
+        seeds = [SPONSORSHIP_VAULT_SEED],
+        bump
+    )]
+    pub sponsorship_vault: Account<'info, SponsorshipVault>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_sponsorship_vault(
+    ctx: Context<ConfigureSponsorshipVault>,
+    enabled: bool,
+) -> Result<()> {
+    let sponsorship_vault = &mut ctx.accounts.sponsorship_vault;
+    let clock = Clock::get()?;
+
+    sponsorship_vault.enabled = enabled;
+    sponsorship_vault.bump = ctx.bumps.sponsorship_vault;
+
+    emit!(SponsorshipVaultConfigured {
+        authority: ctx.accounts.authority.key(),
+        enabled,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Rent sponsorship {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+
+    Ok(())
+}
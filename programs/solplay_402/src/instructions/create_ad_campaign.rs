@@ -0,0 +1,128 @@
+// =============================================================================
+// Create Ad Campaign Instruction
+// =============================================================================
+// Advertisers deposit a budget into a campaign escrow, set a per-chunk
+// subsidy and pick the creators it may be spent on. Settlements on those
+// creators' ad-supported videos that include the campaign draw the subsidy
+// from the escrow and record the chunks as impressions. Creators can't aim
+// someone else's campaign at their own videos.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateAdCampaign<'info> {
+    #[account(
+        init,
+        payer = advertiser,
+        space = AdCampaign::LEN,
+        seeds = [AD_CAMPAIGN_SEED, advertiser.key().as_ref(), &campaign_id.to_le_bytes()],
+        bump
+    )]
+    pub ad_campaign: Account<'info, AdCampaign>,
+
+    /// Escrow holding the campaign budget, owned by the platform PDA
+    #[account(
+        init,
+        payer = advertiser,
+        seeds = [AD_CAMPAIGN_VAULT_SEED, ad_campaign.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub ad_campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Advertiser's token account (funds the campaign)
+    #[account(
+        mut,
+        constraint = advertiser_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = advertiser_token_account.owner == advertiser.key()
+    )]
+    pub advertiser_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub advertiser: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_ad_campaign(
+    ctx: Context<CreateAdCampaign>,
+    campaign_id: u64,
+    subsidy_per_chunk: u64,
+    deposit: u64,
+    target_creators: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        subsidy_per_chunk > 0 && deposit > 0,
+        StreamingError::InvalidAdCampaign
+    );
+    require!(
+        !target_creators.is_empty() && target_creators.len() <= MAX_AD_CAMPAIGN_CREATORS,
+        StreamingError::InvalidAdCampaign
+    );
+    require!(
+        ctx.accounts.advertiser_token_account.amount >= deposit,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.advertiser_token_account.to_account_info(),
+        to: ctx.accounts.ad_campaign_vault.to_account_info(),
+        authority: ctx.accounts.advertiser.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, deposit)?;
+
+    let ad_campaign = &mut ctx.accounts.ad_campaign;
+    ad_campaign.advertiser = ctx.accounts.advertiser.key();
+    ad_campaign.campaign_id = campaign_id;
+    ad_campaign.vault = ctx.accounts.ad_campaign_vault.key();
+    ad_campaign.subsidy_per_chunk = subsidy_per_chunk;
+    ad_campaign.total_deposited = deposit;
+    ad_campaign.total_spent = 0;
+    ad_campaign.impressions = 0;
+    ad_campaign.is_active = true;
+    ad_campaign.created_at = clock.unix_timestamp;
+    ad_campaign.bump = ctx.bumps.ad_campaign;
+    ad_campaign.target_creators = target_creators;
+
+    emit!(AdCampaignCreated {
+        ad_campaign: ad_campaign.key(),
+        advertiser: ad_campaign.advertiser,
+        campaign_id,
+        subsidy_per_chunk,
+        deposit,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Ad campaign {} created: {} tokens/chunk, {} deposited",
+        campaign_id,
+        subsidy_per_chunk,
+        deposit
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+// =============================================================================
+// Create Campaign Instruction (Crowdfunding)
+// =============================================================================
+// Creators pre-fund production of a video that does not exist yet. Backers
+// pledge into an escrow tied to the future `video_id`; the creator can claim
+// the funds once the goal is met and the video is published (create_video
+// with the same id) before the deadline. Otherwise backers are refunded.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(video_id: String)]
+pub struct CreateCampaign<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = FundingCampaign::LEN,
+        seeds = [FUNDING_CAMPAIGN_SEED, video_id.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, FundingCampaign>,
+
+    /// Escrow holding pledges, owned by the platform PDA
+    #[account(
+        init,
+        payer = creator,
+        seeds = [FUNDING_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_campaign(
+    ctx: Context<CreateCampaign>,
+    video_id: String,
+    goal: u64,
+    deadline: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        video_id.len() <= MAX_VIDEO_ID_LENGTH,
+        StreamingError::VideoIdTooLong
+    );
+    require!(goal > 0, StreamingError::InvalidFundingCampaign);
+    require!(
+        deadline > clock.unix_timestamp,
+        StreamingError::InvalidFundingCampaign
+    );
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.creator = ctx.accounts.creator.key();
+    campaign.video_id = video_id.clone();
+    campaign.goal = goal;
+    campaign.deadline = deadline;
+    campaign.total_pledged = 0;
+    campaign.claimed = false;
+    campaign.created_at = clock.unix_timestamp;
+    campaign.bump = ctx.bumps.campaign;
+
+    emit!(FundingCampaignCreated {
+        campaign: campaign.key(),
+        creator: campaign.creator,
+        video_id,
+        goal,
+        deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Funding campaign created: goal {} by {}", goal, deadline);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// =============================================================================
+// Create Coupon Instruction
+// =============================================================================
+// Promo codes discount the price locked into new sessions. The coupon PDA is
+// derived from the hash of the code, so clients holding the code can find it.
+//
+// - Created by the platform authority: applies to every video
+// - Created by anyone else: applies only to that creator's own videos
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(code_hash: [u8; 32])]
+pub struct CreateCoupon<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = Coupon::LEN,
+        seeds = [COUPON_SEED, code_hash.as_ref()],
+        bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_coupon(
+    ctx: Context<CreateCoupon>,
+    code_hash: [u8; 32],
+    discount_bps: u16,
+    max_uses: u32,
+    expiry: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        discount_bps > 0 && discount_bps as u64 <= BASIS_POINTS,
+        StreamingError::InvalidCoupon
+    );
+    require!(max_uses > 0, StreamingError::InvalidCoupon);
+    require!(expiry > clock.unix_timestamp, StreamingError::InvalidCoupon);
+
+    let issuer = ctx.accounts.issuer.key();
+    let creator = if issuer == ctx.accounts.platform.authority {
+        Pubkey::default()
+    } else {
+        issuer
+    };
+
+    let coupon = &mut ctx.accounts.coupon;
+    coupon.code_hash = code_hash;
+    coupon.issuer = issuer;
+    coupon.creator = creator;
+    coupon.discount_bps = discount_bps;
+    coupon.max_uses = max_uses;
+    coupon.uses = 0;
+    coupon.expiry = expiry;
+    coupon.bump = ctx.bumps.coupon;
+
+    emit!(CouponCreated {
+        coupon: coupon.key(),
+        issuer,
+        creator,
+        discount_bps,
+        max_uses,
+        expiry,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Coupon created: {} bps off, {} uses, expires {}",
+        discount_bps,
+        max_uses,
+        expiry
+    );
+
+    Ok(())
+}
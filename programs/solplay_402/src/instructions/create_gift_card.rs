@@ -0,0 +1,102 @@
+// =============================================================================
+// Create Gift Card Instruction
+// =============================================================================
+// Funds a prepaid gift card. The redemption code sold off-platform is the
+// seed of an Ed25519 keypair; the card PDA and its vault are derived from that
+// keypair's public key (`code_key`). Whoever holds the code can sign the
+// balance over to a wallet of their choice (see redeem_gift_card).
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, code_key: Pubkey)]
+pub struct CreateGiftCard<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = GiftCard::LEN,
+        seeds = [GIFT_CARD_SEED, code_key.as_ref()],
+        bump
+    )]
+    pub gift_card: Account<'info, GiftCard>,
+
+    /// Vault holding the card balance, owned by the platform PDA
+    #[account(
+        init,
+        payer = issuer,
+        seeds = [GIFT_CARD_VAULT_SEED, code_key.as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub gift_card_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Issuer's token account (funds the card)
+    #[account(
+        mut,
+        constraint = issuer_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = issuer_token_account.owner == issuer.key()
+    )]
+    pub issuer_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_gift_card(ctx: Context<CreateGiftCard>, amount: u64, code_key: Pubkey) -> Result<()> {
+    require!(amount > 0, StreamingError::InvalidGiftCardAmount);
+    require!(
+        ctx.accounts.issuer_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    // Fund the vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.issuer_token_account.to_account_info(),
+        to: ctx.accounts.gift_card_vault.to_account_info(),
+        authority: ctx.accounts.issuer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let gift_card = &mut ctx.accounts.gift_card;
+    gift_card.code_key = code_key;
+    gift_card.issuer = ctx.accounts.issuer.key();
+    gift_card.amount = amount;
+    gift_card.created_at = clock.unix_timestamp;
+    gift_card.bump = ctx.bumps.gift_card;
+
+    emit!(GiftCardCreated {
+        gift_card: gift_card.key(),
+        issuer: gift_card.issuer,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Gift card created: {} tokens", amount);
+
+    Ok(())
+}
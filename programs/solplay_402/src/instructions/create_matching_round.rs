@@ -0,0 +1,120 @@
+// =============================================================================
+// Create Matching Round Instruction (Quadratic Funding)
+// =============================================================================
+// A sponsor deposits a matching pool for a time window. Viewers who pay for a
+// creator's videos during the window are recorded as that creator's unique
+// supporters (record_matching_support); once the round ends the platform
+// distributes the pool in proportion to each creator's supporters squared.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct CreateMatchingRound<'info> {
+    #[account(
+        init,
+        payer = sponsor,
+        space = MatchingRound::LEN,
+        seeds = [MATCHING_ROUND_SEED, sponsor.key().as_ref(), &round_id.to_le_bytes()],
+        bump
+    )]
+    pub matching_round: Account<'info, MatchingRound>,
+
+    /// Escrow holding the matching pool, owned by the platform PDA
+    #[account(
+        init,
+        payer = sponsor,
+        seeds = [MATCHING_VAULT_SEED, matching_round.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub matching_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Sponsor's token account (funds the pool)
+    #[account(
+        mut,
+        constraint = sponsor_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = sponsor_token_account.owner == sponsor.key()
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_matching_round(
+    ctx: Context<CreateMatchingRound>,
+    round_id: u64,
+    pool: u64,
+    start_time: i64,
+    end_time: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        pool > 0 && start_time < end_time && end_time > clock.unix_timestamp,
+        StreamingError::InvalidMatchingRound
+    );
+    require!(
+        ctx.accounts.sponsor_token_account.amount >= pool,
+        StreamingError::InsufficientBalance
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.sponsor_token_account.to_account_info(),
+        to: ctx.accounts.matching_vault.to_account_info(),
+        authority: ctx.accounts.sponsor.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, pool)?;
+
+    let matching_round = &mut ctx.accounts.matching_round;
+    matching_round.sponsor = ctx.accounts.sponsor.key();
+    matching_round.round_id = round_id;
+    matching_round.vault = ctx.accounts.matching_vault.key();
+    matching_round.pool = pool;
+    matching_round.start_time = start_time;
+    matching_round.end_time = end_time;
+    matching_round.total_weight = 0;
+    matching_round.creators = 0;
+    matching_round.creators_matched = 0;
+    matching_round.distributed = 0;
+    matching_round.created_at = clock.unix_timestamp;
+    matching_round.bump = ctx.bumps.matching_round;
+
+    emit!(MatchingRoundCreated {
+        round: matching_round.key(),
+        sponsor: matching_round.sponsor,
+        round_id,
+        pool,
+        start_time,
+        end_time,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Matching round {} created with {} tokens", round_id, pool);
+
+    Ok(())
+}
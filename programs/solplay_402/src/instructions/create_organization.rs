@@ -0,0 +1,86 @@
+// =============================================================================
+// Create Organization Instruction
+// =============================================================================
+// Sets up a shared viewing budget for a school or company: the admin's
+// Organization account and its treasury, a token account owned by the
+// organization PDA. Members added with add_organization_member open sessions
+// paid from the treasury (start_organization_session); unspent session
+// escrows flow back into it when the sessions are closed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CreateOrganization<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Organization::LEN,
+        seeds = [ORGANIZATION_SEED, admin.key().as_ref()],
+        bump
+    )]
+    pub organization: Box<Account<'info, Organization>>,
+
+    /// Shared budget, owned by the organization PDA
+    #[account(
+        init,
+        payer = admin,
+        seeds = [ORGANIZATION_TREASURY_SEED, organization.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = organization
+    )]
+    pub organization_treasury: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_organization(ctx: Context<CreateOrganization>, seats: u16) -> Result<()> {
+    require!(
+        seats > 0 && seats as usize <= MAX_ORGANIZATION_SEATS,
+        StreamingError::InvalidSeatCount
+    );
+
+    let organization = &mut ctx.accounts.organization;
+    let clock = Clock::get()?;
+
+    organization.set_inner(Organization {
+        admin: ctx.accounts.admin.key(),
+        seats,
+        members: Vec::new(),
+        total_spent: 0,
+        bump: ctx.bumps.organization,
+    });
+
+    emit!(OrganizationCreated {
+        organization: organization.key(),
+        admin: organization.admin,
+        seats,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Organization created: {} seats", seats);
+
+    Ok(())
+}
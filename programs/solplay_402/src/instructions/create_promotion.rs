@@ -0,0 +1,125 @@
+// =============================================================================
+// Create Promotion Instruction (Sponsored Placement)
+// =============================================================================
+// A sponsor deposits a budget and a bid per impression to promote a video.
+// Active Promotion accounts are the on-chain source of which videos are
+// currently paying for placement; the platform draws down the budget as it
+// records promoted impressions.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct CreatePromotion<'info> {
+    #[account(
+        init,
+        payer = sponsor,
+        space = Promotion::LEN,
+        seeds = [PROMOTION_SEED, video.key().as_ref(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub promotion: Account<'info, Promotion>,
+
+    /// Escrow holding the promotion budget, owned by the platform PDA
+    #[account(
+        init,
+        payer = sponsor,
+        seeds = [PROMOTION_VAULT_SEED, promotion.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub promotion_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Sponsor's token account (funds the budget)
+    #[account(
+        mut,
+        constraint = sponsor_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = sponsor_token_account.owner == sponsor.key()
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_promotion(
+    ctx: Context<CreatePromotion>,
+    budget: u64,
+    bid_per_impression: u64,
+) -> Result<()> {
+    require!(
+        bid_per_impression > 0 && budget >= bid_per_impression,
+        StreamingError::InvalidPromotion
+    );
+    require!(
+        ctx.accounts.sponsor_token_account.amount >= budget,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.sponsor_token_account.to_account_info(),
+        to: ctx.accounts.promotion_vault.to_account_info(),
+        authority: ctx.accounts.sponsor.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, budget)?;
+
+    let promotion = &mut ctx.accounts.promotion;
+    promotion.video = ctx.accounts.video.key();
+    promotion.sponsor = ctx.accounts.sponsor.key();
+    promotion.vault = ctx.accounts.promotion_vault.key();
+    promotion.bid_per_impression = bid_per_impression;
+    promotion.budget = budget;
+    promotion.spent = 0;
+    promotion.impressions = 0;
+    promotion.is_active = true;
+    promotion.created_at = clock.unix_timestamp;
+    promotion.bump = ctx.bumps.promotion;
+
+    emit!(PromotionCreated {
+        promotion: promotion.key(),
+        video: promotion.video,
+        sponsor: promotion.sponsor,
+        budget,
+        bid_per_impression,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Promotion created: {} tokens at {} per impression",
+        budget,
+        bid_per_impression
+    );
+
+    Ok(())
+}
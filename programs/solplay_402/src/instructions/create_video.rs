@@ -1,6 +1,10 @@
 // =============================================================================
 // Create Video Instruction
 // =============================================================================
+// The signer publishes for themselves, or, when passing a creator team on
+// which they hold the uploader role, for the team's creator: the video and its
+// earnings then belong to that creator, who must not be suspended either.
+// =============================================================================
 
 use crate::constants::*;
 use crate::errors::*;
@@ -14,7 +18,7 @@ const MAX_VIDEO_CHUNKS: u32 = MAX_TOTAL_CHUNKS;
 const MAX_VIDEO_ID_LEN: usize = MAX_VIDEO_ID_LENGTH;
 const MAX_IPFS_HASH_LEN: usize = MAX_IPFS_HASH_LENGTH;
 const MAX_TITLE_LEN: usize = MAX_TITLE_LENGTH;
-const MAX_DESCRIPTION_LEN: usize = MAX_DESCRIPTION_LENGTH;
+const MAX_METADATA_URI_LEN: usize = MAX_METADATA_URI_LENGTH;
 
 #[derive(Accounts)]
 #[instruction(video_id: String)]
@@ -44,12 +48,66 @@ pub struct CreateVideo<'info> {
     )]
     pub platform: Account<'info, Platform>,
 
+    /// CHECK: Creator suspension marker; must not exist to publish
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, creator.key().as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// Team of the creator being published for (uploaders only)
+    #[account(
+        seeds = [CREATOR_TEAM_SEED, creator_team.creator.as_ref()],
+        bump = creator_team.bump
+    )]
+    pub creator_team: Option<Box<Account<'info, CreatorTeam>>>,
+
+    /// CHECK: Team creator's suspension marker (required with `creator_team`);
+    /// address and emptiness are checked in the handler
+    pub team_creator_suspension: Option<UncheckedAccount<'info>>,
+
+    /// Creator, or an uploader publishing for their team's creator (pays rent)
     #[account(mut)]
     pub creator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+impl VideoParams {
+    /// Validate inputs BEFORE event emission to prevent tx/log size failures.
+    /// These limits are enforced to protect VideoCreated event serialization
+    pub fn validate(&self, platform: &Platform) -> Result<()> {
+        require!(
+            self.video_id.len() <= MAX_VIDEO_ID_LEN,
+            StreamingError::VideoIdTooLong
+        );
+        require!(
+            self.ipfs_hash.len() <= MAX_IPFS_HASH_LEN,
+            StreamingError::IpfsHashTooLong
+        );
+        require!(
+            self.thumbnail_cid.len() <= MAX_IPFS_HASH_LEN
+                && self.poster_cid.len() <= MAX_IPFS_HASH_LEN,
+            StreamingError::IpfsHashTooLong
+        );
+        require!(
+            self.title.len() <= MAX_TITLE_LEN,
+            StreamingError::TitleTooLong
+        );
+        require!(
+            self.metadata_uri.len() <= MAX_METADATA_URI_LEN,
+            StreamingError::MetadataUriTooLong
+        );
+        require!(
+            self.total_chunks >= MIN_VIDEO_CHUNKS && self.total_chunks <= MAX_VIDEO_CHUNKS,
+            StreamingError::TooManyChunks
+        );
+        platform.check_chunk_price(self.price_per_chunk)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn create_video(
     ctx: Context<CreateVideo>,
     video_id: String,
@@ -57,58 +115,71 @@ pub fn create_video(
     total_chunks: u32,
     price_per_chunk: u64,
     title: String,
-    description: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+    chunk_merkle_root: [u8; 32],
+    thumbnail_cid: String,
+    poster_cid: String,
 ) -> Result<()> {
-    // Validate inputs BEFORE event emission to prevent tx/log size failures
-    // These limits are enforced to protect VideoCreated event serialization
-    require!(
-        video_id.len() <= MAX_VIDEO_ID_LEN,
-        StreamingError::VideoIdTooLong
-    );
-    require!(
-        ipfs_hash.len() <= MAX_IPFS_HASH_LEN,
-        StreamingError::IpfsHashTooLong
-    );
-    require!(title.len() <= MAX_TITLE_LEN, StreamingError::TitleTooLong);
-    require!(
-        description.len() <= MAX_DESCRIPTION_LEN,
-        StreamingError::DescriptionTooLong
-    );
-    require!(
-        total_chunks >= MIN_VIDEO_CHUNKS && total_chunks <= MAX_VIDEO_CHUNKS,
-        StreamingError::TooManyChunks
-    );
-    require!(
-        price_per_chunk >= ctx.accounts.platform.min_price_per_chunk,
-        StreamingError::PriceTooLow
-    );
+    let params = VideoParams {
+        video_id,
+        ipfs_hash,
+        total_chunks,
+        price_per_chunk,
+        title,
+        metadata_uri,
+        metadata_hash,
+        chunk_merkle_root,
+        thumbnail_cid,
+        poster_cid,
+    };
+    params.validate(&ctx.accounts.platform)?;
+
+    // Resolve who the video belongs to
+    let signer = ctx.accounts.creator.key();
+    let owner = match ctx.accounts.creator_team.as_ref() {
+        Some(team) => {
+            require!(
+                team.has_role(&signer, TeamRole::Uploader),
+                StreamingError::Unauthorized
+            );
+            let (marker, _) = Pubkey::find_program_address(
+                &[SUSPENDED_CREATOR_SEED, team.creator.as_ref()],
+                &crate::ID,
+            );
+            let suspension = ctx
+                .accounts
+                .team_creator_suspension
+                .as_ref()
+                .ok_or(StreamingError::InvalidSuspensionMarker)?;
+            require_keys_eq!(
+                suspension.key(),
+                marker,
+                StreamingError::InvalidSuspensionMarker
+            );
+            require!(suspension.data_is_empty(), StreamingError::CreatorSuspended);
+            team.creator
+        }
+        None => signer,
+    };
 
     let video = &mut ctx.accounts.video;
     let creator_earnings = &mut ctx.accounts.creator_earnings;
     let platform = &mut ctx.accounts.platform;
     let clock = Clock::get()?;
 
-    // Initialize video
-    video.creator = ctx.accounts.creator.key();
-    video.video_id = video_id.clone();
-    video.ipfs_hash = ipfs_hash.clone();
-    video.total_chunks = total_chunks;
-    video.price_per_chunk = price_per_chunk;
-    video.title = title.clone();
-    video.description = description.clone();
-    video.is_active = true;
-    video.total_sessions = 0;
-    video.total_chunks_served = 0;
-    video.created_at = clock.unix_timestamp;
-    video.bump = ctx.bumps.video;
-
-    // Initialize creator earnings
-    creator_earnings.creator = ctx.accounts.creator.key();
-    creator_earnings.video = video.key();
-    creator_earnings.total_earned = 0;
-    creator_earnings.total_sessions = 0;
-    creator_earnings.total_chunks_sold = 0;
-    creator_earnings.bump = ctx.bumps.creator_earnings;
+    // Initialize video and creator earnings
+    video.set_inner(Video::new(
+        owner,
+        params,
+        clock.unix_timestamp,
+        ctx.bumps.video,
+    ));
+    creator_earnings.set_inner(CreatorEarnings::new(
+        owner,
+        video.key(),
+        ctx.bumps.creator_earnings,
+    ));
 
     // Update platform stats
     platform.total_videos = platform
@@ -118,19 +189,19 @@ pub fn create_video(
 
     emit!(VideoCreated {
         video: video.key(),
-        creator: ctx.accounts.creator.key(),
-        video_id,
-        ipfs_hash,
-        total_chunks,
-        price_per_chunk,
-        title,
+        creator: owner,
+        video_id: video.video_id.clone(),
+        ipfs_hash: video.ipfs_hash.clone(),
+        total_chunks: video.total_chunks,
+        price_per_chunk: video.price_per_chunk,
+        title: video.title.clone(),
         timestamp: clock.unix_timestamp,
     });
 
     msg!(
         "Video created: {} chunks @ {} tokens/chunk",
-        total_chunks,
-        price_per_chunk
+        video.total_chunks,
+        video.price_per_chunk
     );
 
     Ok(())
@@ -0,0 +1,127 @@
+// =============================================================================
+// Create Videos Batch Instruction
+// =============================================================================
+// Publishes up to MAX_VIDEO_BATCH videos in one transaction, for creators
+// migrating a back-catalog. Each entry is validated exactly like create_video
+// and the new accounts are passed as remaining accounts, in pairs:
+//   [2i]     the Video PDA for videos[i].video_id (writable, uninitialized)
+//   [2i + 1] its CreatorEarnings PDA (writable, uninitialized)
+// Videos are always published for the signing creator.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+
+#[derive(Accounts)]
+pub struct CreateVideosBatch<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// CHECK: Creator suspension marker; must not exist to publish
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, creator.key().as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the program-owned PDA at `seeds`, failing if it already exists
+fn create_pda<'info>(
+    ctx: &Context<'_, '_, '_, 'info, CreateVideosBatch<'info>>,
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    space: usize,
+) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), address, StreamingError::InvalidVideoBatch);
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.creator.to_account_info(),
+                to: account.clone(),
+            },
+            &[&[seeds, &[&[bump][..]]].concat()[..]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+    Ok(bump)
+}
+
+pub fn create_videos_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateVideosBatch<'info>>,
+    videos: Vec<VideoParams>,
+) -> Result<()> {
+    require!(
+        !videos.is_empty()
+            && videos.len() <= MAX_VIDEO_BATCH
+            && ctx.remaining_accounts.len() == videos.len() * 2,
+        StreamingError::InvalidVideoBatch
+    );
+
+    let owner = ctx.accounts.creator.key();
+    let clock = Clock::get()?;
+    let count = videos.len();
+
+    for (params, accounts) in videos.into_iter().zip(ctx.remaining_accounts.chunks(2)) {
+        params.validate(&ctx.accounts.platform)?;
+        let (video_info, earnings_info) = (&accounts[0], &accounts[1]);
+
+        let video_bump = create_pda(
+            &ctx,
+            video_info,
+            &[VIDEO_SEED, params.video_id.as_bytes()],
+            Video::MAX_LEN,
+        )?;
+        let earnings_bump = create_pda(
+            &ctx,
+            earnings_info,
+            &[CREATOR_EARNINGS_SEED, video_info.key.as_ref()],
+            CreatorEarnings::LEN,
+        )?;
+
+        let video = Video::new(owner, params, clock.unix_timestamp, video_bump);
+        video.try_serialize(&mut &mut video_info.try_borrow_mut_data()?[..])?;
+        CreatorEarnings::new(owner, video_info.key(), earnings_bump)
+            .try_serialize(&mut &mut earnings_info.try_borrow_mut_data()?[..])?;
+
+        emit!(VideoCreated {
+            video: video_info.key(),
+            creator: owner,
+            video_id: video.video_id,
+            ipfs_hash: video.ipfs_hash,
+            total_chunks: video.total_chunks,
+            price_per_chunk: video.price_per_chunk,
+            title: video.title,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Update platform stats
+    let platform = &mut ctx.accounts.platform;
+    platform.total_videos = platform
+        .total_videos
+        .checked_add(count as u64)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    msg!("Batch created {} videos", count);
+
+    Ok(())
+}
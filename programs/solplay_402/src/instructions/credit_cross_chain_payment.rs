@@ -0,0 +1,316 @@
+// =============================================================================
+// Credit Cross-Chain Payment Instruction
+// =============================================================================
+// Opens a prepaid session for a viewer who paid on another chain. The payment
+// is proven by a Wormhole VAA from the chain's registered emitter, posted to
+// Solana by the core bridge (see wormhole.rs). Anyone may relay it; each VAA
+// is credited once (tracked by a receipt per emitter sequence).
+//
+// The session works like a gifted one: its escrow is funded from the
+// cross-chain float at the current price, settlements are paid from the
+// escrow, and the unspent balance returns to the float when it is closed
+// (the session's funder is the platform PDA).
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use crate::wormhole::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16, sequence: u64, viewer: Pubkey, session_nonce: u8)]
+pub struct CreditCrossChainPayment<'info> {
+    #[account(
+        init,
+        payer = relayer,
+        space = ViewerSession::LEN,
+        seeds = [VIEWER_SESSION_SEED, viewer.as_ref(), video.key().as_ref(), &[session_nonce]],
+        bump
+    )]
+    pub viewer_session: Box<Account<'info, ViewerSession>>,
+
+    /// Escrow holding the credited funds, owned by the platform PDA
+    #[account(
+        init,
+        payer = relayer,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub session_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// First-view marker; counts the viewer towards the video's unique viewers
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = ViewedMarker::LEN,
+        seeds = [VIEWED_MARKER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump
+    )]
+    pub viewed_marker: Box<Account<'info, ViewedMarker>>,
+
+    /// Marks the VAA as credited
+    #[account(
+        init,
+        payer = relayer,
+        space = CrossChainReceipt::LEN,
+        seeds = [CROSS_CHAIN_RECEIPT_SEED, cross_chain_emitter.key().as_ref(), &sequence.to_le_bytes()],
+        bump
+    )]
+    pub cross_chain_receipt: Box<Account<'info, CrossChainReceipt>>,
+
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_EMITTER_SEED, &chain_id.to_le_bytes()],
+        bump = cross_chain_emitter.bump,
+        constraint = cross_chain_emitter.enabled @ StreamingError::CrossChainEmitterDisabled
+    )]
+    pub cross_chain_emitter: Box<Account<'info, CrossChainEmitter>>,
+
+    #[account(
+        mut,
+        seeds = [CROSS_CHAIN_VAULT_SEED],
+        bump
+    )]
+    pub cross_chain_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PostedVAA account; ownership by the core bridge is checked in the handler
+    pub posted_vaa: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Box<Account<'info, Video>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Allowlist entry (required when the video is private)
+    #[account(
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump = allowed_viewer.bump
+    )]
+    pub allowed_viewer: Option<Account<'info, AllowedViewer>>,
+
+    /// Age attestation (required when the video is rated mature)
+    #[account(
+        seeds = [AGE_ATTESTATION_SEED, viewer.as_ref()],
+        bump = age_attestation.bump
+    )]
+    pub age_attestation: Option<Box<Account<'info, AgeAttestation>>>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, video.creator.as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to be credited
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn credit_cross_chain_payment(
+    ctx: Context<CreditCrossChainPayment>,
+    chain_id: u16,
+    sequence: u64,
+    viewer: Pubkey,
+    session_nonce: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // The VAA must come from the registered emitter and describe this session
+    let cross_chain_emitter = &ctx.accounts.cross_chain_emitter;
+    let vaa = load_posted_vaa(&ctx.accounts.posted_vaa, &cross_chain_emitter.core_bridge)?;
+    require!(
+        vaa.emitter_chain == chain_id
+            && vaa.emitter_address == cross_chain_emitter.emitter_address
+            && vaa.sequence == sequence,
+        StreamingError::InvalidVaa
+    );
+    let payment = CrossChainPayment::decode(&vaa.payload)?;
+    require!(
+        payment.viewer == viewer
+            && payment.video == ctx.accounts.video.key()
+            && payment.session_nonce == session_nonce,
+        StreamingError::CrossChainPaymentMismatch
+    );
+
+    let max_chunks = payment.max_chunks;
+    require!(
+        max_chunks > 0 && max_chunks <= ctx.accounts.video.max_units_per_approval(),
+        StreamingError::MaxChunksPerApprovalExceeded
+    );
+    require!(
+        !ctx.accounts.video.is_private || ctx.accounts.allowed_viewer.is_some(),
+        StreamingError::ViewerNotAllowed
+    );
+    require!(
+        !ctx.accounts.video.requires_age_attestation() || ctx.accounts.age_attestation.is_some(),
+        StreamingError::AgeAttestationRequired
+    );
+    // Region-restricted videos need an attested approval (approve_streaming_delegate)
+    require!(
+        ctx.accounts.video.region_policy.is_none(),
+        StreamingError::RegionAttestationRequired
+    );
+
+    // Count first-time viewers of the video
+    let video_key = ctx.accounts.video.key();
+    ctx.accounts.viewed_marker.record_view(
+        &mut ctx.accounts.video,
+        video_key,
+        viewer,
+        clock.unix_timestamp,
+        ctx.bumps.viewed_marker,
+    )?;
+
+    let video = &ctx.accounts.video;
+    video.check_publish_window(clock.unix_timestamp)?;
+
+    let price_per_chunk = video.unit_price();
+    let escrow_amount = u64::try_from(
+        (price_per_chunk as u128)
+            .checked_mul(max_chunks as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?,
+    )
+    .map_err(|_| StreamingError::ArithmeticOverflow)?;
+
+    // The viewer paid for the chunks at no less than the current price
+    require!(
+        payment.amount >= escrow_amount,
+        StreamingError::PriceChangedSinceApproval
+    );
+    require!(
+        ctx.accounts.cross_chain_vault.amount >= escrow_amount,
+        StreamingError::InsufficientBalance
+    );
+
+    // Fund the escrow from the float
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.cross_chain_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.session_escrow.to_account_info(), escrow_amount)?;
+
+    // Create the viewer's session at the current price
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    viewer_session.viewer = viewer;
+    viewer_session.video = video.key();
+    viewer_session.max_approved_chunks = max_chunks;
+    viewer_session.chunks_consumed = 0;
+    viewer_session.total_spent = 0;
+    viewer_session.approved_price_per_chunk = price_per_chunk;
+    viewer_session.list_price_per_chunk = price_per_chunk;
+    viewer_session.session_start = clock.unix_timestamp;
+    viewer_session.last_activity = clock.unix_timestamp;
+    viewer_session.bump = ctx.bumps.viewer_session;
+    viewer_session.quality_reports = 0;
+    viewer_session.verified_quality_reports = 0;
+    viewer_session.funded_by = ctx.accounts.platform.key();
+    viewer_session.billing_mode = video.billing_mode;
+    viewer_session.quality_tier = None;
+    viewer_session.settlement_count = 0;
+    viewer_session.last_settled_at = 0;
+    viewer_session.disputed_chunks = 0;
+    viewer_session.last_operator = Pubkey::default();
+    viewer_session.recent_settlement_ids = [[0u8; 16]; SETTLEMENT_ID_HISTORY];
+    viewer_session.last_watched_chunk = 0;
+    viewer_session.session_nonce = session_nonce;
+    viewer_session.auto_renew = false;
+    viewer_session.renewal_chunks = 0;
+    viewer_session.renewal_budget_chunks = 0;
+    viewer_session.rent_sponsored = false;
+    viewer_session.session_key = Pubkey::default();
+    viewer_session.evm_address = [0u8; 20];
+    viewer_session.passkey = [0u8; 33];
+    viewer_session.settlement_consent = [0u8; 32];
+    viewer_session.watermark_id = viewer_session.derive_watermark_id();
+    viewer_session.attested_region = [0u8; 2];
+    viewer_session.price_breaks = video.session_price_breaks(true);
+    viewer_session.refund_policy = video.refund_policy;
+    viewer_session.value_window_start = 0;
+    viewer_session.value_window_settled = 0;
+    viewer_session.client_tag = [0u8; 32];
+    viewer_session.refund_pool = ctx.accounts.cross_chain_vault.key();
+    viewer_session.refunded_chunks = 0;
+
+    let cross_chain_receipt = &mut ctx.accounts.cross_chain_receipt;
+    cross_chain_receipt.emitter = ctx.accounts.cross_chain_emitter.key();
+    cross_chain_receipt.sequence = sequence;
+    cross_chain_receipt.viewer_session = viewer_session.key();
+    cross_chain_receipt.amount = payment.amount;
+    cross_chain_receipt.credited_at = clock.unix_timestamp;
+    cross_chain_receipt.bump = ctx.bumps.cross_chain_receipt;
+
+    let cross_chain_emitter = &mut ctx.accounts.cross_chain_emitter;
+    cross_chain_emitter.payments_credited = cross_chain_emitter
+        .payments_credited
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    cross_chain_emitter.total_credited = cross_chain_emitter
+        .total_credited
+        .checked_add(escrow_amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let platform = &mut ctx.accounts.platform;
+    platform.total_sessions = platform
+        .total_sessions
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(CrossChainPaymentCredited {
+        viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        chain_id,
+        sequence,
+        amount: payment.amount,
+        max_chunks,
+        escrow_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Credited cross-chain payment {}/{} for {}: {} chunks, {} tokens in escrow",
+        chain_id,
+        sequence,
+        viewer,
+        max_chunks,
+        escrow_amount
+    );
+
+    Ok(())
+}
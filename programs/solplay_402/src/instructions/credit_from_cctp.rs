@@ -0,0 +1,266 @@
+// =============================================================================
+// Credit From CCTP Instruction
+// =============================================================================
+// Funds a viewer's prepaid session with USDC bridged through Circle CCTP, so
+// the whole fund-and-watch flow can start on any CCTP-supported chain. The
+// deposit burns USDC on the source chain with the session escrow (derivable
+// from viewer, video and session nonce) as mint recipient; this instruction
+// creates the session and escrow if needed, receives the attested message
+// (minting into the escrow, see cctp.rs), and credits the minted amount as
+// chunks at the session's price.
+//
+// The session is prepaid by the viewer themselves: settlements are paid from
+// the escrow and the unspent balance is refunded to the viewer on close.
+// Remaining accounts: the MessageTransmitter receive_message accounts.
+// =============================================================================
+
+use crate::cctp::*;
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(viewer: Pubkey, session_nonce: u8)]
+pub struct CreditFromCctp<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ViewerSession::LEN,
+        seeds = [VIEWER_SESSION_SEED, viewer.as_ref(), video.key().as_ref(), &[session_nonce]],
+        bump
+    )]
+    pub viewer_session: Box<Account<'info, ViewerSession>>,
+
+    /// Prepaid escrow (the CCTP mint recipient), owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub session_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// First-view marker; counts the viewer towards the video's unique viewers
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ViewedMarker::LEN,
+        seeds = [VIEWED_MARKER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump
+    )]
+    pub viewed_marker: Box<Account<'info, ViewedMarker>>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Box<Account<'info, Video>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Box<Account<'info, Platform>>,
+
+    /// USDC mint - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Allowlist entry (required when the video is private)
+    #[account(
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), viewer.as_ref()],
+        bump = allowed_viewer.bump
+    )]
+    pub allowed_viewer: Option<Account<'info, AllowedViewer>>,
+
+    /// Age attestation (required when the video is rated mature)
+    #[account(
+        seeds = [AGE_ATTESTATION_SEED, viewer.as_ref()],
+        bump = age_attestation.bump
+    )]
+    pub age_attestation: Option<Box<Account<'info, AgeAttestation>>>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, video.creator.as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to be credited
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// CHECK: Circle CCTP MessageTransmitter program
+    #[account(address = MESSAGE_TRANSMITTER_PROGRAM_ID)]
+    pub message_transmitter_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn credit_from_cctp<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreditFromCctp<'info>>,
+    viewer: Pubkey,
+    session_nonce: u8,
+    message: Vec<u8>,
+    attestation: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    check_burn_message(&message, &ctx.accounts.session_escrow.key())?;
+
+    let is_new_session = ctx.accounts.viewer_session.session_start == 0;
+    if is_new_session {
+        require!(
+            !ctx.accounts.video.is_private || ctx.accounts.allowed_viewer.is_some(),
+            StreamingError::ViewerNotAllowed
+        );
+        require!(
+            !ctx.accounts.video.requires_age_attestation()
+                || ctx.accounts.age_attestation.is_some(),
+            StreamingError::AgeAttestationRequired
+        );
+        // Region-restricted videos need an attested approval (approve_streaming_delegate)
+        require!(
+            ctx.accounts.video.region_policy.is_none(),
+            StreamingError::RegionAttestationRequired
+        );
+
+        // Count first-time viewers of the video
+        let video_key = ctx.accounts.video.key();
+        ctx.accounts.viewed_marker.record_view(
+            &mut ctx.accounts.video,
+            video_key,
+            viewer,
+            clock.unix_timestamp,
+            ctx.bumps.viewed_marker,
+        )?;
+        ctx.accounts
+            .video
+            .check_publish_window(clock.unix_timestamp)?;
+    } else {
+        require!(
+            ctx.accounts.viewer_session.funded_by == viewer,
+            StreamingError::PrepaidSessionMismatch
+        );
+    }
+
+    // Mint the bridged USDC into the escrow
+    let balance_before = ctx.accounts.session_escrow.amount;
+    receive_message(
+        &ctx.accounts.message_transmitter_program.to_account_info(),
+        ctx.remaining_accounts,
+        message,
+        attestation,
+    )?;
+    ctx.accounts.session_escrow.reload()?;
+    let amount = ctx
+        .accounts
+        .session_escrow
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    require!(amount > 0, StreamingError::CctpMintNotReceived);
+
+    let video = &ctx.accounts.video;
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    if is_new_session {
+        let price_per_chunk = video.unit_price();
+        viewer_session.viewer = viewer;
+        viewer_session.video = video.key();
+        viewer_session.max_approved_chunks = 0;
+        viewer_session.chunks_consumed = 0;
+        viewer_session.total_spent = 0;
+        viewer_session.approved_price_per_chunk = price_per_chunk;
+        viewer_session.list_price_per_chunk = price_per_chunk;
+        viewer_session.session_start = clock.unix_timestamp;
+        viewer_session.last_activity = clock.unix_timestamp;
+        viewer_session.bump = ctx.bumps.viewer_session;
+        viewer_session.quality_reports = 0;
+        viewer_session.verified_quality_reports = 0;
+        viewer_session.funded_by = viewer;
+        viewer_session.billing_mode = video.billing_mode;
+        viewer_session.quality_tier = None;
+        viewer_session.settlement_count = 0;
+        viewer_session.last_settled_at = 0;
+        viewer_session.disputed_chunks = 0;
+        viewer_session.last_operator = Pubkey::default();
+        viewer_session.recent_settlement_ids = [[0u8; 16]; SETTLEMENT_ID_HISTORY];
+        viewer_session.last_watched_chunk = 0;
+        viewer_session.session_nonce = session_nonce;
+        viewer_session.auto_renew = false;
+        viewer_session.renewal_chunks = 0;
+        viewer_session.renewal_budget_chunks = 0;
+        viewer_session.rent_sponsored = false;
+        viewer_session.session_key = Pubkey::default();
+        viewer_session.evm_address = [0u8; 20];
+        viewer_session.passkey = [0u8; 33];
+        viewer_session.settlement_consent = [0u8; 32];
+        viewer_session.watermark_id = viewer_session.derive_watermark_id();
+        viewer_session.attested_region = [0u8; 2];
+        viewer_session.price_breaks = Vec::new();
+        viewer_session.refund_policy = RefundPolicy::Always;
+        viewer_session.value_window_start = 0;
+        viewer_session.value_window_settled = 0;
+        viewer_session.client_tag = [0u8; 32];
+        viewer_session.refund_pool = Pubkey::default();
+        viewer_session.refunded_chunks = 0;
+
+        let platform = &mut ctx.accounts.platform;
+        platform.total_sessions = platform
+            .total_sessions
+            .checked_add(1)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+
+    // Credit whole chunks up to the per-approval cap; any remainder stays in
+    // the escrow and is refunded on close
+    let purchasable = amount / viewer_session.approved_price_per_chunk.max(1);
+    let headroom = video
+        .max_units_per_approval()
+        .saturating_sub(viewer_session.max_approved_chunks);
+    let added_chunks = u32::try_from(purchasable).unwrap_or(u32::MAX).min(headroom);
+    viewer_session.max_approved_chunks = viewer_session
+        .max_approved_chunks
+        .checked_add(added_chunks)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(CctpDepositCredited {
+        viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        amount,
+        added_chunks,
+        max_approved_chunks: viewer_session.max_approved_chunks,
+        is_new_session,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "CCTP deposit of {} USDC credited to {}: +{} chunks",
+        amount,
+        viewer_session.key(),
+        added_chunks
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+// =============================================================================
+// Deactivate Videos Batch Instruction (Emergency Takedown)
+// =============================================================================
+// Lets the platform authority pull many videos at once, e.g. abuse uploaded
+// from a compromised creator account. Every Video passed as a remaining
+// account (writable) is deactivated and a VideoTakenDown event is emitted for
+// it. Pair with suspend_creator so the creator can't simply reactivate them.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DeactivateVideosBatch<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn deactivate_videos_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeactivateVideosBatch<'info>>,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        StreamingError::InvalidVideoBatch
+    );
+
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    for video_info in ctx.remaining_accounts {
+        let mut video = Account::<Video>::try_from(video_info)?;
+        video.is_active = false;
+        video.exit(&crate::ID)?;
+
+        emit!(VideoTakenDown {
+            authority,
+            video: video_info.key(),
+            creator: video.creator,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("{} videos taken down", ctx.remaining_accounts.len());
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// =============================================================================
+// Dispute Batch Leaf Instruction
+// =============================================================================
+// Lets a viewer contest the chunk count a Merkle batch charged them for. The
+// viewer proves their (viewer, chunk_count) leaf is under the batch root, and
+// a marker account records the dispute (one per leaf) for the arbiter to
+// review, within the platform's dispute window from the batch settlement.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::merkle::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(leaf_index: u32)]
+pub struct DisputeBatchLeaf<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = BatchLeafDispute::LEN,
+        seeds = [BATCH_LEAF_DISPUTE_SEED, merkle_batch.key().as_ref(), &leaf_index.to_le_bytes()],
+        bump
+    )]
+    pub batch_leaf_dispute: Account<'info, BatchLeafDispute>,
+
+    #[account(
+        mut,
+        seeds = [MERKLE_BATCH_SEED, merkle_batch.video.as_ref(), &merkle_batch.batch_id.to_le_bytes()],
+        bump = merkle_batch.bump
+    )]
+    pub merkle_batch: Account<'info, MerkleBatch>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn dispute_batch_leaf(
+    ctx: Context<DisputeBatchLeaf>,
+    leaf_index: u32,
+    chunk_count: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let merkle_batch = &mut ctx.accounts.merkle_batch;
+    let platform = &ctx.accounts.platform;
+    let viewer = ctx.accounts.viewer.key();
+    let clock = Clock::get()?;
+
+    require!(
+        platform.dispute_window > 0,
+        StreamingError::DisputesDisabled
+    );
+    require!(
+        clock.unix_timestamp <= merkle_batch.settled_at + platform.dispute_window,
+        StreamingError::DisputeWindowClosed
+    );
+    require!(
+        leaf_index < merkle_batch.leaf_count
+            && verify_proof(
+                &merkle_batch.root,
+                batch_leaf(leaf_index, &viewer, chunk_count),
+                leaf_index,
+                &proof,
+            ),
+        StreamingError::InvalidBatchLeafProof
+    );
+
+    merkle_batch.disputed_leaves = merkle_batch
+        .disputed_leaves
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let batch_leaf_dispute = &mut ctx.accounts.batch_leaf_dispute;
+    batch_leaf_dispute.batch = merkle_batch.key();
+    batch_leaf_dispute.viewer = viewer;
+    batch_leaf_dispute.leaf_index = leaf_index;
+    batch_leaf_dispute.chunk_count = chunk_count;
+    batch_leaf_dispute.opened_at = clock.unix_timestamp;
+    batch_leaf_dispute.bump = ctx.bumps.batch_leaf_dispute;
+
+    emit!(BatchLeafDisputed {
+        batch: merkle_batch.key(),
+        video: merkle_batch.video,
+        viewer,
+        leaf_index,
+        chunk_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Batch {} leaf {} disputed: {} chunks",
+        merkle_batch.batch_id,
+        leaf_index,
+        chunk_count
+    );
+
+    Ok(())
+}
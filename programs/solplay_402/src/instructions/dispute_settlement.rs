@@ -0,0 +1,144 @@
+// =============================================================================
+// Dispute Settlement Instruction
+// =============================================================================
+// Viewers can contest a settlement within the dispute window their session's
+// refund policy grants (never longer than the platform's), claiming some of
+// its chunks were never delivered. The disputed value is held
+// against the creator's earnings until the platform arbiter resolves it with
+// resolve_dispute. Settlements are identified by nonce - their 0-based index
+// in the session - and the window runs from the session's latest settlement.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(nonce: u32)]
+pub struct DisputeSettlement<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = Dispute::LEN,
+        seeds = [DISPUTE_SEED, viewer_session.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump,
+        constraint = creator_earnings.video == video.key() @ StreamingError::InvalidCreatorEarnings
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn dispute_settlement(
+    ctx: Context<DisputeSettlement>,
+    nonce: u32,
+    disputed_chunks: u32,
+) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let platform = &ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        platform.dispute_window > 0,
+        StreamingError::DisputesDisabled
+    );
+    let dispute_window = viewer_session
+        .refund_policy
+        .dispute_window(platform.dispute_window)
+        .ok_or(StreamingError::RefundsNotOffered)?;
+    require!(
+        clock.unix_timestamp <= viewer_session.last_settled_at + dispute_window,
+        StreamingError::DisputeWindowClosed
+    );
+
+    // Only settled chunks not already under dispute or refunded can be contested
+    let total_disputed = viewer_session
+        .disputed_chunks
+        .checked_add(disputed_chunks)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    let contested = total_disputed
+        .checked_add(viewer_session.refunded_chunks)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    require!(
+        nonce < viewer_session.settlement_count
+            && disputed_chunks > 0
+            && contested <= viewer_session.chunks_consumed,
+        StreamingError::InvalidDispute
+    );
+
+    // Disputes are valued from the most recently paid undisputed chunks back
+    let amount =
+        viewer_session.price_of(viewer_session.chunks_consumed - contested, disputed_chunks)?;
+
+    viewer_session.disputed_chunks = total_disputed;
+    creator_earnings.disputed_amount = creator_earnings
+        .disputed_amount
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.viewer_session = viewer_session.key();
+    dispute.video = ctx.accounts.video.key();
+    dispute.viewer = ctx.accounts.viewer.key();
+    dispute.nonce = nonce;
+    dispute.disputed_chunks = disputed_chunks;
+    dispute.amount = amount;
+    dispute.operator = viewer_session.last_operator;
+    dispute.status = DisputeStatus::Open;
+    dispute.opened_at = clock.unix_timestamp;
+    dispute.resolved_at = 0;
+    dispute.bump = ctx.bumps.dispute;
+
+    emit!(SettlementDisputed {
+        viewer: dispute.viewer,
+        video: dispute.video,
+        viewer_session: viewer_session.key(),
+        dispute: dispute.key(),
+        nonce,
+        disputed_chunks,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Settlement {} disputed: {} chunks ({} tokens held)",
+        nonce,
+        disputed_chunks,
+        amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// =============================================================================
+// Distribute Match Instruction
+// =============================================================================
+// After the round ends the platform authority pays each creator's match. The
+// round's total weight is final once support can no longer be recorded, so
+// every creator's share is fixed regardless of distribution order.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct DistributeMatch<'info> {
+    #[account(
+        mut,
+        seeds = [MATCHING_ROUND_SEED, matching_round.sponsor.as_ref(), &matching_round.round_id.to_le_bytes()],
+        bump = matching_round.bump,
+        constraint = matching_round.vault == matching_vault.key() @ StreamingError::InvalidMatchingRound
+    )]
+    pub matching_round: Account<'info, MatchingRound>,
+
+    #[account(
+        mut,
+        seeds = [MATCHING_ENTRY_SEED, matching_round.key().as_ref(), matching_entry.creator.as_ref()],
+        bump = matching_entry.bump
+    )]
+    pub matching_entry: Account<'info, MatchingEntry>,
+
+    #[account(mut)]
+    pub matching_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Creator's token account (receives the match)
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == matching_entry.creator,
+        constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn distribute_match(ctx: Context<DistributeMatch>) -> Result<()> {
+    let matching_round = &mut ctx.accounts.matching_round;
+    let matching_entry = &mut ctx.accounts.matching_entry;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= matching_round.end_time,
+        StreamingError::MatchingRoundNotEnded
+    );
+    require!(
+        !matching_entry.matched,
+        StreamingError::MatchAlreadyDistributed
+    );
+
+    let amount = matching_round.match_for(matching_entry.supporters);
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.matching_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.creator_token_account.to_account_info(), amount)?;
+
+    matching_entry.matched = true;
+    matching_round.creators_matched += 1;
+    matching_round.distributed = matching_round
+        .distributed
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(MatchDistributed {
+        round: matching_round.key(),
+        creator: matching_entry.creator,
+        supporters: matching_entry.supporters,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Match distributed: {} tokens for {} supporters",
+        amount,
+        matching_entry.supporters
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// =============================================================================
+// End Promotion Instruction
+// =============================================================================
+// The sponsor withdraws from placement: the unspent budget is refunded and the
+// promotion and its escrow are closed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct EndPromotion<'info> {
+    #[account(
+        mut,
+        seeds = [PROMOTION_SEED, promotion.video.as_ref(), sponsor.key().as_ref()],
+        bump = promotion.bump,
+        has_one = sponsor @ StreamingError::Unauthorized,
+        has_one = vault @ StreamingError::InvalidPromotion,
+        close = sponsor
+    )]
+    pub promotion: Account<'info, Promotion>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Sponsor's token account (receives the unspent budget)
+    #[account(
+        mut,
+        constraint = sponsor_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = sponsor_token_account.owner == sponsor.key()
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn end_promotion(ctx: Context<EndPromotion>) -> Result<()> {
+    let promotion = &ctx.accounts.promotion;
+    let clock = Clock::get()?;
+
+    let refunded = ctx.accounts.vault.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.sponsor_token_account.to_account_info(),
+        refunded,
+    )?;
+    source.close(ctx.accounts.sponsor.to_account_info())?;
+
+    emit!(PromotionEnded {
+        promotion: promotion.key(),
+        video: promotion.video,
+        sponsor: promotion.sponsor,
+        refunded,
+        impressions: promotion.impressions,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Promotion ended: {} tokens refunded", refunded);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// =============================================================================
+// Execute Fee Change Instruction (Timelocked)
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteFeeChange<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn execute_fee_change(ctx: Context<ExecuteFeeChange>) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        platform.has_pending_fee_change(),
+        StreamingError::NoPendingFeeChange
+    );
+    require!(
+        clock.unix_timestamp >= platform.fee_change_eta,
+        StreamingError::FeeChangeTimelockActive
+    );
+
+    let previous_fee_basis_points = platform.platform_fee_basis_points;
+    platform.platform_fee_basis_points = platform.pending_fee_basis_points;
+    platform.pending_fee_basis_points = 0;
+    platform.fee_change_eta = 0;
+
+    emit!(FeeChangeExecuted {
+        platform: platform.key(),
+        previous_fee_basis_points,
+        new_fee_basis_points: platform.platform_fee_basis_points,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Fee change executed: {} -> {} bps",
+        previous_fee_basis_points,
+        platform.platform_fee_basis_points
+    );
+
+    Ok(())
+}
@@ -0,0 +1,274 @@
+// =============================================================================
+// Execute Settlement Instruction (Two-Phase Settlement, Phase 2)
+// =============================================================================
+// Performs the token transfers for a batch recorded by prepare_settlement,
+// applies the bookkeeping, emits SessionSettled, and closes the pending batch.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ExecuteSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [PENDING_SETTLEMENT_SEED, viewer_session.key().as_ref()],
+        bump = pending_settlement.bump,
+        constraint = pending_settlement.viewer_session == viewer_session.key() @ StreamingError::InvalidSession,
+        close = viewer
+    )]
+    pub pending_settlement: Account<'info, PendingSettlement>,
+
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key() @ StreamingError::Unauthorized,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump,
+        constraint = creator_earnings.creator == video.creator @ StreamingError::Unauthorized,
+        constraint = creator_earnings.video == video.key() @ StreamingError::InvalidCreatorEarnings
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (source of payment)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == viewer.key(),
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    /// Gift escrow (required for gifted sessions, which pay from it instead
+    /// of the viewer's token account)
+    #[account(
+        mut,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump
+    )]
+    pub session_escrow: Option<Account<'info, TokenAccount>>,
+
+    /// Viewer's lifetime stats (updated when provided)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer_session.viewer.as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Account<'info, ViewerProfile>>,
+
+    /// Viewer's recently watched videos (updated when provided)
+    #[account(
+        mut,
+        seeds = [WATCH_HISTORY_SEED, viewer_session.viewer.as_ref()],
+        bump = watch_history.bump
+    )]
+    pub watch_history: Option<Account<'info, WatchHistory>>,
+
+    /// Video's earnings bucket for the current day (updated when provided)
+    #[account(
+        mut,
+        seeds = [EARNINGS_BUCKET_SEED, video.key().as_ref(), &earnings_bucket.day.to_le_bytes()],
+        bump = earnings_bucket.bump,
+    )]
+    pub earnings_bucket: Option<Account<'info, EarningsBucket>>,
+
+    /// Creator's token account (receives payment)
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator_earnings.payout_owner(),
+        constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// Platform's token account (receives fees)
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = platform_token_account.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    /// Platform fee split configuration (required when fee splitting is enabled).
+    /// Recipient token accounts are passed as remaining accounts, in config order.
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, FeeRecipientConfig>>,
+
+    /// Token mint (required when the platform burns a share of fees)
+    #[account(
+        mut,
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Option<Account<'info, Mint>>,
+
+    /// Insurance fund (required when the platform insures a share of fees)
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump
+    )]
+    pub insurance_fund: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Viewer wallet (must sign the settlement transaction, receives pending batch rent)
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn execute_settlement<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteSettlement<'info>>,
+) -> Result<()> {
+    let pending_settlement = &ctx.accounts.pending_settlement;
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let video = &mut ctx.accounts.video;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    // The batch was validated against this exact session state
+    require!(
+        viewer_session.chunks_consumed == pending_settlement.chunks_consumed_before,
+        StreamingError::StaleSettlement
+    );
+    video.check_publish_window(pending_settlement.settlement_timestamp)?;
+    viewer_session.record_settled_value(
+        platform.max_settlement_value_per_hour_per_session,
+        pending_settlement.total_payment,
+        clock.unix_timestamp,
+    )?;
+    let payment_account = session_payment_account(
+        viewer_session,
+        &ctx.accounts.viewer_token_account,
+        ctx.accounts.session_escrow.as_ref(),
+    )?;
+    require!(
+        payment_account.amount >= pending_settlement.total_payment,
+        StreamingError::InsufficientBalance
+    );
+    check_delegation(
+        viewer_session,
+        payment_account,
+        &platform.key(),
+        pending_settlement.total_payment,
+    )?;
+
+    let chunk_count = pending_settlement.chunk_count;
+    let mut amounts = SettlementAmounts::split(
+        platform,
+        pending_settlement.total_payment,
+        pending_settlement.platform_fee,
+    )?;
+    amounts.withhold_penalty(creator_earnings);
+
+    let fee_recipients = platform.resolve_fee_recipients(ctx.accounts.fee_recipients.as_deref())?;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: payment_account.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
+
+    source.transfer(
+        ctx.accounts.creator_token_account.to_account_info(),
+        amounts.creator_amount,
+    )?;
+
+    source.pay_platform_share(
+        ctx.accounts.platform_token_account.to_account_info(),
+        ctx.accounts
+            .token_mint
+            .as_ref()
+            .map(|token_mint| token_mint.to_account_info()),
+        ctx.accounts
+            .insurance_fund
+            .as_ref()
+            .map(|insurance_fund| insurance_fund.to_account_info()),
+        fee_recipients,
+        ctx.remaining_accounts,
+        &amounts,
+    )?;
+
+    record_settlement(
+        viewer_session,
+        video,
+        creator_earnings,
+        platform,
+        ctx.accounts.viewer_profile.as_deref_mut(),
+        chunk_count,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    record_earnings_bucket(
+        ctx.accounts.earnings_bucket.as_deref_mut(),
+        chunk_count as u64,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    if let Some(watch_history) = ctx.accounts.watch_history.as_mut() {
+        watch_history.record(
+            video.key(),
+            viewer_session.chunks_consumed,
+            clock.unix_timestamp,
+        );
+    }
+
+    emit!(SessionSettled {
+        viewer: viewer_session.viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        chunk_count,
+        total_payment: amounts.total_payment,
+        platform_fee: amounts.platform_fee,
+        creator_amount: amounts.creator_amount,
+        penalty_withheld: amounts.penalty_withheld,
+        fee_burned: amounts.fee_burned,
+        chunks_consumed: viewer_session.chunks_consumed,
+        chunks_remaining: viewer_session.max_approved_chunks - viewer_session.chunks_consumed,
+        settlement_timestamp: pending_settlement.settlement_timestamp,
+        x402_proof: Pubkey::default(),
+        watermark_id: viewer_session.watermark_id,
+        client_tag: viewer_session.client_tag,
+        fee_basis_points: pending_settlement.fee_basis_points,
+        batch_discount_bps: pending_settlement.batch_discount_bps,
+        completion_rebate: 0,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Prepared settlement executed: {} chunks, {} tokens (creator: {}, fee: {})",
+        chunk_count,
+        amounts.total_payment,
+        amounts.creator_amount,
+        amounts.platform_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// =============================================================================
+// Finalize Live Stream Instruction
+// =============================================================================
+// Ends a live stream and freezes its chunk count. The video stays streamable
+// as a regular VOD with the strict chunk index check.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FinalizeLiveStream<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn finalize_live_stream(ctx: Context<FinalizeLiveStream>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    require!(video.is_live, StreamingError::NotLiveStream);
+
+    video.is_live = false;
+    video.live_finalized = true;
+
+    emit!(LiveStreamFinalized {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        total_chunks: video.total_chunks,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Live stream finalized at {} chunks", video.total_chunks);
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+// =============================================================================
+// Flush Payouts Instruction
+// =============================================================================
+// Pays out balances accumulated by netted settlements: one transfer per
+// creator from the payout vault, plus the platform's accrued fees when the
+// platform token account is passed. Permissionless - funds can only move to
+// the creator (and platform) they are owed to. Earnings held under an open
+// settlement dispute stay in the vault until it is resolved.
+//
+// Creators are passed as remaining accounts, in triples:
+//   [3i]     the video's CreatorEarnings (writable)
+//   [3i + 1] the token account of the creator's payout owner (writable)
+//   [3i + 2] the creator's suspension marker PDA (suspended creators' payouts
+//            stay frozen in the vault and are skipped)
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct FlushPayouts<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [PAYOUT_VAULT_SEED],
+        bump
+    )]
+    pub payout_vault: Account<'info, TokenAccount>,
+
+    /// Platform's token account (receives accrued fees when provided)
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = platform_token_account.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn flush_payouts<'info>(ctx: Context<'_, '_, '_, 'info, FlushPayouts<'info>>) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len().is_multiple_of(3),
+        StreamingError::InvalidPayoutAccounts
+    );
+
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.payout_vault.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
+
+    let mut creators_paid: u16 = 0;
+    let mut creator_total: u64 = 0;
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let (earnings_info, creator_token_info, suspension_info) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        require_keys_eq!(
+            *earnings_info.owner,
+            crate::ID,
+            StreamingError::InvalidPayoutAccounts
+        );
+        let mut creator_earnings =
+            CreatorEarnings::try_deserialize(&mut &earnings_info.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            *creator_token_info.owner,
+            Token::id(),
+            StreamingError::InvalidPayoutAccounts
+        );
+        let creator_token_account =
+            TokenAccount::try_deserialize(&mut &creator_token_info.try_borrow_data()?[..])?;
+        require!(
+            creator_token_account.owner == creator_earnings.payout_owner()
+                && creator_token_account.mint == platform.token_mint,
+            StreamingError::InvalidPayoutAccounts
+        );
+
+        let (suspension_marker, _) = Pubkey::find_program_address(
+            &[SUSPENDED_CREATOR_SEED, creator_earnings.creator.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            suspension_info.key(),
+            suspension_marker,
+            StreamingError::InvalidPayoutAccounts
+        );
+        if !suspension_info.data_is_empty() {
+            continue;
+        }
+
+        let owed = creator_earnings.take_claimable(clock.unix_timestamp)?;
+        if owed == 0 {
+            continue;
+        }
+        source.transfer(creator_token_info.clone(), owed)?;
+
+        creator_earnings.try_serialize(&mut &mut earnings_info.try_borrow_mut_data()?[..])?;
+
+        creators_paid += 1;
+        creator_total = creator_total
+            .checked_add(owed)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+
+    let mut platform_fee = 0;
+    if let Some(platform_token_account) = ctx.accounts.platform_token_account.as_ref() {
+        platform_fee = platform.fee_owed;
+        source.transfer(platform_token_account.to_account_info(), platform_fee)?;
+        platform.fee_owed = 0;
+    }
+
+    emit!(PayoutsFlushed {
+        creators_paid,
+        creator_total,
+        platform_fee,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Payouts flushed: {} creators ({} tokens), platform fee: {}",
+        creators_paid,
+        creator_total,
+        platform_fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// =============================================================================
+// Fund Emissions Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct FundEmissions<'info> {
+    #[account(
+        mut,
+        seeds = [EMISSION_SCHEDULE_SEED],
+        bump = emission_schedule.bump
+    )]
+    pub emission_schedule: Account<'info, EmissionSchedule>,
+
+    #[account(
+        mut,
+        seeds = [EMISSION_VAULT_SEED],
+        bump
+    )]
+    pub emission_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Authority's platform token account (funds the vault)
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == emission_schedule.reward_mint @ StreamingError::InvalidTokenMint,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn fund_emissions(ctx: Context<FundEmissions>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.authority_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.emission_vault.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let emission_schedule = &mut ctx.accounts.emission_schedule;
+    emission_schedule.total_funded = emission_schedule
+        .total_funded
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(EmissionsFunded {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_funded: emission_schedule.total_funded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Emissions funded: {} tokens", amount);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// =============================================================================
+// Fund Organization Instruction
+// =============================================================================
+// Tops up an organization's shared budget. Anyone may fund it (a finance
+// wallet, a grant, the admin); only members can spend it.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct FundOrganization<'info> {
+    #[account(
+        seeds = [ORGANIZATION_SEED, organization.admin.as_ref()],
+        bump = organization.bump
+    )]
+    pub organization: Box<Account<'info, Organization>>,
+
+    #[account(
+        mut,
+        seeds = [ORGANIZATION_TREASURY_SEED, organization.key().as_ref()],
+        bump
+    )]
+    pub organization_treasury: Account<'info, TokenAccount>,
+
+    /// Funder's token account
+    #[account(
+        mut,
+        constraint = funder_token_account.mint == organization_treasury.mint @ StreamingError::InvalidTokenMint,
+        constraint = funder_token_account.owner == funder.key()
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn fund_organization(ctx: Context<FundOrganization>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.funder_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.organization_treasury.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    ctx.accounts.organization_treasury.reload()?;
+
+    emit!(OrganizationFunded {
+        organization: ctx.accounts.organization.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        balance: ctx.accounts.organization_treasury.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Organization funded: {} tokens", amount);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// =============================================================================
+// Fund Rewards Pool Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct FundRewardsPool<'info> {
+    #[account(
+        mut,
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    #[account(
+        mut,
+        seeds = [REWARDS_VAULT_SEED],
+        bump
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Authority's token account (funds the pool)
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn fund_rewards_pool(ctx: Context<FundRewardsPool>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.authority_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.rewards_vault.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let rewards_pool = &mut ctx.accounts.rewards_pool;
+    rewards_pool.total_funded = rewards_pool
+        .total_funded
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(RewardsPoolFunded {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        total_funded: rewards_pool.total_funded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Rewards pool funded: {} tokens", amount);
+
+    Ok(())
+}
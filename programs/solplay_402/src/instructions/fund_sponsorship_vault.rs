@@ -0,0 +1,53 @@
+// =============================================================================
+// Fund Sponsorship Vault Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+#[derive(Accounts)]
+pub struct FundSponsorshipVault<'info> {
+    #[account(
+        mut,
+        seeds = [SPONSORSHIP_VAULT_SEED],
+        bump = sponsorship_vault.bump
+    )]
+    pub sponsorship_vault: Account<'info, SponsorshipVault>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_sponsorship_vault(ctx: Context<FundSponsorshipVault>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.sponsorship_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let sponsorship_vault = &mut ctx.accounts.sponsorship_vault;
+    sponsorship_vault.total_funded = sponsorship_vault
+        .total_funded
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(SponsorshipVaultFunded {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_funded: sponsorship_vault.total_funded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Sponsorship vault funded: {} lamports", amount);
+
+    Ok(())
+}
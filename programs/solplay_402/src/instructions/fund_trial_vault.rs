@@ -0,0 +1,86 @@
+// =============================================================================
+// Fund Trial Vault Instruction
+// =============================================================================
+// Tops up the promo vault that pays for trial sessions and the daily free
+// chunk allowance. The first call creates the vault: a platform-owned token
+// account that `start_trial_session` and `claim_free_chunks` draw from and
+// that unspent promo escrows are refunded to on close.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct FundTrialVault<'info> {
+    /// Promo vault funding trial sessions, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [TRIAL_VAULT_SEED],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub trial_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Authority's token account (funds the vault)
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_trial_vault(ctx: Context<FundTrialVault>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.authority_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.trial_vault.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    ctx.accounts.trial_vault.reload()?;
+
+    emit!(TrialVaultFunded {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        vault_balance: ctx.accounts.trial_vault.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Trial vault funded: {} tokens", amount);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// =============================================================================
+// Get Session Status Instruction (Read-Only)
+// =============================================================================
+// Meant to be run through simulateTransaction (Anchor's `.view()`): returns a
+// session's remaining approval, the SPL delegation actually backing it and
+// its expiry countdowns, derived with the same logic the program enforces,
+// so frontends don't each reimplement (and drift from) it. Modifies nothing.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::TokenAccount;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SessionStatus {
+    pub remaining_approved_chunks: u32, // Approved but not yet settled (units of the billing mode)
+    pub renewal_budget_chunks: u32,     // Pre-delegated chunks left for auto-renewals
+    pub remaining_allowance: u64,       // Value of the remaining approval and renewal budget
+    pub delegated_amount: u64, // SPL allowance the platform actually holds (0 = not delegated)
+    pub token_balance: u64,    // Viewer's balance available to settlements
+    pub is_funded: bool,       // Delegation and balance both cover the remaining allowance
+    pub expires_in: i64,       // Seconds until the session expires (0 = expired)
+    pub inactive_in: i64,      // Seconds until the session goes inactive (0 = inactive)
+}
+
+#[derive(Accounts)]
+pub struct GetSessionStatus<'info> {
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), viewer_session.video.as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (the source settlements draw from)
+    #[account(
+        constraint = viewer_token_account.owner == viewer_session.viewer @ StreamingError::Unauthorized,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+}
+
+pub fn get_session_status(ctx: Context<GetSessionStatus>) -> Result<SessionStatus> {
+    let viewer_session = &ctx.accounts.viewer_session;
+    let viewer_token_account = &ctx.accounts.viewer_token_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    let remaining_allowance = viewer_session.remaining_allowance()?;
+    let delegated_amount =
+        if viewer_token_account.delegate == COption::Some(ctx.accounts.platform.key()) {
+            viewer_token_account.delegated_amount
+        } else {
+            0
+        };
+
+    Ok(SessionStatus {
+        remaining_approved_chunks: viewer_session
+            .max_approved_chunks
+            .saturating_sub(viewer_session.chunks_consumed),
+        renewal_budget_chunks: viewer_session.renewal_budget_chunks,
+        remaining_allowance,
+        delegated_amount,
+        token_balance: viewer_token_account.amount,
+        is_funded: delegated_amount >= remaining_allowance
+            && viewer_token_account.amount >= remaining_allowance,
+        expires_in: (viewer_session.session_start + SESSION_EXPIRY_DURATION - now).max(0),
+        inactive_in: (viewer_session.last_activity + SESSION_INACTIVITY_DURATION - now).max(0),
+    })
+}
@@ -94,6 +94,35 @@ pub fn initialize_platform(
     platform.total_sessions = 0;
     platform.total_revenue = 0;
     platform.bump = ctx.bumps.platform;
+    platform.fee_change_delay = DEFAULT_FEE_CHANGE_DELAY;
+    platform.pending_fee_basis_points = 0;
+    platform.fee_change_eta = 0;
+    platform.quality_report_threshold = 0;
+    platform.quality_refund_bps = 0;
+    platform.creator_tiers_enabled = false;
+    platform.fee_split_enabled = false;
+    platform.burn_bps = 0;
+    platform.total_burned = 0;
+    platform.dispute_window = 0;
+    platform.arbiter = Pubkey::default();
+    platform.min_operator_bond = 0;
+    platform.netting_enabled = false;
+    platform.fee_owed = 0;
+    platform.max_sessions_per_hour = 0;
+    platform.max_chunks_per_hour = 0;
+    platform.trial_spend_cap = 0;
+    platform.free_chunks_per_day = 0;
+    platform.insurance_bps = 0;
+    platform.stake_weight_per_discount_bps = 0;
+    platform.age_attestor = Pubkey::default();
+    platform.region_attestor = Pubkey::default();
+    platform.max_price_per_chunk = 0;
+    platform.min_settlement_interval = 0;
+    platform.batch_discount_threshold = 0;
+    platform.batch_discount_bps = 0;
+    platform.payout_maturation_period = 0;
+    platform.max_chunks_per_settlement = 0;
+    platform.max_settlement_value_per_hour_per_session = 0;
 
     emit!(PlatformInitialized {
         platform: platform.key(),
@@ -0,0 +1,49 @@
+// =============================================================================
+// Lift Creator Suspension Instruction
+// =============================================================================
+// Ends a suspend_creator hold by closing the marker, unfreezing the creator's
+// payouts and reopening publishing and new sessions.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LiftCreatorSuspension<'info> {
+    #[account(
+        mut,
+        seeds = [SUSPENDED_CREATOR_SEED, suspended_creator.creator.as_ref()],
+        bump = suspended_creator.bump,
+        close = authority
+    )]
+    pub suspended_creator: Account<'info, SuspendedCreator>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn lift_creator_suspension(ctx: Context<LiftCreatorSuspension>) -> Result<()> {
+    let suspended_creator = &ctx.accounts.suspended_creator;
+    let clock = Clock::get()?;
+
+    emit!(SuspensionLifted {
+        authority: ctx.accounts.authority.key(),
+        creator: suspended_creator.creator,
+        suspended_at: suspended_creator.suspended_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Suspension of creator {} lifted", suspended_creator.creator);
+
+    Ok(())
+}
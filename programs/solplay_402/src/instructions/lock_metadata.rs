@@ -0,0 +1,50 @@
+// =============================================================================
+// Lock Metadata Instruction
+// =============================================================================
+// Irreversibly freezes a video's content: its title, description, IPFS hash,
+// chunk count, chunk manifest, renditions and chunk format can no longer
+// change, so buyers of permanent access know the content they paid for won't
+// be swapped out. Pricing, visibility and images stay editable.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LockMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized,
+        constraint = !video.is_immutable @ StreamingError::VideoImmutable
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn lock_metadata(ctx: Context<LockMetadata>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    require!(!video.is_live, StreamingError::CannotLockLiveStream);
+
+    video.is_immutable = true;
+
+    emit!(VideoMetadataLocked {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        ipfs_hash: video.ipfs_hash.clone(),
+        total_chunks: video.total_chunks,
+        chunk_merkle_root: video.chunk_merkle_root,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Video {} metadata locked", video.video_id);
+
+    Ok(())
+}
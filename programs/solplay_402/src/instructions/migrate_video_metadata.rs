@@ -0,0 +1,187 @@
+// =============================================================================
+// Migrate Video Metadata Instruction
+// =============================================================================
+// Videos created before `metadata_uri` stored a description of up to 1000
+// bytes on-chain. This rewrites such an account in the current layout (the
+// description moves off-chain behind `metadata_uri` + `metadata_hash`),
+// shrinks it to `Video::MAX_LEN` and refunds the freed rent to the creator.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Video layout prior to the metadata_uri migration
+#[derive(AnchorDeserialize)]
+struct LegacyVideo {
+    creator: Pubkey,
+    video_id: String,
+    ipfs_hash: String,
+    total_chunks: u32,
+    price_per_chunk: u64,
+    title: String,
+    _description: String,
+    is_active: bool,
+    total_sessions: u64,
+    total_chunks_served: u64,
+    created_at: i64,
+    bump: u8,
+    gate_collection: Pubkey,
+    gated_price_per_chunk: u64,
+    is_private: bool,
+    ad_supported: bool,
+    publish_at: i64,
+    unpublish_at: i64,
+    is_live: bool,
+    live_finalized: bool,
+    billing_mode: BillingMode,
+    price_per_second: u64,
+    quality_tiers: Vec<QualityTier>,
+    chunk_merkle_root: [u8; 32],
+}
+
+impl LegacyVideo {
+    const MAX_LEN: usize = 8 + // discriminator
+        32 + // creator
+        4 + MAX_VIDEO_ID_LENGTH + // video_id
+        4 + MAX_IPFS_HASH_LENGTH + // ipfs_hash
+        4 +  // total_chunks
+        8 +  // price_per_chunk
+        4 + MAX_TITLE_LENGTH + // title
+        4 + MAX_DESCRIPTION_LENGTH + // description
+        1 +  // is_active
+        8 +  // total_sessions
+        8 +  // total_chunks_served
+        8 +  // created_at
+        1 +  // bump
+        32 + // gate_collection
+        8 +  // gated_price_per_chunk
+        1 +  // is_private
+        1 +  // ad_supported
+        8 +  // publish_at
+        8 +  // unpublish_at
+        1 +  // is_live
+        1 +  // live_finalized
+        1 +  // billing_mode
+        8 +  // price_per_second
+        4 + MAX_QUALITY_TIERS * QualityTier::LEN + // quality_tiers
+        32; // chunk_merkle_root
+}
+
+#[derive(Accounts)]
+pub struct MigrateVideoMetadata<'info> {
+    /// CHECK: Legacy-layout Video account; the discriminator, size and
+    /// creator are verified in the handler
+    #[account(mut, owner = crate::ID)]
+    pub video: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn migrate_video_metadata(
+    ctx: Context<MigrateVideoMetadata>,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+) -> Result<()> {
+    let video_info = ctx.accounts.video.to_account_info();
+    let creator_info = ctx.accounts.creator.to_account_info();
+    let clock = Clock::get()?;
+
+    require!(
+        metadata_uri.len() <= MAX_METADATA_URI_LENGTH,
+        StreamingError::MetadataUriTooLong
+    );
+
+    let legacy = {
+        let data = video_info.try_borrow_data()?;
+        require!(
+            data.len() == LegacyVideo::MAX_LEN && data.starts_with(Video::DISCRIMINATOR),
+            StreamingError::NotLegacyVideo
+        );
+        LegacyVideo::deserialize(&mut &data[Video::DISCRIMINATOR.len()..])?
+    };
+    require_keys_eq!(
+        legacy.creator,
+        ctx.accounts.creator.key(),
+        StreamingError::Unauthorized
+    );
+
+    let video = Video {
+        creator: legacy.creator,
+        video_id: legacy.video_id,
+        ipfs_hash: legacy.ipfs_hash,
+        total_chunks: legacy.total_chunks,
+        price_per_chunk: legacy.price_per_chunk,
+        title: legacy.title,
+        metadata_uri,
+        metadata_hash,
+        is_active: legacy.is_active,
+        total_sessions: legacy.total_sessions,
+        total_chunks_served: legacy.total_chunks_served,
+        created_at: legacy.created_at,
+        bump: legacy.bump,
+        gate_collection: legacy.gate_collection,
+        gated_price_per_chunk: legacy.gated_price_per_chunk,
+        is_private: legacy.is_private,
+        ad_supported: legacy.ad_supported,
+        publish_at: legacy.publish_at,
+        unpublish_at: legacy.unpublish_at,
+        is_live: legacy.is_live,
+        live_finalized: legacy.live_finalized,
+        billing_mode: legacy.billing_mode,
+        price_per_second: legacy.price_per_second,
+        quality_tiers: legacy.quality_tiers,
+        chunk_merkle_root: legacy.chunk_merkle_root,
+        unique_viewers: 0,
+        thumbnail_cid: String::new(),
+        poster_cid: String::new(),
+        duration_seconds: 0,
+        chunk_duration_ms: 0,
+        media_format: MediaFormat::Unspecified,
+        rating: ContentRating::Unrated,
+        content_flags: 0,
+        region_policy: None,
+        is_immutable: false,
+        price_breaks: Vec::new(),
+        discount_windows: Vec::new(),
+        completion_rebate_bps: 0,
+        refund_policy: RefundPolicy::Always,
+        require_viewer_cosign_settlement: false,
+    };
+
+    // Shrink the account and rewrite it in the current layout
+    video_info.resize(Video::MAX_LEN)?;
+    {
+        let mut data = video_info.try_borrow_mut_data()?;
+        data.fill(0);
+        video.try_serialize(&mut &mut data[..])?;
+    }
+
+    // Refund the rent freed by the smaller account
+    let refunded = video_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(Video::MAX_LEN));
+    **video_info.try_borrow_mut_lamports()? -= refunded;
+    **creator_info.try_borrow_mut_lamports()? += refunded;
+
+    emit!(VideoMetadataMigrated {
+        video: video_info.key(),
+        creator: video.creator,
+        metadata_uri: video.metadata_uri.clone(),
+        metadata_hash,
+        rent_refunded: refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Video {} migrated to off-chain metadata, {} lamports refunded",
+        video.video_id,
+        refunded
+    );
+
+    Ok(())
+}
@@ -2,20 +2,274 @@
 // Instructions Module
 // =============================================================================
 
+pub mod add_allowed_viewer;
+pub mod add_organization_member;
+pub mod add_track;
+pub mod append_chunks;
+pub mod approve_channel_session;
 pub mod approve_delegate;
+pub mod approve_with_signature;
+pub mod archive_video;
+pub mod assert_invariants;
+pub mod attest_age;
+pub mod ban_viewer;
+pub mod cancel_bounty;
+pub mod cancel_settlement;
+pub mod challenge_delivery;
+pub mod claim_badge;
+pub mod claim_bounty;
+pub mod claim_creator_payout;
+pub mod claim_emissions;
+pub mod claim_free_chunks;
+pub mod claim_or_refund;
+pub mod claim_rewards;
+pub mod close_ad_campaign;
+pub mod close_channel_session;
+pub mod close_matching_round;
 pub mod close_session;
+pub mod compensate_viewer;
+pub mod configure_emissions;
+pub mod configure_insurance_fund;
+pub mod configure_rewards_pool;
+pub mod configure_sponsorship_vault;
+pub mod create_ad_campaign;
+pub mod create_campaign;
+pub mod create_coupon;
+pub mod create_gift_card;
+pub mod create_matching_round;
+pub mod create_organization;
+pub mod create_promotion;
 pub mod create_video;
+pub mod create_videos_batch;
+pub mod credit_cross_chain_payment;
+pub mod credit_from_cctp;
+pub mod deactivate_videos_batch;
+pub mod dispute_batch_leaf;
+pub mod dispute_settlement;
+pub mod distribute_match;
+pub mod end_promotion;
+pub mod execute_fee_change;
+pub mod execute_settlement;
+pub mod finalize_live_stream;
+pub mod flush_payouts;
+pub mod fund_emissions;
+pub mod fund_organization;
+pub mod fund_rewards_pool;
+pub mod fund_sponsorship_vault;
+pub mod fund_trial_vault;
+pub mod get_session_status;
+pub mod gift_session;
 pub mod initialize;
+pub mod lift_creator_suspension;
+pub mod lock_metadata;
+pub mod migrate_video_metadata;
+pub mod onboard_creator_and_video;
+pub mod open_earnings_bucket;
+pub mod open_emission_balance;
+pub mod open_viewer_profile;
+pub mod open_viewer_rewards;
+pub mod open_watch_history;
+pub mod passkey_consent;
+pub mod pay_channel_chunk;
 pub mod pay_for_chunk;
+pub mod pledge;
+pub mod post_bounty;
+pub mod prepare_settlement;
+pub mod propose_fee_change;
+pub mod record_matching_support;
+pub mod record_promoted_impressions;
+pub mod redeem_gift_card;
+pub mod refund_viewer;
+pub mod register_cross_chain_emitter;
+pub mod remove_organization_member;
+pub mod remove_track;
+pub mod renew_approval;
+pub mod renew_session;
+pub mod report_quality_issue;
+pub mod request_key_release;
+pub mod resolve_dispute;
+pub mod revoke_age_attestation;
 pub mod revoke_delegate;
+pub mod revoke_verification;
+pub mod set_auto_renew;
+pub mod set_billing_mode;
+pub mod set_completion_rebate;
+pub mod set_content_rating;
+pub mod set_creator_team;
+pub mod set_creator_tiers;
+pub mod set_discount_windows;
+pub mod set_evm_address;
+pub mod set_fee_recipients;
+pub mod set_netting_mode;
+pub mod set_passkey;
+pub mod set_payout_address;
+pub mod set_price_curve;
+pub mod set_publish_window;
+pub mod set_quality_tiers;
+pub mod set_refund_policy;
+pub mod set_region_policy;
+pub mod set_renditions;
+pub mod set_session_key;
+pub mod set_settlement_cosign;
+pub mod set_video_ad_mode;
+pub mod set_video_format;
+pub mod set_video_gate;
+pub mod set_video_visibility;
+pub mod settle_merkle_batch;
 pub mod settle_session;
+pub mod slash_operator_bond;
+pub mod snapshot_platform_stats;
+pub mod stake_for_boost;
+pub mod stake_operator_bond;
+pub mod start_live_stream;
+pub mod start_organization_session;
+pub mod start_trial_session;
+pub mod suspend_creator;
+pub mod transfer_session;
+pub mod unban_viewer;
+pub mod unstake_boost;
+pub mod update_common_accounts;
+pub mod update_platform_config;
+pub mod update_prices_batch;
 pub mod update_video;
+pub mod verify_creator;
+pub mod verify_quality_report;
+pub mod verify_x402_proof;
+pub mod withdraw_operator_bond;
+pub mod withdraw_organization_funds;
 
+pub use add_allowed_viewer::*;
+pub use add_organization_member::*;
+pub use add_track::*;
+pub use append_chunks::*;
+pub use approve_channel_session::*;
 pub use approve_delegate::*;
+pub use approve_with_signature::*;
+pub use archive_video::*;
+pub use assert_invariants::*;
+pub use attest_age::*;
+pub use ban_viewer::*;
+pub use cancel_bounty::*;
+pub use cancel_settlement::*;
+pub use challenge_delivery::*;
+pub use claim_badge::*;
+pub use claim_bounty::*;
+pub use claim_creator_payout::*;
+pub use claim_emissions::*;
+pub use claim_free_chunks::*;
+pub use claim_or_refund::*;
+pub use claim_rewards::*;
+pub use close_ad_campaign::*;
+pub use close_channel_session::*;
+pub use close_matching_round::*;
 pub use close_session::*;
+pub use compensate_viewer::*;
+pub use configure_emissions::*;
+pub use configure_insurance_fund::*;
+pub use configure_rewards_pool::*;
+pub use configure_sponsorship_vault::*;
+pub use create_ad_campaign::*;
+pub use create_campaign::*;
+pub use create_coupon::*;
+pub use create_gift_card::*;
+pub use create_matching_round::*;
+pub use create_organization::*;
+pub use create_promotion::*;
 pub use create_video::*;
+pub use create_videos_batch::*;
+pub use credit_cross_chain_payment::*;
+pub use credit_from_cctp::*;
+pub use deactivate_videos_batch::*;
+pub use dispute_batch_leaf::*;
+pub use dispute_settlement::*;
+pub use distribute_match::*;
+pub use end_promotion::*;
+pub use execute_fee_change::*;
+pub use execute_settlement::*;
+pub use finalize_live_stream::*;
+pub use flush_payouts::*;
+pub use fund_emissions::*;
+pub use fund_organization::*;
+pub use fund_rewards_pool::*;
+pub use fund_sponsorship_vault::*;
+pub use fund_trial_vault::*;
+pub use get_session_status::*;
+pub use gift_session::*;
 pub use initialize::*;
+pub use lift_creator_suspension::*;
+pub use lock_metadata::*;
+pub use migrate_video_metadata::*;
+pub use onboard_creator_and_video::*;
+pub use open_earnings_bucket::*;
+pub use open_emission_balance::*;
+pub use open_viewer_profile::*;
+pub use open_viewer_rewards::*;
+pub use open_watch_history::*;
+pub use passkey_consent::*;
+pub use pay_channel_chunk::*;
 pub use pay_for_chunk::*;
+pub use pledge::*;
+pub use post_bounty::*;
+pub use prepare_settlement::*;
+pub use propose_fee_change::*;
+pub use record_matching_support::*;
+pub use record_promoted_impressions::*;
+pub use redeem_gift_card::*;
+pub use refund_viewer::*;
+pub use register_cross_chain_emitter::*;
+pub use remove_organization_member::*;
+pub use remove_track::*;
+pub use renew_approval::*;
+pub use renew_session::*;
+pub use report_quality_issue::*;
+pub use request_key_release::*;
+pub use resolve_dispute::*;
+pub use revoke_age_attestation::*;
 pub use revoke_delegate::*;
+pub use revoke_verification::*;
+pub use set_auto_renew::*;
+pub use set_billing_mode::*;
+pub use set_completion_rebate::*;
+pub use set_content_rating::*;
+pub use set_creator_team::*;
+pub use set_creator_tiers::*;
+pub use set_discount_windows::*;
+pub use set_evm_address::*;
+pub use set_fee_recipients::*;
+pub use set_netting_mode::*;
+pub use set_passkey::*;
+pub use set_payout_address::*;
+pub use set_price_curve::*;
+pub use set_publish_window::*;
+pub use set_quality_tiers::*;
+pub use set_refund_policy::*;
+pub use set_region_policy::*;
+pub use set_renditions::*;
+pub use set_session_key::*;
+pub use set_settlement_cosign::*;
+pub use set_video_ad_mode::*;
+pub use set_video_format::*;
+pub use set_video_gate::*;
+pub use set_video_visibility::*;
+pub use settle_merkle_batch::*;
 pub use settle_session::*;
+pub use slash_operator_bond::*;
+pub use snapshot_platform_stats::*;
+pub use stake_for_boost::*;
+pub use stake_operator_bond::*;
+pub use start_live_stream::*;
+pub use start_organization_session::*;
+pub use start_trial_session::*;
+pub use suspend_creator::*;
+pub use transfer_session::*;
+pub use unban_viewer::*;
+pub use unstake_boost::*;
+pub use update_common_accounts::*;
+pub use update_platform_config::*;
+pub use update_prices_batch::*;
 pub use update_video::*;
+pub use verify_creator::*;
+pub use verify_quality_report::*;
+pub use verify_x402_proof::*;
+pub use withdraw_operator_bond::*;
+pub use withdraw_organization_funds::*;
@@ -0,0 +1,136 @@
+// =============================================================================
+// Onboard Creator And Video Instruction
+// =============================================================================
+// One-transaction onboarding for new creators: creates the creator's profile
+// (left untouched if it already exists), publishes the first video with its
+// earnings account and optionally gives it a first quality tier. Onboarding
+// wizards no longer orchestrate dependent transactions that can half-fail.
+// The profile starts unverified; verification stays with verify_creator.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(params: VideoParams)]
+pub struct OnboardCreatorAndVideo<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorProfile::LEN,
+        seeds = [CREATOR_PROFILE_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Video::MAX_LEN,
+        seeds = [VIDEO_SEED, params.video_id.as_bytes()],
+        bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorEarnings::LEN,
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// CHECK: Creator suspension marker; must not exist to publish
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, creator.key().as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn onboard_creator_and_video(
+    ctx: Context<OnboardCreatorAndVideo>,
+    params: VideoParams,
+    first_tier: Option<QualityTier>,
+) -> Result<()> {
+    params.validate(&ctx.accounts.platform)?;
+    if let Some(tier) = first_tier.as_ref() {
+        require!(
+            tier.is_valid(&ctx.accounts.platform),
+            StreamingError::InvalidQualityTiers
+        );
+    }
+
+    let creator = ctx.accounts.creator.key();
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    let video = &mut ctx.accounts.video;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    // Fresh profiles only - an existing one keeps its verification state
+    if creator_profile.creator == Pubkey::default() {
+        creator_profile.set_inner(CreatorProfile {
+            creator,
+            verified: false,
+            verified_at: 0,
+            bump: ctx.bumps.creator_profile,
+        });
+    }
+
+    video.set_inner(Video::new(
+        creator,
+        params,
+        clock.unix_timestamp,
+        ctx.bumps.video,
+    ));
+    if let Some(tier) = first_tier {
+        video.quality_tiers = vec![tier];
+    }
+    creator_earnings.set_inner(CreatorEarnings::new(
+        creator,
+        video.key(),
+        ctx.bumps.creator_earnings,
+    ));
+
+    platform.total_videos = platform
+        .total_videos
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(VideoCreated {
+        video: video.key(),
+        creator,
+        video_id: video.video_id.clone(),
+        ipfs_hash: video.ipfs_hash.clone(),
+        total_chunks: video.total_chunks,
+        price_per_chunk: video.price_per_chunk,
+        title: video.title.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Creator onboarded with video: {} chunks @ {} tokens/chunk",
+        video.total_chunks,
+        video.price_per_chunk
+    );
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// =============================================================================
+// Open Earnings Bucket Instruction
+// =============================================================================
+// Creates a video's revenue bucket for one UTC day. Settlements made that day
+// add to it when it is passed in, so creator dashboards can chart daily
+// revenue from chain state. Permissionless - the creator or a backend opens
+// today's (or tomorrow's) bucket and pays its rent.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(day: i64)]
+pub struct OpenEarningsBucket<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = EarningsBucket::LEN,
+        seeds = [EARNINGS_BUCKET_SEED, video.key().as_ref(), &day.to_le_bytes()],
+        bump
+    )]
+    pub earnings_bucket: Account<'info, EarningsBucket>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_earnings_bucket(ctx: Context<OpenEarningsBucket>, day: i64) -> Result<()> {
+    let today = EarningsBucket::day_of(Clock::get()?.unix_timestamp);
+    require!(
+        day == today || day == today + 1,
+        StreamingError::EarningsBucketDayMismatch
+    );
+
+    let earnings_bucket = &mut ctx.accounts.earnings_bucket;
+    earnings_bucket.video = ctx.accounts.video.key();
+    earnings_bucket.day = day;
+    earnings_bucket.chunks_sold = 0;
+    earnings_bucket.creator_revenue = 0;
+    earnings_bucket.platform_fee = 0;
+    earnings_bucket.settlements = 0;
+    earnings_bucket.bump = ctx.bumps.earnings_bucket;
+
+    msg!("Earnings bucket opened for day {}", day);
+
+    Ok(())
+}
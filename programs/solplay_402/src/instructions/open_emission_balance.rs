@@ -0,0 +1,41 @@
+// =============================================================================
+// Open Emission Balance Instruction
+// =============================================================================
+// Opt-in account tracking a wallet's platform-token emissions, as a creator
+// or a viewer. Settlements accrue emissions once both the creator's and the
+// viewer's balances exist and are passed with the emission schedule.
+// =============================================================================
+
+use crate::constants::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct OpenEmissionBalance<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = EmissionBalance::LEN,
+        seeds = [EMISSION_BALANCE_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub emission_balance: Account<'info, EmissionBalance>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_emission_balance(ctx: Context<OpenEmissionBalance>) -> Result<()> {
+    let emission_balance = &mut ctx.accounts.emission_balance;
+
+    emission_balance.owner = ctx.accounts.owner.key();
+    emission_balance.accrued = 0;
+    emission_balance.claimed = 0;
+    emission_balance.bump = ctx.bumps.emission_balance;
+
+    msg!("Emission balance opened");
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// =============================================================================
+// Open Viewer Profile Instruction
+// =============================================================================
+// Opt-in account accumulating a viewer's lifetime chunks watched and tokens
+// spent across all videos. Settlement paths update it when it is passed in,
+// giving creators and the platform a provable basis for loyalty perks.
+// =============================================================================
+
+use crate::constants::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct OpenViewerProfile<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = ViewerProfile::LEN,
+        seeds = [VIEWER_PROFILE_SEED, viewer.key().as_ref()],
+        bump
+    )]
+    pub viewer_profile: Account<'info, ViewerProfile>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_viewer_profile(ctx: Context<OpenViewerProfile>) -> Result<()> {
+    let viewer_profile = &mut ctx.accounts.viewer_profile;
+    let clock = Clock::get()?;
+
+    viewer_profile.viewer = ctx.accounts.viewer.key();
+    viewer_profile.total_chunks_watched = 0;
+    viewer_profile.total_spent = 0;
+    viewer_profile.total_sessions = 0;
+    viewer_profile.created_at = clock.unix_timestamp;
+    viewer_profile.last_activity = clock.unix_timestamp;
+    viewer_profile.bump = ctx.bumps.viewer_profile;
+    viewer_profile.rate_window_start = 0;
+    viewer_profile.window_sessions = 0;
+    viewer_profile.window_chunks = 0;
+    viewer_profile.free_chunks_day = 0;
+    viewer_profile.free_chunks_used = 0;
+
+    msg!("Viewer profile opened");
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// =============================================================================
+// Open Viewer Rewards Instruction
+// =============================================================================
+// Opt-in account tracking a viewer's watch-to-earn balance. Once it exists,
+// passing it (with the rewards pool) to settle_session accrues rewards.
+// =============================================================================
+
+use crate::constants::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct OpenViewerRewards<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = ViewerRewards::LEN,
+        seeds = [VIEWER_REWARDS_SEED, viewer.key().as_ref()],
+        bump
+    )]
+    pub viewer_rewards: Account<'info, ViewerRewards>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_viewer_rewards(ctx: Context<OpenViewerRewards>) -> Result<()> {
+    let viewer_rewards = &mut ctx.accounts.viewer_rewards;
+
+    viewer_rewards.viewer = ctx.accounts.viewer.key();
+    viewer_rewards.accrued = 0;
+    viewer_rewards.claimed = 0;
+    viewer_rewards.bump = ctx.bumps.viewer_rewards;
+
+    msg!("Viewer rewards account opened");
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// =============================================================================
+// Open Watch History Instruction
+// =============================================================================
+// Opt-in account listing the viewer's last WATCH_HISTORY_LENGTH videos with
+// their settled chunk counts. Settlement paths update it when it is passed
+// in, so frontends can offer "continue watching" from chain state.
+// =============================================================================
+
+use crate::constants::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct OpenWatchHistory<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = WatchHistory::LEN,
+        seeds = [WATCH_HISTORY_SEED, viewer.key().as_ref()],
+        bump
+    )]
+    pub watch_history: Account<'info, WatchHistory>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_watch_history(ctx: Context<OpenWatchHistory>) -> Result<()> {
+    let watch_history = &mut ctx.accounts.watch_history;
+
+    watch_history.viewer = ctx.accounts.viewer.key();
+    watch_history.entries = Vec::new();
+    watch_history.bump = ctx.bumps.watch_history;
+
+    msg!("Watch history opened");
+
+    Ok(())
+}
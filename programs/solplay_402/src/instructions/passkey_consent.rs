@@ -0,0 +1,91 @@
+// =============================================================================
+// Passkey Consent Instruction
+// =============================================================================
+// Authorizes a session action with the session's registered passkey. The
+// WebAuthn assertion's challenge commits to the action, and a Secp256r1
+// precompile instruction right before this one checks the signature.
+//   Settlement:    records consent for the session's next settlement with
+//                  these terms; settle_session then runs without the viewer's
+//                  signature (typically later in the same transaction)
+//   RenewApproval: extends the approval from the auto-renew budget now
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::sig_verify::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+#[derive(Accounts)]
+pub struct PasskeyConsent<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), viewer_session.video.as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.passkey != [0u8; 33] @ StreamingError::PasskeyNotRegistered
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    /// CHECK: Instructions sysvar, read to find the Secp256r1 precompile check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+pub fn passkey_consent(
+    ctx: Context<PasskeyConsent>,
+    action: PasskeyAction,
+    authenticator_data: Vec<u8>,
+    client_data_json: Vec<u8>,
+) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    let message = passkey_action_message(viewer_session, &viewer_session.key(), &action);
+    verify_webauthn_signature(
+        &ctx.accounts.instructions,
+        &viewer_session.passkey,
+        &message,
+        &authenticator_data,
+        &client_data_json,
+    )?;
+
+    match action {
+        PasskeyAction::Settlement { .. } => {
+            viewer_session.settlement_consent = hash(&message).to_bytes();
+        }
+        PasskeyAction::RenewApproval => {
+            require!(
+                !viewer_session.is_expired(clock.unix_timestamp),
+                StreamingError::SessionExpired
+            );
+            require!(
+                viewer_session.auto_renew && viewer_session.renewal_budget_chunks > 0,
+                StreamingError::AutoRenewNotEnabled
+            );
+            let renewed_chunks = viewer_session.apply_renewal(clock.unix_timestamp)?;
+
+            emit!(ApprovalRenewed {
+                viewer: viewer_session.viewer,
+                viewer_session: viewer_session.key(),
+                renewed_chunks,
+                max_approved_chunks: viewer_session.max_approved_chunks,
+                renewal_budget_chunks: viewer_session.renewal_budget_chunks,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    emit!(PasskeyConsented {
+        viewer: viewer_session.viewer,
+        viewer_session: viewer_session.key(),
+        action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Passkey consent recorded: {:?}", action);
+
+    Ok(())
+}
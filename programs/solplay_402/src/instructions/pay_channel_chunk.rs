@@ -0,0 +1,289 @@
+// =============================================================================
+// Pay Channel Chunk Instruction
+// =============================================================================
+// Pays for one chunk of any of the creator's videos from a channel session's
+// budget (see approve_channel_session), at the video's list price. Chunks
+// priced above the cap the viewer approved are refused. Videos needing
+// per-viewer checks at approval (private, age-gated, region-restricted) or
+// billed per second still need a per-video session.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct PayChannelChunk<'info> {
+    #[account(
+        mut,
+        seeds = [CHANNEL_SESSION_SEED, viewer.key().as_ref(), channel_session.creator.as_ref()],
+        bump = channel_session.bump,
+        constraint = channel_session.viewer == viewer.key() @ StreamingError::Unauthorized
+    )]
+    pub channel_session: Account<'info, ChannelSession>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive,
+        constraint = video.creator == channel_session.creator @ StreamingError::ChannelMismatch
+    )]
+    pub video: Box<Account<'info, Video>>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump,
+        constraint = creator_earnings.creator == video.creator @ StreamingError::Unauthorized,
+        constraint = creator_earnings.video == video.key() @ StreamingError::InvalidCreatorEarnings
+    )]
+    pub creator_earnings: Box<Account<'info, CreatorEarnings>>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (source of payment)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == viewer.key(),
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    /// Viewer's lifetime stats (updated when provided)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer.key().as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Account<'info, ViewerProfile>>,
+
+    /// Viewer's recently watched videos (updated when provided)
+    #[account(
+        mut,
+        seeds = [WATCH_HISTORY_SEED, viewer.key().as_ref()],
+        bump = watch_history.bump
+    )]
+    pub watch_history: Option<Account<'info, WatchHistory>>,
+
+    /// Video's earnings bucket for the current day (updated when provided)
+    #[account(
+        mut,
+        seeds = [EARNINGS_BUCKET_SEED, video.key().as_ref(), &earnings_bucket.day.to_le_bytes()],
+        bump = earnings_bucket.bump,
+    )]
+    pub earnings_bucket: Option<Account<'info, EarningsBucket>>,
+
+    /// Creator's token account (receives payment)
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator_earnings.payout_owner(),
+        constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// Platform's token account (receives fees)
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = platform_token_account.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    /// Volume fee tiers (required when the platform has tiers enabled)
+    #[account(
+        seeds = [CREATOR_TIER_SEED],
+        bump = creator_tiers.bump
+    )]
+    pub creator_tiers: Option<Account<'info, CreatorTierConfig>>,
+
+    /// Creator's boost stake (earns a fee discount while locked)
+    #[account(
+        seeds = [CREATOR_STAKE_SEED, video.creator.as_ref()],
+        bump = creator_stake.bump
+    )]
+    pub creator_stake: Option<Account<'info, CreatorStake>>,
+
+    /// Platform fee split configuration (required when fee splitting is enabled).
+    /// Recipient token accounts are passed as remaining accounts, in config order.
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, FeeRecipientConfig>>,
+
+    /// Token mint (required when the platform burns a share of fees)
+    #[account(
+        mut,
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Option<Account<'info, Mint>>,
+
+    /// Insurance fund (required when the platform insures a share of fees)
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump
+    )]
+    pub insurance_fund: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn pay_channel_chunk<'info>(
+    ctx: Context<'_, '_, '_, 'info, PayChannelChunk<'info>>,
+    chunk_index: u32,
+) -> Result<()> {
+    let channel_session = &mut ctx.accounts.channel_session;
+    let video = &mut ctx.accounts.video;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    video.check_publish_window(clock.unix_timestamp)?;
+    require!(
+        !video.is_private
+            && !video.requires_age_attestation()
+            && video.region_policy.is_none()
+            && video.billing_mode == BillingMode::PerChunk,
+        StreamingError::ChannelVideoNotEligible
+    );
+    require!(
+        !channel_session.is_expired(clock.unix_timestamp),
+        StreamingError::SessionExpired
+    );
+    require!(
+        !channel_session.is_inactive(clock.unix_timestamp),
+        StreamingError::SessionInactive
+    );
+    require!(
+        video.is_valid_chunk_index(chunk_index),
+        StreamingError::InvalidChunkIndex
+    );
+
+    // The video's list price, within the cap and the remaining budget
+    let chunk_price = video.price_per_chunk;
+    require!(
+        chunk_price <= channel_session.max_price_per_chunk,
+        StreamingError::PriceChangedSinceApproval
+    );
+    require!(
+        chunk_price <= channel_session.remaining_allowance(),
+        StreamingError::InsufficientApproval
+    );
+
+    let viewer_token_account = &ctx.accounts.viewer_token_account;
+    require!(
+        viewer_token_account.amount >= chunk_price,
+        StreamingError::InsufficientBalance
+    );
+    require!(
+        viewer_token_account.delegate == COption::Some(platform.key())
+            && viewer_token_account.delegated_amount >= chunk_price,
+        StreamingError::DelegationMissing
+    );
+
+    let fee_basis_points = platform.effective_fee_basis_points(
+        ctx.accounts.creator_tiers.as_deref(),
+        creator_earnings.total_chunks_sold,
+        ctx.accounts.creator_stake.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    let mut amounts = SettlementAmounts::compute(platform, chunk_price, fee_basis_points)?;
+    amounts.withhold_penalty(creator_earnings);
+
+    let fee_recipients = platform.resolve_fee_recipients(ctx.accounts.fee_recipients.as_deref())?;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: viewer_token_account.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.creator_token_account.to_account_info(),
+        amounts.creator_amount,
+    )?;
+    source.pay_platform_share(
+        ctx.accounts.platform_token_account.to_account_info(),
+        ctx.accounts
+            .token_mint
+            .as_ref()
+            .map(|token_mint| token_mint.to_account_info()),
+        ctx.accounts
+            .insurance_fund
+            .as_ref()
+            .map(|insurance_fund| insurance_fund.to_account_info()),
+        fee_recipients,
+        ctx.remaining_accounts,
+        &amounts,
+    )?;
+
+    // The channel session counts once, on its first chunk
+    let is_first_settlement = channel_session.chunks_consumed == 0;
+    channel_session.chunks_consumed = channel_session
+        .chunks_consumed
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    channel_session.total_spent = channel_session
+        .total_spent
+        .checked_add(chunk_price)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    channel_session.last_activity = clock.unix_timestamp;
+
+    record_sale(
+        video,
+        creator_earnings,
+        platform,
+        ctx.accounts.viewer_profile.as_deref_mut(),
+        1,
+        &amounts,
+        is_first_settlement,
+        clock.unix_timestamp,
+    )?;
+    record_earnings_bucket(
+        ctx.accounts.earnings_bucket.as_deref_mut(),
+        1,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    if let Some(watch_history) = ctx.accounts.watch_history.as_mut() {
+        watch_history.record(video.key(), chunk_index + 1, clock.unix_timestamp);
+    }
+
+    emit!(ChannelChunkPaid {
+        viewer: channel_session.viewer,
+        creator: channel_session.creator,
+        video: video.key(),
+        channel_session: channel_session.key(),
+        chunk_index,
+        amount_paid: chunk_price,
+        platform_fee: amounts.platform_fee,
+        creator_amount: amounts.creator_amount,
+        penalty_withheld: amounts.penalty_withheld,
+        fee_burned: amounts.fee_burned,
+        spend_remaining: channel_session.remaining_allowance(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Channel chunk {} paid: {} tokens (creator: {}, fee: {})",
+        chunk_index,
+        chunk_price,
+        amounts.creator_amount,
+        amounts.platform_fee
+    );
+
+    Ok(())
+}
@@ -1,19 +1,26 @@
 // =============================================================================
 // Pay For Chunk Instruction (Sequential Payment System)
 // =============================================================================
+// Signed by the viewer's wallet or by the session key it registered with
+// set_session_key; either way payments stay capped by the session approval.
+// Paying for the video's last chunk earns the completion rebate, as in
+// settle_session. Trial sessions can't pay per chunk; they settle through
+// co-signed settle_session calls.
+// =============================================================================
 
 use crate::constants::*;
 use crate::errors::*;
 use crate::events::*;
+use crate::payments::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
 pub struct PayForChunk<'info> {
     #[account(
         mut,
-        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref()],
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
         bump = viewer_session.bump,
         constraint = viewer_session.viewer == viewer.key(),
         constraint = viewer_session.video == video.key()
@@ -52,10 +59,43 @@ pub struct PayForChunk<'info> {
     )]
     pub viewer_token_account: Account<'info, TokenAccount>,
 
+    /// Gift escrow (required for gifted sessions, which pay from it instead
+    /// of the viewer's token account)
+    #[account(
+        mut,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump
+    )]
+    pub session_escrow: Option<Account<'info, TokenAccount>>,
+
+    /// Viewer's lifetime stats (updated when provided)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer_session.viewer.as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Account<'info, ViewerProfile>>,
+
+    /// Viewer's recently watched videos (updated when provided)
+    #[account(
+        mut,
+        seeds = [WATCH_HISTORY_SEED, viewer_session.viewer.as_ref()],
+        bump = watch_history.bump
+    )]
+    pub watch_history: Option<Account<'info, WatchHistory>>,
+
+    /// Video's earnings bucket for the current day (updated when provided)
+    #[account(
+        mut,
+        seeds = [EARNINGS_BUCKET_SEED, video.key().as_ref(), &earnings_bucket.day.to_le_bytes()],
+        bump = earnings_bucket.bump,
+    )]
+    pub earnings_bucket: Option<Account<'info, EarningsBucket>>,
+
     /// Creator's token account (receives payment)
     #[account(
         mut,
-        constraint = creator_token_account.owner == video.creator,
+        constraint = creator_token_account.owner == creator_earnings.payout_owner(),
         constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
@@ -68,18 +108,80 @@ pub struct PayForChunk<'info> {
     )]
     pub platform_token_account: Account<'info, TokenAccount>,
 
-    pub viewer: Signer<'info>,
+    /// Volume fee tiers (required when the platform has tiers enabled)
+    #[account(
+        seeds = [CREATOR_TIER_SEED],
+        bump = creator_tiers.bump
+    )]
+    pub creator_tiers: Option<Account<'info, CreatorTierConfig>>,
+
+    /// Creator's boost stake (earns a fee discount while locked)
+    #[account(
+        seeds = [CREATOR_STAKE_SEED, video.creator.as_ref()],
+        bump = creator_stake.bump
+    )]
+    pub creator_stake: Option<Account<'info, CreatorStake>>,
+
+    /// Platform fee split configuration (required when fee splitting is enabled).
+    /// Recipient token accounts are passed as remaining accounts, in config order.
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, FeeRecipientConfig>>,
+
+    /// Token mint (required when the platform burns a share of fees)
+    #[account(
+        mut,
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Option<Account<'info, Mint>>,
+
+    /// Insurance fund (required when the platform insures a share of fees)
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump
+    )]
+    pub insurance_fund: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Viewer wallet; bound to the session by its seeds and constraint
+    pub viewer: UncheckedAccount<'info>,
+
+    /// Viewer wallet or its registered session key
+    #[account(
+        constraint = viewer_session.is_payment_signer(&authority.key()) @ StreamingError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
-pub fn pay_for_chunk(ctx: Context<PayForChunk>, chunk_index: u32) -> Result<()> {
+pub fn pay_for_chunk<'info>(
+    ctx: Context<'_, '_, '_, 'info, PayForChunk<'info>>,
+    chunk_index: u32,
+) -> Result<()> {
     let viewer_session = &mut ctx.accounts.viewer_session;
     let video = &mut ctx.accounts.video;
     let creator_earnings = &mut ctx.accounts.creator_earnings;
     let platform = &mut ctx.accounts.platform;
     let clock = Clock::get()?;
 
+    // Validation 0: Check the video's publish window
+    video.check_publish_window(clock.unix_timestamp)?;
+
+    // Validation 0b: Single-chunk payments only apply to per-chunk billing
+    require!(
+        viewer_session.billing_mode == BillingMode::PerChunk,
+        StreamingError::BillingModeMismatch
+    );
+
+    // Validation 0c: Trial escrows only pay out through co-signed settlements
+    require!(
+        !viewer_session.is_trial_funded(),
+        StreamingError::TrialSettlementCosignRequired
+    );
+
     // Validation 1: Check session expiry
     require!(
         !viewer_session.is_expired(clock.unix_timestamp),
@@ -94,7 +196,7 @@ pub fn pay_for_chunk(ctx: Context<PayForChunk>, chunk_index: u32) -> Result<()>
 
     // Validation 3: Check chunk index is valid
     require!(
-        chunk_index < video.total_chunks,
+        video.is_valid_chunk_index(chunk_index),
         StreamingError::InvalidChunkIndex
     );
 
@@ -106,96 +208,97 @@ pub fn pay_for_chunk(ctx: Context<PayForChunk>, chunk_index: u32) -> Result<()>
 
     // Validation 5: Price lock protection (PREVENTS MID-SESSION PRICE CHANGES)
     require!(
-        video.price_per_chunk == viewer_session.approved_price_per_chunk,
+        video.chunk_price(viewer_session.quality_tier)? == viewer_session.list_price_per_chunk,
         StreamingError::PriceChangedSinceApproval
     );
 
+    let payment_account = session_payment_account(
+        viewer_session,
+        &ctx.accounts.viewer_token_account,
+        ctx.accounts.session_escrow.as_ref(),
+    )?;
+
     // Validation 6: Check viewer has sufficient balance
-    let chunk_price = video.price_per_chunk;
+    // Charge the locked price (may be discounted below the list price),
+    // scaled by the pricing curve bracket of this chunk
+    let chunk_price = viewer_session.price_of(viewer_session.chunks_consumed, 1)?;
     require!(
-        ctx.accounts.viewer_token_account.amount >= chunk_price,
+        payment_account.amount >= chunk_price,
         StreamingError::InsufficientBalance
     );
+    check_delegation(
+        viewer_session,
+        payment_account,
+        &platform.key(),
+        chunk_price,
+    )?;
 
     // Calculate payment breakdown
-    let platform_fee = platform.calculate_platform_fee(chunk_price)?;
-    let creator_amount = chunk_price
-        .checked_sub(platform_fee)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
+    let fee_basis_points = platform.effective_fee_basis_points(
+        ctx.accounts.creator_tiers.as_deref(),
+        creator_earnings.total_chunks_sold,
+        ctx.accounts.creator_stake.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    let mut amounts = SettlementAmounts::compute(platform, chunk_price, fee_basis_points)?;
+    amounts.withhold_penalty(creator_earnings);
+    amounts.apply_completion_rebate(video, viewer_session, 1)?;
+
+    let fee_recipients = platform.resolve_fee_recipients(ctx.accounts.fee_recipients.as_deref())?;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: payment_account.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
 
     // Transfer to creator (using platform PDA as delegated authority)
-    let platform_seeds = &[PLATFORM_SEED, &[platform.bump]];
-    let signer = &[&platform_seeds[..]];
+    source.transfer(
+        ctx.accounts.creator_token_account.to_account_info(),
+        amounts.creator_amount,
+    )?;
 
-    let transfer_to_creator = Transfer {
-        from: ctx.accounts.viewer_token_account.to_account_info(),
-        to: ctx.accounts.creator_token_account.to_account_info(),
-        authority: platform.to_account_info(),
-    };
-    let cpi_ctx_creator = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_to_creator,
-        signer,
-    );
-    token::transfer(cpi_ctx_creator, creator_amount)?;
-
-    // Transfer platform fee (if non-zero)
-    if platform_fee > 0 {
-        let transfer_to_platform = Transfer {
-            from: ctx.accounts.viewer_token_account.to_account_info(),
-            to: ctx.accounts.platform_token_account.to_account_info(),
-            authority: platform.to_account_info(),
-        };
-        let cpi_ctx_platform = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            transfer_to_platform,
-            signer,
-        );
-        token::transfer(cpi_ctx_platform, platform_fee)?;
-    }
+    // Transfer platform fee plus any withheld penalty (if non-zero)
+    source.pay_platform_share(
+        ctx.accounts.platform_token_account.to_account_info(),
+        ctx.accounts
+            .token_mint
+            .as_ref()
+            .map(|token_mint| token_mint.to_account_info()),
+        ctx.accounts
+            .insurance_fund
+            .as_ref()
+            .map(|insurance_fund| insurance_fund.to_account_info()),
+        fee_recipients,
+        ctx.remaining_accounts,
+        &amounts,
+    )?;
 
-    // Update viewer session state
-    viewer_session.last_activity = clock.unix_timestamp;
-    viewer_session.chunks_consumed += 1;
-    viewer_session.total_spent = viewer_session
-        .total_spent
-        .checked_add(chunk_price)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    // Update video stats
-    video.total_chunks_served = video
-        .total_chunks_served
-        .checked_add(1)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    // Update creator earnings
-    creator_earnings.total_earned = creator_earnings
-        .total_earned
-        .checked_add(creator_amount)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-    creator_earnings.total_chunks_sold = creator_earnings
-        .total_chunks_sold
-        .checked_add(1)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    // Track unique sessions (increment only on first chunk)
-    if viewer_session.chunks_consumed == 1 {
-        video.total_sessions = video
-            .total_sessions
-            .checked_add(1)
-            .ok_or(StreamingError::ArithmeticOverflow)?;
-        creator_earnings.total_sessions = creator_earnings
-            .total_sessions
-            .checked_add(1)
-            .ok_or(StreamingError::ArithmeticOverflow)?;
+    // Update session, video, creator earnings, and platform revenue
+    record_settlement(
+        viewer_session,
+        video,
+        creator_earnings,
+        platform,
+        ctx.accounts.viewer_profile.as_deref_mut(),
+        1,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    record_earnings_bucket(
+        ctx.accounts.earnings_bucket.as_deref_mut(),
+        1,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    if let Some(watch_history) = ctx.accounts.watch_history.as_mut() {
+        watch_history.record(
+            video.key(),
+            viewer_session.chunks_consumed,
+            clock.unix_timestamp,
+        );
     }
 
-    // Update platform revenue
-    platform.total_revenue = platform
-        .total_revenue
-        .checked_add(platform_fee)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
     // Emit event (instead of storing - 99.75% cost savings!)
     emit!(ChunkPaid {
         viewer: ctx.accounts.viewer.key(),
@@ -203,19 +306,24 @@ pub fn pay_for_chunk(ctx: Context<PayForChunk>, chunk_index: u32) -> Result<()>
         creator: video.creator,
         chunk_index,
         payment_sequence: viewer_session.chunks_consumed,
-        amount_paid: chunk_price,
-        platform_fee,
-        creator_amount,
+        amount_paid: amounts.total_payment,
+        platform_fee: amounts.platform_fee,
+        creator_amount: amounts.creator_amount,
+        penalty_withheld: amounts.penalty_withheld,
+        fee_burned: amounts.fee_burned,
+        completion_rebate: amounts.completion_rebate,
         chunks_remaining: viewer_session.max_approved_chunks - viewer_session.chunks_consumed,
+        watermark_id: viewer_session.watermark_id,
+        client_tag: viewer_session.client_tag,
         timestamp: clock.unix_timestamp,
     });
 
     msg!(
         "Chunk {} paid: {} tokens (creator: {}, fee: {})",
         chunk_index,
-        chunk_price,
-        creator_amount,
-        platform_fee
+        amounts.total_payment,
+        amounts.creator_amount,
+        amounts.platform_fee
     );
 
     Ok(())
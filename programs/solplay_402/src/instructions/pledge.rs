@@ -0,0 +1,104 @@
+// =============================================================================
+// Pledge Instruction (Crowdfunding)
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct MakePledge<'info> {
+    #[account(
+        mut,
+        seeds = [FUNDING_CAMPAIGN_SEED, campaign.video_id.as_bytes()],
+        bump = campaign.bump
+    )]
+    pub campaign: Account<'info, FundingCampaign>,
+
+    #[account(
+        mut,
+        seeds = [FUNDING_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = backer,
+        space = Pledge::LEN,
+        seeds = [PLEDGE_SEED, campaign.key().as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub pledge: Account<'info, Pledge>,
+
+    /// Backer's token account (source of the pledge)
+    #[account(
+        mut,
+        constraint = backer_token_account.mint == campaign_vault.mint @ StreamingError::InvalidTokenMint,
+        constraint = backer_token_account.owner == backer.key()
+    )]
+    pub backer_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pledge(ctx: Context<MakePledge>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(amount > 0, StreamingError::InvalidFundingCampaign);
+    require!(
+        !ctx.accounts.campaign.claimed && clock.unix_timestamp <= ctx.accounts.campaign.deadline,
+        StreamingError::FundingCampaignClosed
+    );
+    require!(
+        ctx.accounts.backer_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.backer_token_account.to_account_info(),
+        to: ctx.accounts.campaign_vault.to_account_info(),
+        authority: ctx.accounts.backer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let campaign = &mut ctx.accounts.campaign;
+    let pledge = &mut ctx.accounts.pledge;
+    pledge.campaign = campaign.key();
+    pledge.backer = ctx.accounts.backer.key();
+    pledge.amount = pledge
+        .amount
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    pledge.bump = ctx.bumps.pledge;
+
+    campaign.total_pledged = campaign
+        .total_pledged
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(PledgeMade {
+        campaign: campaign.key(),
+        backer: pledge.backer,
+        amount,
+        total_pledged: campaign.total_pledged,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Pledged {} tokens ({} of {} goal)",
+        amount,
+        campaign.total_pledged,
+        campaign.goal
+    );
+
+    Ok(())
+}
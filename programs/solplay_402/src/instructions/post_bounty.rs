@@ -0,0 +1,118 @@
+// =============================================================================
+// Post Bounty Instruction (Subtitles and Translations)
+// =============================================================================
+// Anyone can escrow a reward for a subtitle track in a given language on a
+// video. The creator approves the contributor whose track they register in the
+// video's metadata by co-signing claim_bounty.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(language: String)]
+pub struct PostBounty<'info> {
+    #[account(
+        init,
+        payer = poster,
+        space = SubtitleBounty::LEN,
+        seeds = [SUBTITLE_BOUNTY_SEED, video.key().as_ref(), poster.key().as_ref(), language.as_bytes()],
+        bump
+    )]
+    pub bounty: Account<'info, SubtitleBounty>,
+
+    /// Escrow holding the reward, owned by the platform PDA
+    #[account(
+        init,
+        payer = poster,
+        seeds = [SUBTITLE_BOUNTY_VAULT_SEED, bounty.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub bounty_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Poster's token account (funds the reward)
+    #[account(
+        mut,
+        constraint = poster_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = poster_token_account.owner == poster.key()
+    )]
+    pub poster_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn post_bounty(ctx: Context<PostBounty>, language: String, amount: u64) -> Result<()> {
+    require!(
+        amount > 0 && !language.is_empty() && language.len() <= MAX_LANGUAGE_LENGTH,
+        StreamingError::InvalidBounty
+    );
+    require!(
+        ctx.accounts.poster_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.poster_token_account.to_account_info(),
+        to: ctx.accounts.bounty_vault.to_account_info(),
+        authority: ctx.accounts.poster.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.video = ctx.accounts.video.key();
+    bounty.poster = ctx.accounts.poster.key();
+    bounty.language = language.clone();
+    bounty.vault = ctx.accounts.bounty_vault.key();
+    bounty.amount = amount;
+    bounty.created_at = clock.unix_timestamp;
+    bounty.bump = ctx.bumps.bounty;
+
+    emit!(BountyPosted {
+        bounty: bounty.key(),
+        video: bounty.video,
+        poster: bounty.poster,
+        language,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Bounty posted: {} tokens for {} subtitles",
+        amount,
+        bounty.language
+    );
+
+    Ok(())
+}
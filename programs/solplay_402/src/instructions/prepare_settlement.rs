@@ -0,0 +1,159 @@
+// =============================================================================
+// Prepare Settlement Instruction (Two-Phase Settlement, Phase 1)
+// =============================================================================
+// Validates a settlement batch and records the intent, amounts, and a merkle
+// root committing to the per-chunk detail - without moving any tokens.
+// execute_settlement performs the transfers. Both can be composed in a single
+// transaction or sent separately when a batch is near compute/log limits.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PrepareSettlement<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = PendingSettlement::LEN,
+        seeds = [PENDING_SETTLEMENT_SEED, viewer_session.key().as_ref()],
+        bump
+    )]
+    pub pending_settlement: Account<'info, PendingSettlement>,
+
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key() @ StreamingError::Unauthorized,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump,
+        constraint = creator_earnings.creator == video.creator @ StreamingError::Unauthorized,
+        constraint = creator_earnings.video == video.key() @ StreamingError::InvalidCreatorEarnings
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Volume fee tiers (required when the platform has tiers enabled)
+    #[account(
+        seeds = [CREATOR_TIER_SEED],
+        bump = creator_tiers.bump
+    )]
+    pub creator_tiers: Option<Account<'info, CreatorTierConfig>>,
+
+    /// Creator's boost stake (earns a fee discount while locked)
+    #[account(
+        seeds = [CREATOR_STAKE_SEED, video.creator.as_ref()],
+        bump = creator_stake.bump
+    )]
+    pub creator_stake: Option<Account<'info, CreatorStake>>,
+
+    /// Viewer wallet (must sign the settlement transaction, pays rent for the pending batch)
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn prepare_settlement(
+    ctx: Context<PrepareSettlement>,
+    chunk_count: u32,
+    settlement_timestamp: i64,
+    merkle_root: [u8; 32],
+) -> Result<()> {
+    let viewer_session = &ctx.accounts.viewer_session;
+    let platform = &ctx.accounts.platform;
+    let pending_settlement = &mut ctx.accounts.pending_settlement;
+    let clock = Clock::get()?;
+
+    // Two-phase batches are counted in chunks, and carry no viewer voucher
+    require!(
+        viewer_session.billing_mode == BillingMode::PerChunk,
+        StreamingError::BillingModeMismatch
+    );
+    require!(
+        !ctx.accounts.video.require_viewer_cosign_settlement,
+        StreamingError::SettlementCosignRequired
+    );
+    require!(
+        !viewer_session.is_trial_funded(),
+        StreamingError::TrialSettlementCosignRequired
+    );
+
+    platform.check_settlement_size(chunk_count)?;
+    let total_payment = viewer_session.validate_settlement(
+        chunk_count,
+        settlement_timestamp,
+        clock.unix_timestamp,
+    )?;
+    viewer_session.check_settlement_interval(
+        platform.min_settlement_interval,
+        chunk_count,
+        clock.unix_timestamp,
+    )?;
+
+    let fee_basis_points = platform.effective_fee_basis_points(
+        ctx.accounts.creator_tiers.as_deref(),
+        ctx.accounts.creator_earnings.total_chunks_sold,
+        ctx.accounts.creator_stake.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    let batch_discount_bps = platform.batch_discount_for(chunk_count);
+    let fee_basis_points = fee_basis_points.saturating_sub(batch_discount_bps);
+    let amounts = SettlementAmounts::compute(platform, total_payment, fee_basis_points)?;
+
+    pending_settlement.viewer_session = viewer_session.key();
+    pending_settlement.chunk_count = chunk_count;
+    pending_settlement.chunks_consumed_before = viewer_session.chunks_consumed;
+    pending_settlement.total_payment = amounts.total_payment;
+    pending_settlement.platform_fee = amounts.platform_fee;
+    pending_settlement.creator_amount = amounts.creator_amount;
+    pending_settlement.merkle_root = merkle_root;
+    pending_settlement.settlement_timestamp = settlement_timestamp;
+    pending_settlement.prepared_at = clock.unix_timestamp;
+    pending_settlement.bump = ctx.bumps.pending_settlement;
+    pending_settlement.fee_basis_points = fee_basis_points;
+    pending_settlement.batch_discount_bps = batch_discount_bps;
+
+    emit!(SettlementPrepared {
+        viewer: viewer_session.viewer,
+        video: ctx.accounts.video.key(),
+        viewer_session: viewer_session.key(),
+        pending_settlement: pending_settlement.key(),
+        chunk_count,
+        total_payment: amounts.total_payment,
+        platform_fee: amounts.platform_fee,
+        creator_amount: amounts.creator_amount,
+        merkle_root,
+        settlement_timestamp,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Settlement prepared: {} chunks, {} tokens",
+        chunk_count,
+        amounts.total_payment
+    );
+
+    Ok(())
+}
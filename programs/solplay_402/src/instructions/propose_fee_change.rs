@@ -0,0 +1,62 @@
+// =============================================================================
+// Propose Fee Change Instruction (Timelocked)
+// =============================================================================
+// Platform fee changes are two-step: the authority proposes a new fee here and
+// can only apply it with execute_fee_change once `fee_change_delay` has passed.
+// This gives creators advance notice before the take rate moves.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeFeeChange<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn propose_fee_change(ctx: Context<ProposeFeeChange>, new_fee_basis_points: u16) -> Result<()> {
+    require!(
+        new_fee_basis_points as u64 <= MAX_PLATFORM_FEE_BPS,
+        StreamingError::PlatformFeeTooHigh
+    );
+
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    // A new proposal replaces any pending one and restarts the timelock
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(platform.fee_change_delay)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    platform.pending_fee_basis_points = new_fee_basis_points;
+    platform.fee_change_eta = executable_at;
+
+    emit!(FeeChangeProposed {
+        platform: platform.key(),
+        current_fee_basis_points: platform.platform_fee_basis_points,
+        proposed_fee_basis_points: new_fee_basis_points,
+        executable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Fee change proposed: {} -> {} bps (executable at {})",
+        platform.platform_fee_basis_points,
+        new_fee_basis_points,
+        executable_at
+    );
+
+    Ok(())
+}
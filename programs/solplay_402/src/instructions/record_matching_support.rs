@@ -0,0 +1,127 @@
+// =============================================================================
+// Record Matching Support Instruction
+// =============================================================================
+// A viewer who paid for one of a creator's videos during an open round counts
+// once as that creator's supporter. Sessions funded by the platform (trials
+// and free chunks) do not count.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RecordMatchingSupport<'info> {
+    #[account(
+        mut,
+        seeds = [MATCHING_ROUND_SEED, matching_round.sponsor.as_ref(), &matching_round.round_id.to_le_bytes()],
+        bump = matching_round.bump
+    )]
+    pub matching_round: Account<'info, MatchingRound>,
+
+    #[account(
+        init_if_needed,
+        payer = viewer,
+        space = MatchingEntry::LEN,
+        seeds = [MATCHING_ENTRY_SEED, matching_round.key().as_ref(), video.creator.as_ref()],
+        bump
+    )]
+    pub matching_entry: Account<'info, MatchingEntry>,
+
+    /// Fails to initialize if the viewer was already counted for this creator
+    #[account(
+        init,
+        payer = viewer,
+        space = MatchingSupport::LEN,
+        seeds = [MATCHING_SUPPORT_SEED, matching_round.key().as_ref(), video.creator.as_ref(), viewer.key().as_ref()],
+        bump
+    )]
+    pub matching_support: Account<'info, MatchingSupport>,
+
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn record_matching_support(ctx: Context<RecordMatchingSupport>) -> Result<()> {
+    let matching_round = &mut ctx.accounts.matching_round;
+    let matching_entry = &mut ctx.accounts.matching_entry;
+    let viewer_session = &ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    require!(
+        matching_round.is_open(clock.unix_timestamp),
+        StreamingError::MatchingRoundNotOpen
+    );
+    require!(
+        viewer_session.total_spent > 0
+            && viewer_session.last_activity >= matching_round.start_time
+            && viewer_session.funded_by != ctx.accounts.platform.key(),
+        StreamingError::NoSupportInRound
+    );
+
+    let creator = ctx.accounts.video.creator;
+    if matching_entry.supporters == 0 {
+        matching_entry.round = matching_round.key();
+        matching_entry.creator = creator;
+        matching_entry.matched = false;
+        matching_entry.bump = ctx.bumps.matching_entry;
+        matching_round.creators = matching_round
+            .creators
+            .checked_add(1)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+
+    // (n + 1)^2 - n^2 = 2n + 1
+    matching_round.total_weight = matching_round
+        .total_weight
+        .checked_add(2 * matching_entry.supporters as u128 + 1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    matching_entry.supporters = matching_entry
+        .supporters
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let matching_support = &mut ctx.accounts.matching_support;
+    matching_support.round = matching_round.key();
+    matching_support.creator = creator;
+    matching_support.supporter = ctx.accounts.viewer.key();
+    matching_support.recorded_at = clock.unix_timestamp;
+    matching_support.bump = ctx.bumps.matching_support;
+
+    emit!(MatchingSupportRecorded {
+        round: matching_round.key(),
+        creator,
+        supporter: matching_support.supporter,
+        supporters: matching_entry.supporters,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Matching support recorded: creator has {} supporters",
+        matching_entry.supporters
+    );
+
+    Ok(())
+}
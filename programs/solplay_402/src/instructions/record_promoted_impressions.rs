@@ -0,0 +1,106 @@
+// =============================================================================
+// Record Promoted Impressions Instruction
+// =============================================================================
+// Called by the platform authority to settle promoted impressions served by
+// the frontend. Draws `impressions * bid` from the promotion budget (capped
+// at what remains) into the platform fee account, and deactivates the
+// promotion once the budget can no longer cover a single impression.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RecordPromotedImpressions<'info> {
+    #[account(
+        mut,
+        seeds = [PROMOTION_SEED, promotion.video.as_ref(), promotion.sponsor.as_ref()],
+        bump = promotion.bump,
+        has_one = vault @ StreamingError::InvalidPromotion,
+        constraint = promotion.is_active @ StreamingError::PromotionNotActive
+    )]
+    pub promotion: Account<'info, Promotion>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Platform's token account (receives promotion spend)
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = platform_token_account.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn record_promoted_impressions(
+    ctx: Context<RecordPromotedImpressions>,
+    impressions: u64,
+) -> Result<()> {
+    require!(impressions > 0, StreamingError::InvalidPromotion);
+
+    let promotion = &mut ctx.accounts.promotion;
+    let clock = Clock::get()?;
+
+    let amount_charged = promotion
+        .bid_per_impression
+        .checked_mul(impressions)
+        .ok_or(StreamingError::ArithmeticOverflow)?
+        .min(promotion.remaining_budget());
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.platform_token_account.to_account_info(),
+        amount_charged,
+    )?;
+
+    promotion.spent = promotion
+        .spent
+        .checked_add(amount_charged)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    promotion.impressions = promotion
+        .impressions
+        .checked_add(impressions)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    if promotion.remaining_budget() < promotion.bid_per_impression {
+        promotion.is_active = false;
+    }
+
+    emit!(PromotedImpressionsRecorded {
+        promotion: promotion.key(),
+        video: promotion.video,
+        impressions,
+        amount_charged,
+        remaining_budget: promotion.remaining_budget(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Promoted impressions recorded: {} ({} tokens)",
+        impressions,
+        amount_charged
+    );
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// =============================================================================
+// Redeem Gift Card Instruction
+// =============================================================================
+// Credits the gift card balance to the redeemer's token account, which then
+// funds sessions through the usual delegation flow. The card and its vault
+// are closed, returning rent to the issuer.
+//
+// The code never appears on-chain: its holder signs `gift_card_redeem_message`
+// (card + redeemer) with the keypair derived from the code, verified via the
+// Ed25519 precompile. The signature names the redeemer, so someone copying it
+// from the mempool can't redirect the balance to their own wallet.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::sig_verify::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RedeemGiftCard<'info> {
+    #[account(
+        mut,
+        seeds = [GIFT_CARD_SEED, gift_card.code_key.as_ref()],
+        bump = gift_card.bump,
+        has_one = issuer @ StreamingError::Unauthorized,
+        close = issuer
+    )]
+    pub gift_card: Account<'info, GiftCard>,
+
+    #[account(
+        mut,
+        seeds = [GIFT_CARD_VAULT_SEED, gift_card.code_key.as_ref()],
+        bump
+    )]
+    pub gift_card_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Redeemer's token account (credited with the card balance)
+    #[account(
+        mut,
+        constraint = redeemer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = redeemer_token_account.owner == redeemer.key()
+    )]
+    pub redeemer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Receives the rent of the closed card and vault (validated via has_one)
+    #[account(mut)]
+    pub issuer: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, read to find the Ed25519 precompile check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub redeemer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn redeem_gift_card(ctx: Context<RedeemGiftCard>) -> Result<()> {
+    let gift_card = &ctx.accounts.gift_card;
+    let clock = Clock::get()?;
+
+    // The code holder must have signed the card over to this redeemer
+    let message = gift_card_redeem_message(&gift_card.key(), &ctx.accounts.redeemer.key());
+    verify_ed25519_signature(&ctx.accounts.instructions, &gift_card.code_key, &message)?;
+
+    let amount = ctx.accounts.gift_card_vault.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.gift_card_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.redeemer_token_account.to_account_info(),
+        amount,
+    )?;
+    source.close(ctx.accounts.issuer.to_account_info())?;
+
+    emit!(GiftCardRedeemed {
+        gift_card: gift_card.key(),
+        issuer: gift_card.issuer,
+        redeemer: ctx.accounts.redeemer.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Gift card redeemed: {} tokens", amount);
+
+    Ok(())
+}
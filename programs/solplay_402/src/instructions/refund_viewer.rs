@@ -0,0 +1,87 @@
+// =============================================================================
+// Refund Viewer Instruction (Creator Goodwill Refund)
+// =============================================================================
+// Lets a creator settle customer-service complaints on-protocol: part of a
+// video's netted earnings is moved from the payout vault back to a viewer of
+// that video, and reversed out of the lifetime earnings. Only unclaimed
+// earnings can be refunded, so settlement netting must be in use.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RefundViewer<'info> {
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), viewer_session.video.as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, viewer_session.video.as_ref()],
+        bump = creator_earnings.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        mut,
+        seeds = [PAYOUT_VAULT_SEED],
+        bump
+    )]
+    pub payout_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (receives the refund)
+    #[account(
+        mut,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = viewer_token_account.owner == viewer_session.viewer @ StreamingError::Unauthorized
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn refund_viewer(ctx: Context<RefundViewer>, amount: u64) -> Result<()> {
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let clock = Clock::get()?;
+
+    creator_earnings.refund(amount)?;
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.payout_vault.to_account_info(),
+        platform: ctx.accounts.platform.to_account_info(),
+        platform_bump: ctx.accounts.platform.bump,
+    };
+    source.transfer(ctx.accounts.viewer_token_account.to_account_info(), amount)?;
+
+    emit!(ViewerRefunded {
+        creator: creator_earnings.creator,
+        video: creator_earnings.video,
+        viewer: ctx.accounts.viewer_session.viewer,
+        viewer_session: ctx.accounts.viewer_session.key(),
+        amount,
+        total_earned: creator_earnings.total_earned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Viewer refunded: {} tokens", amount);
+
+    Ok(())
+}
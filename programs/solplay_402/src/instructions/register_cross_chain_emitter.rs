@@ -0,0 +1,94 @@
+// =============================================================================
+// Register Cross-Chain Emitter Instruction
+// =============================================================================
+// Trusts (or, with `enabled = false`, stops trusting) the payment contract on
+// another chain whose Wormhole messages credit viewer sessions. One emitter is
+// kept per Wormhole chain id. The first registration also creates the
+// cross-chain float: a platform-owned token account, topped up by the platform
+// as it nets the payments received off-Solana, from which credited sessions
+// are funded.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct RegisterCrossChainEmitter<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CrossChainEmitter::LEN,
+        seeds = [CROSS_CHAIN_EMITTER_SEED, &chain_id.to_le_bytes()],
+        bump
+    )]
+    pub cross_chain_emitter: Account<'info, CrossChainEmitter>,
+
+    /// Float funding credited sessions, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [CROSS_CHAIN_VAULT_SEED],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub cross_chain_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_cross_chain_emitter(
+    ctx: Context<RegisterCrossChainEmitter>,
+    chain_id: u16,
+    emitter_address: [u8; 32],
+    core_bridge: Pubkey,
+    enabled: bool,
+) -> Result<()> {
+    let cross_chain_emitter = &mut ctx.accounts.cross_chain_emitter;
+    let clock = Clock::get()?;
+
+    cross_chain_emitter.chain_id = chain_id;
+    cross_chain_emitter.emitter_address = emitter_address;
+    cross_chain_emitter.core_bridge = core_bridge;
+    cross_chain_emitter.enabled = enabled;
+    cross_chain_emitter.bump = ctx.bumps.cross_chain_emitter;
+
+    emit!(CrossChainEmitterRegistered {
+        authority: ctx.accounts.authority.key(),
+        chain_id,
+        emitter_address,
+        core_bridge,
+        enabled,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Cross-chain emitter for chain {} {}",
+        chain_id,
+        if enabled { "enabled" } else { "disabled" }
+    );
+
+    Ok(())
+}
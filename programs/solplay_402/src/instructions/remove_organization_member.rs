@@ -0,0 +1,53 @@
+// =============================================================================
+// Remove Organization Member Instruction
+// =============================================================================
+// The admin frees a member's seat. The wallet can no longer open sessions on
+// the organization's budget; sessions it already opened keep their escrow
+// until closed, when the unspent balance returns to the treasury.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveOrganizationMember<'info> {
+    #[account(
+        mut,
+        seeds = [ORGANIZATION_SEED, admin.key().as_ref()],
+        bump = organization.bump,
+        has_one = admin @ StreamingError::Unauthorized
+    )]
+    pub organization: Box<Account<'info, Organization>>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn remove_organization_member(
+    ctx: Context<RemoveOrganizationMember>,
+    member: Pubkey,
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let clock = Clock::get()?;
+
+    let index = organization
+        .members
+        .iter()
+        .position(|m| *m == member)
+        .ok_or(StreamingError::NotOrganizationMember)?;
+    organization.members.swap_remove(index);
+    let member_count = organization.members.len() as u16;
+
+    emit!(OrganizationMemberRemoved {
+        organization: organization.key(),
+        member,
+        member_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Organization member removed: {}", member);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// =============================================================================
+// Remove Track Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveTrack<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_TRACKS_SEED, video.key().as_ref()],
+        bump = video_tracks.bump
+    )]
+    pub video_tracks: Account<'info, VideoTracks>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    /// Creator's team (required when a team member signs)
+    #[account(
+        seeds = [CREATOR_TEAM_SEED, video.creator.as_ref()],
+        bump = creator_team.bump
+    )]
+    pub creator_team: Option<Account<'info, CreatorTeam>>,
+
+    /// Video creator, or a manager or uploader on the creator's team
+    pub signer: Signer<'info>,
+}
+
+pub fn remove_track(ctx: Context<RemoveTrack>, kind: TrackKind, language: String) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == ctx.accounts.video.creator
+            || ctx
+                .accounts
+                .creator_team
+                .as_ref()
+                .is_some_and(|team| team.can_manage_tracks(&signer)),
+        StreamingError::Unauthorized
+    );
+
+    let video_tracks = &mut ctx.accounts.video_tracks;
+    let clock = Clock::get()?;
+
+    let index = video_tracks
+        .find(kind, &language)
+        .ok_or(StreamingError::TrackNotFound)?;
+    video_tracks.tracks.remove(index);
+    video_tracks.updated_at = clock.unix_timestamp;
+
+    emit!(TrackRemoved {
+        video: video_tracks.video,
+        kind,
+        language,
+        removed_by: signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Track removed: {} tracks", video_tracks.tracks.len());
+
+    Ok(())
+}
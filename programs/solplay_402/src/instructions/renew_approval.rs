@@ -0,0 +1,78 @@
+// =============================================================================
+// Renew Approval Instruction
+// =============================================================================
+// Called by the platform authority when an auto-renew session's approval is
+// nearly exhausted mid-stream. Moves up to `renewal_chunks` from the session's
+// pre-delegated renewal budget into its approval; the viewer's token
+// delegation already covers them, so no wallet signature is needed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RenewApproval<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn renew_approval(ctx: Context<RenewApproval>) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    require!(
+        !viewer_session.is_expired(clock.unix_timestamp),
+        StreamingError::SessionExpired
+    );
+    require!(
+        viewer_session.auto_renew,
+        StreamingError::AutoRenewNotEnabled
+    );
+    require!(
+        viewer_session.is_renewal_due(),
+        StreamingError::RenewalNotDue
+    );
+
+    let renewed_chunks = viewer_session.apply_renewal(clock.unix_timestamp)?;
+
+    emit!(ApprovalRenewed {
+        viewer: viewer_session.viewer,
+        viewer_session: viewer_session.key(),
+        renewed_chunks,
+        max_approved_chunks: viewer_session.max_approved_chunks,
+        renewal_budget_chunks: viewer_session.renewal_budget_chunks,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Approval renewed by {} chunks ({} budgeted chunks left)",
+        renewed_chunks,
+        viewer_session.renewal_budget_chunks
+    );
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// =============================================================================
+// Renew Session Instruction
+// =============================================================================
+// approve_streaming_delegate refuses expired or inactive sessions, which used
+// to force viewers to close and reopen them, losing the session's history.
+// Renewing restarts the session's expiry and inactivity clocks while keeping
+// its counters, approval and locked pricing. Settlements must be settled
+// first: a prepared two-phase settlement blocks renewal, since its chunks
+// were served in the old window.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RenewSession<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key() @ StreamingError::Unauthorized
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    /// CHECK: Two-phase settlement of the session; must not exist (be executed
+    /// or cancelled) before renewing
+    #[account(
+        seeds = [PENDING_SETTLEMENT_SEED, viewer_session.key().as_ref()],
+        bump,
+        constraint = pending_settlement.data_is_empty() @ StreamingError::UnsettledConsumption
+    )]
+    pub pending_settlement: UncheckedAccount<'info>,
+
+    /// CHECK: Ban marker; must not exist for the viewer to renew
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, viewer.key().as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    pub viewer: Signer<'info>,
+}
+
+pub fn renew_session(ctx: Context<RenewSession>) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    // Live sessions are extended through approve_streaming_delegate
+    require!(
+        viewer_session.is_expired(clock.unix_timestamp)
+            || viewer_session.is_inactive(clock.unix_timestamp),
+        StreamingError::SessionStillActive
+    );
+
+    viewer_session.session_start = clock.unix_timestamp;
+    viewer_session.last_activity = clock.unix_timestamp;
+
+    emit!(SessionRenewed {
+        viewer: viewer_session.viewer,
+        video: viewer_session.video,
+        viewer_session: viewer_session.key(),
+        chunks_consumed: viewer_session.chunks_consumed,
+        max_approved_chunks: viewer_session.max_approved_chunks,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Session renewed: {}/{} chunks consumed",
+        viewer_session.chunks_consumed,
+        viewer_session.max_approved_chunks
+    );
+
+    Ok(())
+}
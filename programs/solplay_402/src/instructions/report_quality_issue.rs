@@ -0,0 +1,108 @@
+// =============================================================================
+// Report Quality Issue Instruction
+// =============================================================================
+// Viewers with an active session can flag A/V problems on chunks they have
+// already paid for. Reports are reviewed by the platform via
+// verify_quality_report, which may trigger a partial refund.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReportQualityIssue<'info> {
+    #[account(
+        init,
+        payer = viewer,
+        space = QualityReport::LEN,
+        seeds = [
+            QUALITY_REPORT_SEED,
+            viewer_session.key().as_ref(),
+            &[viewer_session.quality_reports]
+        ],
+        bump
+    )]
+    pub quality_report: Account<'info, QualityReport>,
+
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key() @ StreamingError::Unauthorized,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn report_quality_issue(
+    ctx: Context<ReportQualityIssue>,
+    chunk_start: u32,
+    chunk_end: u32,
+    issue_code: QualityIssueCode,
+) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let quality_report = &mut ctx.accounts.quality_report;
+    let clock = Clock::get()?;
+
+    require!(
+        !viewer_session.is_expired(clock.unix_timestamp),
+        StreamingError::SessionExpired
+    );
+    require!(
+        viewer_session.quality_reports < MAX_QUALITY_REPORTS_PER_SESSION,
+        StreamingError::TooManyQualityReports
+    );
+
+    // Only chunks that were actually paid for can be reported
+    require!(
+        chunk_start <= chunk_end && chunk_end < viewer_session.chunks_consumed,
+        StreamingError::InvalidChunkRange
+    );
+
+    quality_report.viewer_session = viewer_session.key();
+    quality_report.viewer = ctx.accounts.viewer.key();
+    quality_report.video = ctx.accounts.video.key();
+    quality_report.chunk_start = chunk_start;
+    quality_report.chunk_end = chunk_end;
+    quality_report.issue_code = issue_code;
+    quality_report.is_verified = false;
+    quality_report.refund_amount = 0;
+    quality_report.created_at = clock.unix_timestamp;
+    quality_report.bump = ctx.bumps.quality_report;
+
+    viewer_session.quality_reports += 1;
+
+    emit!(QualityIssueReported {
+        viewer: ctx.accounts.viewer.key(),
+        video: ctx.accounts.video.key(),
+        viewer_session: viewer_session.key(),
+        quality_report: quality_report.key(),
+        chunk_start,
+        chunk_end,
+        issue_code,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Quality issue reported: chunks {}-{} ({:?})",
+        chunk_start,
+        chunk_end,
+        issue_code
+    );
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// =============================================================================
+// Request Key Release Instruction (DRM Coordination)
+// =============================================================================
+// Validates a session's payment state for one chunk and emits
+// `KeyReleaseAuthorized`, which the license server watches to issue the
+// chunk's decryption key, so the server does not re-implement session
+// validation off-chain. A chunk is released when it is already paid for or
+// still covered by the session's approval (settled later in a batch).
+// Signed by the viewer wallet or its registered session key.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RequestKeyRelease<'info> {
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    /// Viewer wallet or its registered session key
+    #[account(
+        constraint = viewer_session.is_payment_signer(&authority.key()) @ StreamingError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn request_key_release(ctx: Context<RequestKeyRelease>, chunk_index: u32) -> Result<()> {
+    let viewer_session = &ctx.accounts.viewer_session;
+    let video = &ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.check_publish_window(clock.unix_timestamp)?;
+    require!(
+        !viewer_session.is_expired(clock.unix_timestamp),
+        StreamingError::SessionExpired
+    );
+    require!(
+        !viewer_session.is_inactive(clock.unix_timestamp),
+        StreamingError::SessionInactive
+    );
+    require!(
+        video.is_valid_chunk_index(chunk_index),
+        StreamingError::InvalidChunkIndex
+    );
+
+    // Price lock protection, as for payments
+    require!(
+        video.chunk_price(viewer_session.quality_tier)? == viewer_session.list_price_per_chunk,
+        StreamingError::PriceChangedSinceApproval
+    );
+
+    let paid = viewer_session.is_chunk_paid(chunk_index);
+    require!(
+        paid || viewer_session.has_approval_remaining(),
+        StreamingError::ChunkNotPaid
+    );
+
+    let key_id = Video::content_key_id(&video.key(), chunk_index);
+
+    emit!(KeyReleaseAuthorized {
+        viewer: viewer_session.viewer,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        chunk_index,
+        key_id,
+        quality_tier: viewer_session.quality_tier,
+        paid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Key release authorized for chunk {} of session {}",
+        chunk_index,
+        viewer_session.key()
+    );
+
+    Ok(())
+}
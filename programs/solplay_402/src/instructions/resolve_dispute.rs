@@ -0,0 +1,165 @@
+// =============================================================================
+// Resolve Dispute Instruction
+// =============================================================================
+// The platform arbiter rules on an open settlement dispute. Upheld disputes
+// refund the viewer from the dispute reserve (a platform-owned token account
+// anyone can top up) and move the held amount to the creator's
+// `penalty_outstanding`, recovered from future earnings like quality refunds.
+// When the payout vault is passed, the refund is first clawed back from the
+// creator's unpaid netted earnings (still maturing, ideally) into the reserve.
+// Rejected disputes simply release the hold. Every dispute ends in exactly one
+// terminal status: this instruction, slash_operator_bond (operator at fault)
+// or compensate_viewer (insurance fund) each close out an open dispute, and
+// each releases the disputed chunks on the session (refunded chunks can't be
+// disputed again).
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, dispute.viewer_session.as_ref(), &dispute.nonce.to_le_bytes()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ StreamingError::DisputeAlreadyResolved
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: Disputed session; its dispute count is released (unless it has
+    /// since been closed)
+    #[account(mut, address = dispute.viewer_session)]
+    pub viewer_session: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, dispute.video.as_ref()],
+        bump = creator_earnings.bump
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = arbiter @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Reserve that fronts upheld refunds, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        seeds = [DISPUTE_RESERVE_SEED],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub dispute_reserve: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Viewer's token account (receives an upheld refund)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == dispute.viewer @ StreamingError::Unauthorized,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    /// Payout vault holding the creator's unpaid netted earnings
+    #[account(
+        mut,
+        seeds = [PAYOUT_VAULT_SEED],
+        bump
+    )]
+    pub payout_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, uphold: bool) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let clock = Clock::get()?;
+
+    creator_earnings.disputed_amount = creator_earnings
+        .disputed_amount
+        .checked_sub(dispute.amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let mut refund_amount = 0;
+    let mut clawed_back = 0;
+    if uphold {
+        refund_amount = dispute.amount;
+        require!(
+            ctx.accounts.dispute_reserve.amount >= refund_amount,
+            StreamingError::InsufficientBalance
+        );
+
+        let source = PaymentSource {
+            token_program: ctx.accounts.token_program.to_account_info(),
+            from: ctx.accounts.dispute_reserve.to_account_info(),
+            platform: ctx.accounts.platform.to_account_info(),
+            platform_bump: ctx.accounts.platform.bump,
+        };
+        source.transfer(
+            ctx.accounts.viewer_token_account.to_account_info(),
+            refund_amount,
+        )?;
+
+        // Reverse unpaid earnings first, the rest comes out of future earnings
+        if let Some(payout_vault) = ctx.accounts.payout_vault.as_ref() {
+            clawed_back = creator_earnings.claw_back(refund_amount);
+            if clawed_back > 0 {
+                let vault = PaymentSource {
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    from: payout_vault.to_account_info(),
+                    platform: ctx.accounts.platform.to_account_info(),
+                    platform_bump: ctx.accounts.platform.bump,
+                };
+                vault.transfer(ctx.accounts.dispute_reserve.to_account_info(), clawed_back)?;
+            }
+        }
+        creator_earnings.penalty_outstanding = creator_earnings
+            .penalty_outstanding
+            .checked_add(refund_amount - clawed_back)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        dispute.status = DisputeStatus::Refunded;
+    } else {
+        dispute.status = DisputeStatus::Rejected;
+    }
+    dispute.resolved_at = clock.unix_timestamp;
+    dispute.release_session(&ctx.accounts.viewer_session.to_account_info(), uphold)?;
+
+    emit!(DisputeResolved {
+        dispute: dispute.key(),
+        viewer: dispute.viewer,
+        video: dispute.video,
+        arbiter: ctx.accounts.arbiter.key(),
+        status: dispute.status,
+        refund_amount,
+        clawed_back,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Dispute resolved: {:?}, refund: {}",
+        dispute.status,
+        refund_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// =============================================================================
+// Revoke Age Attestation Instruction
+// =============================================================================
+// Signed by the platform's current age attestor, which can revoke
+// attestations issued under a previous attestor key as well.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeAgeAttestation<'info> {
+    #[account(
+        mut,
+        seeds = [AGE_ATTESTATION_SEED, age_attestation.viewer.as_ref()],
+        bump = age_attestation.bump,
+        close = attestor
+    )]
+    pub age_attestation: Account<'info, AgeAttestation>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        constraint = platform.age_attestor != Pubkey::default()
+            && platform.age_attestor == attestor.key() @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+}
+
+pub fn revoke_age_attestation(ctx: Context<RevokeAgeAttestation>) -> Result<()> {
+    let age_attestation = &ctx.accounts.age_attestation;
+    let clock = Clock::get()?;
+
+    emit!(AgeAttestationRevoked {
+        viewer: age_attestation.viewer,
+        attestor: ctx.accounts.attestor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Age attestation revoked for viewer {}",
+        age_attestation.viewer
+    );
+
+    Ok(())
+}
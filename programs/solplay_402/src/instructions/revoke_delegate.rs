@@ -13,7 +13,7 @@ use anchor_spl::token::{self, Revoke, Token, TokenAccount};
 pub struct RevokeDelegate<'info> {
     #[account(
         mut,
-        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref()],
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
         bump = viewer_session.bump,
         constraint = viewer_session.viewer == viewer.key()
     )]
@@ -0,0 +1,50 @@
+// =============================================================================
+// Revoke Verification Instruction
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeVerification<'info> {
+    #[account(
+        mut,
+        seeds = [CREATOR_PROFILE_SEED, creator_profile.creator.as_ref()],
+        bump = creator_profile.bump,
+        constraint = creator_profile.verified @ StreamingError::CreatorNotVerified
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    let clock = Clock::get()?;
+
+    creator_profile.verified = false;
+
+    emit!(VerificationRevoked {
+        authority: ctx.accounts.authority.key(),
+        creator: creator_profile.creator,
+        verified_at: creator_profile.verified_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Verification revoked for creator {}",
+        creator_profile.creator
+    );
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+// =============================================================================
+// Set Auto-Renew Instruction
+// =============================================================================
+// Viewer opts a session into auto-renewal. `renewal_budget_chunks` chunks are
+// delegated up front (at the session's locked price) on top of the current
+// approval, so the backend can later extend the approval `renewal_chunks` at a
+// time via `renew_approval` without a new wallet signature. Disabling clears
+// the budget and shrinks the delegation back to the approved chunks.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, ApproveChecked, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct SetAutoRenew<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key(),
+        constraint = viewer_session.video == video.key()
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Viewer's token account (delegation is resized to cover the budget)
+    #[account(
+        mut,
+        constraint = viewer_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = viewer_token_account.owner == viewer.key()
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    pub viewer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn set_auto_renew(
+    ctx: Context<SetAutoRenew>,
+    auto_renew: bool,
+    renewal_chunks: u32,
+    renewal_budget_chunks: u32,
+) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    require!(
+        !viewer_session.is_gifted(),
+        StreamingError::GiftedSessionNotExtendable
+    );
+    if auto_renew {
+        require!(
+            renewal_chunks > 0
+                && renewal_chunks <= ctx.accounts.video.max_units_per_approval()
+                && renewal_budget_chunks >= renewal_chunks,
+            StreamingError::InvalidRenewalBudget
+        );
+    }
+
+    // The delegation is shared by the viewer's sessions; swap this session's share
+    let viewer_token_account = &ctx.accounts.viewer_token_account;
+    let other_sessions_allowance =
+        if viewer_token_account.delegate == COption::Some(ctx.accounts.platform.key()) {
+            viewer_token_account
+                .delegated_amount
+                .saturating_sub(viewer_session.remaining_allowance()?)
+        } else {
+            0
+        };
+
+    viewer_session.auto_renew = auto_renew;
+    viewer_session.renewal_chunks = if auto_renew { renewal_chunks } else { 0 };
+    viewer_session.renewal_budget_chunks = if auto_renew { renewal_budget_chunks } else { 0 };
+
+    let allowance = viewer_session.remaining_allowance()?;
+    require!(
+        viewer_token_account.amount >= allowance,
+        StreamingError::InsufficientBalanceForApproval
+    );
+    let delegated_amount = allowance
+        .checked_add(other_sessions_allowance)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let cpi_accounts = ApproveChecked {
+        to: viewer_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        delegate: ctx.accounts.platform.to_account_info(),
+        authority: ctx.accounts.viewer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::approve_checked(cpi_ctx, delegated_amount, ctx.accounts.token_mint.decimals)?;
+
+    emit!(AutoRenewUpdated {
+        viewer: ctx.accounts.viewer.key(),
+        viewer_session: viewer_session.key(),
+        auto_renew,
+        renewal_chunks: viewer_session.renewal_chunks,
+        renewal_budget_chunks: viewer_session.renewal_budget_chunks,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Auto-renew {}: {} chunks per renewal, {} chunks budgeted",
+        if auto_renew { "enabled" } else { "disabled" },
+        viewer_session.renewal_chunks,
+        viewer_session.renewal_budget_chunks
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// =============================================================================
+// Set Billing Mode Instruction
+// =============================================================================
+// Switches a video between per-chunk and per-second billing. Chunk counts map
+// poorly to value for live and variable-bitrate content, so per-second videos
+// settle on `seconds_watched` using the same fee split and approval-cap math.
+// Existing sessions keep the mode (and price) they were approved under.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetBillingMode<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_billing_mode(
+    ctx: Context<SetBillingMode>,
+    billing_mode: BillingMode,
+    price_per_second: u64,
+) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    require!(
+        billing_mode == BillingMode::PerChunk || price_per_second > 0,
+        StreamingError::InvalidPricePerSecond
+    );
+
+    video.billing_mode = billing_mode;
+    video.price_per_second = price_per_second;
+
+    emit!(BillingModeUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        billing_mode,
+        price_per_second,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Billing mode updated: {:?} ({} tokens/second)",
+        billing_mode,
+        price_per_second
+    );
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// =============================================================================
+// Set Completion Rebate Instruction
+// =============================================================================
+// "Finish it and get 10% back" promotions. When a settlement (or a
+// pay_for_chunk payment) brings a session to the video's last chunk,
+// `completion_rebate_bps` of everything the viewer paid in the session is
+// netted off that payment, funded from (and capped at) the creator's share
+// of it - for pay_for_chunk, a single chunk's share. Zero turns the rebate
+// off.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCompletionRebate<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_completion_rebate(
+    ctx: Context<SetCompletionRebate>,
+    completion_rebate_bps: u16,
+) -> Result<()> {
+    require!(
+        completion_rebate_bps <= MAX_COMPLETION_REBATE_BPS,
+        StreamingError::InvalidCompletionRebate
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.completion_rebate_bps = completion_rebate_bps;
+
+    emit!(CompletionRebateUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        completion_rebate_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Completion rebate for {} set to {} bps",
+        video.video_id,
+        completion_rebate_bps
+    );
+
+    Ok(())
+}
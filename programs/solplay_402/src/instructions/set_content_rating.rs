@@ -0,0 +1,55 @@
+// =============================================================================
+// Set Content Rating Instruction
+// =============================================================================
+// Mature-rated videos only open sessions for viewers holding an age
+// attestation (see attest_age). Content flags are informational warnings for
+// players and storefronts.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetContentRating<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_content_rating(
+    ctx: Context<SetContentRating>,
+    rating: ContentRating,
+    content_flags: u16,
+) -> Result<()> {
+    require!(
+        content_flags & !CONTENT_FLAGS_MASK == 0,
+        StreamingError::InvalidContentFlags
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.rating = rating;
+    video.content_flags = content_flags;
+
+    emit!(ContentRatingUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        rating,
+        content_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Video {} rated {:?}", video.video_id, rating);
+
+    Ok(())
+}
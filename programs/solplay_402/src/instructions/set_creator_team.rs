@@ -0,0 +1,63 @@
+// =============================================================================
+// Set Creator Team Instruction
+// =============================================================================
+// Replaces the keys allowed to act for a creator. Managers may update the
+// creator's videos and uploaders may publish new ones, so day-to-day work
+// doesn't need the owner key. Passing an empty list revokes every member.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCreatorTeam<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorTeam::LEN,
+        seeds = [CREATOR_TEAM_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_team: Account<'info, CreatorTeam>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_creator_team(ctx: Context<SetCreatorTeam>, members: Vec<TeamMember>) -> Result<()> {
+    require!(
+        members.len() <= MAX_TEAM_MEMBERS,
+        StreamingError::InvalidTeamMembers
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            member.member != ctx.accounts.creator.key()
+                && members[..i].iter().all(|m| m.member != member.member),
+            StreamingError::InvalidTeamMembers
+        );
+    }
+
+    let creator_team = &mut ctx.accounts.creator_team;
+    let clock = Clock::get()?;
+
+    let member_count = members.len() as u8;
+    creator_team.creator = ctx.accounts.creator.key();
+    creator_team.members = members;
+    creator_team.bump = ctx.bumps.creator_team;
+
+    emit!(CreatorTeamUpdated {
+        creator: creator_team.creator,
+        creator_team: creator_team.key(),
+        member_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator team updated: {} members", member_count);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// =============================================================================
+// Set Creator Tiers Instruction
+// =============================================================================
+// Admin-managed volume tiers that lower the platform fee for high-volume
+// creators. Tiers are resolved at settlement time against the video's
+// CreatorEarnings.total_chunks_sold. Passing an empty list disables tiers.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCreatorTiers<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CreatorTierConfig::LEN,
+        seeds = [CREATOR_TIER_SEED],
+        bump
+    )]
+    pub creator_tiers: Account<'info, CreatorTierConfig>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_creator_tiers(ctx: Context<SetCreatorTiers>, tiers: Vec<CreatorTier>) -> Result<()> {
+    require!(
+        tiers.len() <= MAX_CREATOR_TIERS,
+        StreamingError::TooManyCreatorTiers
+    );
+
+    // Thresholds must be strictly ascending so the highest matching tier wins
+    let ascending = tiers
+        .windows(2)
+        .all(|pair| pair[0].min_chunks_sold < pair[1].min_chunks_sold);
+    let fees_valid = tiers
+        .iter()
+        .all(|tier| tier.fee_basis_points as u64 <= MAX_PLATFORM_FEE_BPS);
+    require!(ascending && fees_valid, StreamingError::InvalidCreatorTiers);
+
+    let creator_tiers = &mut ctx.accounts.creator_tiers;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    let tier_count = tiers.len() as u8;
+    creator_tiers.tiers = tiers;
+    creator_tiers.bump = ctx.bumps.creator_tiers;
+    platform.creator_tiers_enabled = tier_count > 0;
+
+    emit!(CreatorTiersUpdated {
+        platform: platform.key(),
+        creator_tiers: creator_tiers.key(),
+        tier_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator fee tiers updated: {} tiers", tier_count);
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// =============================================================================
+// Set Discount Windows Instruction
+// =============================================================================
+// Off-peak ("happy hour") pricing: up to MAX_DISCOUNT_WINDOWS recurring daily
+// windows in UTC hours, each with a discount. A viewer approving a new session
+// while a window is open gets the deepest open discount locked into the
+// session price. Passing an empty list removes them.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDiscountWindows<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_discount_windows(
+    ctx: Context<SetDiscountWindows>,
+    discount_windows: Vec<DiscountWindow>,
+) -> Result<()> {
+    require!(
+        discount_windows.len() <= MAX_DISCOUNT_WINDOWS
+            && discount_windows.iter().all(DiscountWindow::is_valid),
+        StreamingError::InvalidDiscountWindows
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.discount_windows = discount_windows;
+
+    emit!(DiscountWindowsUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        discount_windows: video.discount_windows.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Discount windows updated: {} windows",
+        video.discount_windows.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// =============================================================================
+// Set EVM Address Instruction
+// =============================================================================
+// Links an EVM wallet (e.g. MetaMask) to a session. Settlements can then be
+// authorized by a secp256k1 `personal_sign` voucher from that address instead
+// of a Solana wallet signature. Pass the zero address to unlink it.
+// =============================================================================
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetEvmAddress<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key(),
+        constraint = viewer_session.video == video.key()
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    pub viewer: Signer<'info>,
+}
+
+pub fn set_evm_address(ctx: Context<SetEvmAddress>, evm_address: [u8; 20]) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    viewer_session.evm_address = evm_address;
+
+    emit!(EvmAddressSet {
+        viewer: ctx.accounts.viewer.key(),
+        viewer_session: viewer_session.key(),
+        evm_address,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "EVM address {} for session {}",
+        if evm_address == [0u8; 20] {
+            "removed"
+        } else {
+            "linked"
+        },
+        viewer_session.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// =============================================================================
+// Set Fee Recipients Instruction
+// =============================================================================
+// Admin-configured split of the platform fee across several token accounts
+// (e.g. treasury, dev fund, buyback wallet). Weights are in basis points and
+// must sum to 100%. Passing an empty list disables splitting, sending the
+// whole fee to the platform fee account as before.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetFeeRecipients<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = FeeRecipientConfig::LEN,
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump
+    )]
+    pub fee_recipients: Account<'info, FeeRecipientConfig>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_fee_recipients(
+    ctx: Context<SetFeeRecipients>,
+    recipients: Vec<FeeRecipient>,
+) -> Result<()> {
+    require!(
+        recipients.len() <= MAX_FEE_RECIPIENTS,
+        StreamingError::InvalidFeeRecipients
+    );
+
+    if !recipients.is_empty() {
+        let total_weight: u64 = recipients.iter().map(|r| r.weight_bps as u64).sum();
+        require!(
+            total_weight == BASIS_POINTS && recipients.iter().all(|r| r.weight_bps > 0),
+            StreamingError::InvalidFeeRecipients
+        );
+    }
+
+    let fee_recipients = &mut ctx.accounts.fee_recipients;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    let recipient_count = recipients.len() as u8;
+    fee_recipients.recipients = recipients;
+    fee_recipients.bump = ctx.bumps.fee_recipients;
+    platform.fee_split_enabled = recipient_count > 0;
+
+    emit!(FeeRecipientsUpdated {
+        platform: platform.key(),
+        fee_recipients: fee_recipients.key(),
+        recipient_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Platform fee recipients updated: {}", recipient_count);
+
+    Ok(())
+}
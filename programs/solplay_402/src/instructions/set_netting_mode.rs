@@ -0,0 +1,79 @@
+// =============================================================================
+// Set Netting Mode Instruction
+// =============================================================================
+// Admin toggle for netted settlement. While enabled, settle_session makes a
+// single transfer into the platform-owned payout vault and only credits
+// `CreatorEarnings.claimable` and `Platform.fee_owed`; creators pull their
+// balance with claim_creator_payout, or flush_payouts pays each creator (and
+// the platform) in one transfer. Netting is incompatible
+// with fee burning and fee splitting, which need per-settlement transfers.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct SetNettingMode<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Payout vault holding netted balances, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [PAYOUT_VAULT_SEED],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub payout_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_netting_mode(ctx: Context<SetNettingMode>, enabled: bool) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        !enabled
+            || (platform.burn_bps == 0
+                && platform.insurance_bps == 0
+                && !platform.fee_split_enabled),
+        StreamingError::NettingUnsupported
+    );
+
+    platform.netting_enabled = enabled;
+
+    emit!(NettingModeUpdated {
+        platform: platform.key(),
+        payout_vault: ctx.accounts.payout_vault.key(),
+        enabled,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Settlement netting {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+
+    Ok(())
+}
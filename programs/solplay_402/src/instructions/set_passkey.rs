@@ -0,0 +1,59 @@
+// =============================================================================
+// Set Passkey Instruction
+// =============================================================================
+// Registers a passkey (compressed secp256r1 public key) on a session so that
+// approval renewals and settlement consent can be signed with WebAuthn via
+// passkey_consent instead of a wallet. Pass the zero key to remove it.
+// =============================================================================
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPasskey<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key(),
+        constraint = viewer_session.video == video.key()
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    pub viewer: Signer<'info>,
+}
+
+pub fn set_passkey(ctx: Context<SetPasskey>, passkey: [u8; 33]) -> Result<()> {
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    viewer_session.passkey = passkey;
+    viewer_session.settlement_consent = [0u8; 32];
+
+    emit!(PasskeySet {
+        viewer: ctx.accounts.viewer.key(),
+        viewer_session: viewer_session.key(),
+        passkey,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Passkey {} for session {}",
+        if passkey == [0u8; 33] {
+            "removed"
+        } else {
+            "registered"
+        },
+        viewer_session.key()
+    );
+
+    Ok(())
+}
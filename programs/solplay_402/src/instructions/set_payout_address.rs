@@ -0,0 +1,50 @@
+// =============================================================================
+// Set Payout Address Instruction
+// =============================================================================
+// Routes a video's creator share to a wallet other than the creator's own,
+// e.g. a treasury, DAO or cold wallet, while the creator key keeps managing
+// the video. Settlements, flushes and claims then require a creator token
+// account owned by the payout address. `Pubkey::default()` restores payouts
+// to the creator wallet.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPayoutAddress<'info> {
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, creator_earnings.video.as_ref()],
+        bump = creator_earnings.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_payout_address(ctx: Context<SetPayoutAddress>, payout_address: Pubkey) -> Result<()> {
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let clock = Clock::get()?;
+
+    creator_earnings.payout_address = payout_address;
+
+    emit!(PayoutAddressSet {
+        creator: ctx.accounts.creator.key(),
+        video: creator_earnings.video,
+        payout_address,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Payouts for video {} now go to {}",
+        creator_earnings.video,
+        creator_earnings.payout_owner()
+    );
+
+    Ok(())
+}
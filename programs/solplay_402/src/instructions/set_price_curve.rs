@@ -0,0 +1,64 @@
+// =============================================================================
+// Set Price Curve Instruction
+// =============================================================================
+// Lets creators price chunks by position, e.g. a cheap first ten chunks and
+// an expensive finale. Each break scales the session's locked price per chunk
+// from its `from_chunk`-th paid chunk onward; chunks before the first break
+// cost the plain price. Sessions opened with approve_streaming_delegate lock
+// the curve in at approval, like the price itself, and the delegation covers
+// it. Volume discounts (curves that never exceed 100%, e.g. 10% off after
+// chunk 100) also apply to gifted, trial, signed and cross-chain sessions.
+// Passing an empty list returns the video to uniform pricing.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPriceCurve<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_price_curve(ctx: Context<SetPriceCurve>, price_breaks: Vec<PriceBreak>) -> Result<()> {
+    // Breaks must start after the first chunk and be strictly ascending
+    let ascending = price_breaks
+        .iter()
+        .try_fold(0u32, |last, b| {
+            (b.from_chunk > last).then_some(b.from_chunk)
+        })
+        .is_some();
+    let multipliers_valid = price_breaks
+        .iter()
+        .all(|b| b.multiplier_bps > 0 && b.multiplier_bps <= MAX_PRICE_MULTIPLIER_BPS);
+    require!(
+        price_breaks.len() <= MAX_PRICE_BREAKS && ascending && multipliers_valid,
+        StreamingError::InvalidPriceCurve
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.price_breaks = price_breaks;
+
+    emit!(PriceCurveUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        price_breaks: video.price_breaks.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Price curve updated: {} breaks", video.price_breaks.len());
+
+    Ok(())
+}
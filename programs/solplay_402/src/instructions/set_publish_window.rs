@@ -0,0 +1,62 @@
+// =============================================================================
+// Set Publish Window Instruction
+// =============================================================================
+// Schedules when a video can be streamed, so premieres and limited-time drops
+// don't require the creator to flip `is_active` by hand. `None` clears a
+// bound. Settlements are checked against the time the chunks were served, so
+// chunks watched before the window closed can still be settled afterwards.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPublishWindow<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_publish_window(
+    ctx: Context<SetPublishWindow>,
+    publish_at: Option<i64>,
+    unpublish_at: Option<i64>,
+) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    let publish_at = publish_at.unwrap_or(0);
+    let unpublish_at = unpublish_at.unwrap_or(0);
+    require!(
+        publish_at >= 0 && unpublish_at >= 0,
+        StreamingError::InvalidPublishWindow
+    );
+    require!(
+        unpublish_at == 0 || unpublish_at > publish_at,
+        StreamingError::InvalidPublishWindow
+    );
+
+    video.publish_at = publish_at;
+    video.unpublish_at = unpublish_at;
+
+    emit!(PublishWindowUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        publish_at,
+        unpublish_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Publish window updated: {} - {}", publish_at, unpublish_at);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// =============================================================================
+// Set Quality Tiers Instruction
+// =============================================================================
+// Per-resolution pricing (e.g. 480p / 720p / 1080p / 4K) so creators can
+// charge more for higher quality without duplicate video accounts. Viewers
+// pick a tier at approval and the tier's price is locked into the session.
+// Passing an empty list returns the video to its single base price.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetQualityTiers<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_quality_tiers(ctx: Context<SetQualityTiers>, tiers: Vec<QualityTier>) -> Result<()> {
+    let platform = &ctx.accounts.platform;
+    let tiers_valid = tiers.iter().all(|tier| tier.is_valid(platform));
+    require!(
+        tiers.len() <= MAX_QUALITY_TIERS && tiers_valid,
+        StreamingError::InvalidQualityTiers
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    let tier_count = tiers.len() as u8;
+    video.quality_tiers = tiers;
+
+    emit!(QualityTiersUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        tier_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Quality tiers updated: {} tiers", tier_count);
+
+    Ok(())
+}
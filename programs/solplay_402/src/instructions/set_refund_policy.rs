@@ -0,0 +1,54 @@
+// =============================================================================
+// Set Refund Policy Instruction
+// =============================================================================
+// Publishes the refunds a creator offers: none, disputes within a number of
+// hours of settlement, or disputes for the platform's whole dispute window.
+// The policy is locked into each session at approval, so changing it never
+// affects viewers who already approved spend.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRefundPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_refund_policy(ctx: Context<SetRefundPolicy>, refund_policy: RefundPolicy) -> Result<()> {
+    require!(
+        refund_policy != RefundPolicy::WithinWindow(0),
+        StreamingError::InvalidRefundPolicy
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.refund_policy = refund_policy;
+
+    emit!(RefundPolicyUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        refund_policy,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Refund policy for {} set to {:?}",
+        video.video_id,
+        refund_policy
+    );
+
+    Ok(())
+}
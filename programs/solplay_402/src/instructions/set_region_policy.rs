@@ -0,0 +1,61 @@
+// =============================================================================
+// Set Region Policy Instruction
+// =============================================================================
+// Restricts where a video may be streamed, for licensing deals that require
+// demonstrable geo-enforcement. Once set, approvals must carry a region
+// attested by the platform (see approve_streaming_delegate); passing None
+// lifts the restriction.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRegionPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_region_policy(
+    ctx: Context<SetRegionPolicy>,
+    region_policy: Option<RegionPolicy>,
+) -> Result<()> {
+    require!(
+        region_policy.as_ref().is_none_or(RegionPolicy::is_valid),
+        StreamingError::InvalidRegionPolicy
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.region_policy = region_policy.clone();
+
+    emit!(RegionPolicyUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        region_policy,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Video {} is now {}",
+        video.video_id,
+        if video.region_policy.is_some() {
+            "region-restricted"
+        } else {
+            "unrestricted"
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+// =============================================================================
+// Set Renditions Instruction
+// =============================================================================
+// A single `ipfs_hash` can't describe an HLS ladder with separate renditions.
+// Creators publish one CID per rendition in a secondary PDA, keyed by the same
+// labels as the video's quality tiers, so backends can resolve the manifest
+// for a session's tier without off-chain lookup tables. The video's own
+// `ipfs_hash` remains the default rendition.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRenditions<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = VideoRenditions::LEN,
+        seeds = [VIDEO_RENDITIONS_SEED, video.key().as_ref()],
+        bump
+    )]
+    pub video_renditions: Account<'info, VideoRenditions>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized,
+        constraint = !video.is_immutable @ StreamingError::VideoImmutable
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_renditions(ctx: Context<SetRenditions>, renditions: Vec<Rendition>) -> Result<()> {
+    require!(
+        renditions.len() <= MAX_RENDITIONS && renditions.iter().all(Rendition::is_valid),
+        StreamingError::InvalidRenditions
+    );
+
+    let video_renditions = &mut ctx.accounts.video_renditions;
+    let clock = Clock::get()?;
+
+    let rendition_count = renditions.len() as u8;
+    video_renditions.video = ctx.accounts.video.key();
+    video_renditions.renditions = renditions;
+    video_renditions.updated_at = clock.unix_timestamp;
+    video_renditions.bump = ctx.bumps.video_renditions;
+
+    emit!(RenditionsUpdated {
+        video: video_renditions.video,
+        video_renditions: video_renditions.key(),
+        rendition_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Renditions updated: {} renditions", rendition_count);
+
+    Ok(())
+}
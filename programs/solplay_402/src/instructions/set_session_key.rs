@@ -0,0 +1,61 @@
+// =============================================================================
+// Set Session Key Instruction
+// =============================================================================
+// Registers a hot "session key" (e.g. a browser-embedded keypair) that may
+// sign pay_for_chunk for this session in place of the viewer's main wallet.
+// Payments remain capped by the session approval. Pass the default pubkey to
+// remove the key.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetSessionKey<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key(),
+        constraint = viewer_session.video == video.key()
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    pub viewer: Signer<'info>,
+}
+
+pub fn set_session_key(ctx: Context<SetSessionKey>, session_key: Pubkey) -> Result<()> {
+    require!(
+        session_key != ctx.accounts.viewer.key(),
+        StreamingError::InvalidSessionKey
+    );
+
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    viewer_session.session_key = session_key;
+
+    emit!(SessionKeySet {
+        viewer: ctx.accounts.viewer.key(),
+        viewer_session: viewer_session.key(),
+        session_key,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if session_key == Pubkey::default() {
+        msg!("Session key removed");
+    } else {
+        msg!("Session key registered: {}", session_key);
+    }
+
+    Ok(())
+}
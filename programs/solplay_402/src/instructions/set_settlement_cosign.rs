@@ -0,0 +1,45 @@
+// =============================================================================
+// Set Settlement Cosign Instruction
+// =============================================================================
+// Dual control for premium content: while set, every settle_session of the
+// video must be signed by an operator and carry an ed25519 voucher from the
+// viewer covering the exact chunk count. Two-phase and Merkle batch
+// settlements, which carry no viewer voucher, are refused.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetSettlementCosign<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_settlement_cosign(ctx: Context<SetSettlementCosign>, required: bool) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.require_viewer_cosign_settlement = required;
+
+    emit!(SettlementCosignUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        required,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Co-signed settlements for {}: {}", video.video_id, required);
+
+    Ok(())
+}
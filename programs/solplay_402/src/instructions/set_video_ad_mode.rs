@@ -0,0 +1,43 @@
+// =============================================================================
+// Set Video Ad Mode Instruction
+// =============================================================================
+// Opts a video into ad-supported viewing: settlements may then draw part or
+// all of the payment from an advertiser's campaign escrow.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVideoAdMode<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_video_ad_mode(ctx: Context<SetVideoAdMode>, ad_supported: bool) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.ad_supported = ad_supported;
+
+    emit!(VideoAdModeUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        ad_supported,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Video {} ad mode: {}", video.video_id, ad_supported);
+
+    Ok(())
+}
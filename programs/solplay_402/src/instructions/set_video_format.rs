@@ -0,0 +1,64 @@
+// =============================================================================
+// Set Video Format Instruction
+// =============================================================================
+// Publishes playback metadata players need before fetching the manifest: the
+// total duration and chunk length (to quote a video's full cost up front) and
+// the codec/container (to pick a compatible rendition).
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVideoFormat<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized,
+        constraint = !video.is_immutable @ StreamingError::VideoImmutable
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_video_format(
+    ctx: Context<SetVideoFormat>,
+    duration_seconds: u32,
+    chunk_duration_ms: u32,
+    media_format: MediaFormat,
+) -> Result<()> {
+    require!(
+        (duration_seconds == 0) == (chunk_duration_ms == 0),
+        StreamingError::InvalidVideoFormat
+    );
+
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.duration_seconds = duration_seconds;
+    video.chunk_duration_ms = chunk_duration_ms;
+    video.media_format = media_format;
+
+    emit!(VideoFormatUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        duration_seconds,
+        chunk_duration_ms,
+        media_format,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Video {} format set: {}s in {}ms chunks",
+        video.video_id,
+        duration_seconds,
+        chunk_duration_ms
+    );
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// =============================================================================
+// Set Video Gate Instruction
+// =============================================================================
+// Creators can offer a reduced (possibly zero) price to holders of an NFT from
+// a Metaplex collection. Passing `None` removes the gate.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVideoGate<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_video_gate(
+    ctx: Context<SetVideoGate>,
+    gate_collection: Option<Pubkey>,
+    gated_price_per_chunk: u64,
+) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    match gate_collection {
+        Some(collection) => {
+            require!(
+                gated_price_per_chunk <= video.price_per_chunk,
+                StreamingError::InvalidGatedPrice
+            );
+            video.gate_collection = collection;
+            video.gated_price_per_chunk = gated_price_per_chunk;
+        }
+        None => {
+            video.gate_collection = Pubkey::default();
+            video.gated_price_per_chunk = 0;
+        }
+    }
+
+    emit!(VideoGateUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        gate_collection,
+        gated_price_per_chunk: video.gated_price_per_chunk,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Video gate updated: {:?} @ {} tokens/chunk",
+        gate_collection,
+        video.gated_price_per_chunk
+    );
+
+    Ok(())
+}
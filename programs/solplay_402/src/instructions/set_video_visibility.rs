@@ -0,0 +1,47 @@
+// =============================================================================
+// Set Video Visibility Instruction
+// =============================================================================
+// Private videos can only be streamed by viewers the creator has added to the
+// allowlist (see add_allowed_viewer).
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetVideoVisibility<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn set_video_visibility(ctx: Context<SetVideoVisibility>, is_private: bool) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    video.is_private = is_private;
+
+    emit!(VideoVisibilityUpdated {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        is_private,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Video {} is now {}",
+        video.video_id,
+        if is_private { "private" } else { "public" }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,268 @@
+// =============================================================================
+// Settle Merkle Batch Instruction
+// =============================================================================
+// Settles many viewers of a video in one transaction. The operator collects
+// x402 payments off-chain into its own pool token account, then posts a
+// Merkle root of (viewer, chunk_count) leaves (see merkle.rs) and pays the
+// aggregate amount to the creator and platform from the pool. Viewers can
+// check their leaf against the stored root and contest it within the dispute
+// window with dispute_batch_leaf.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct SettleMerkleBatch<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = MerkleBatch::LEN,
+        seeds = [MERKLE_BATCH_SEED, video.key().as_ref(), &batch_id.to_le_bytes()],
+        bump
+    )]
+    pub merkle_batch: Account<'info, MerkleBatch>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump,
+        constraint = creator_earnings.creator == video.creator @ StreamingError::Unauthorized,
+        constraint = creator_earnings.video == video.key() @ StreamingError::InvalidCreatorEarnings
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Operator's pool of collected viewer payments (source of the batch)
+    #[account(
+        mut,
+        constraint = operator_pool.owner == operator.key(),
+        constraint = operator_pool.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub operator_pool: Account<'info, TokenAccount>,
+
+    /// Creator's token account (receives the creator share)
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator_earnings.payout_owner(),
+        constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// Platform's token account (receives fees)
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = platform_token_account.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    /// Volume fee tiers (required when the platform has tiers enabled)
+    #[account(
+        seeds = [CREATOR_TIER_SEED],
+        bump = creator_tiers.bump
+    )]
+    pub creator_tiers: Option<Account<'info, CreatorTierConfig>>,
+
+    /// Creator's boost stake (earns a fee discount while locked)
+    #[account(
+        seeds = [CREATOR_STAKE_SEED, video.creator.as_ref()],
+        bump = creator_stake.bump
+    )]
+    pub creator_stake: Option<Account<'info, CreatorStake>>,
+
+    /// Video's earnings bucket for the current day (updated when provided)
+    #[account(
+        mut,
+        seeds = [EARNINGS_BUCKET_SEED, video.key().as_ref(), &earnings_bucket.day.to_le_bytes()],
+        bump = earnings_bucket.bump,
+    )]
+    pub earnings_bucket: Option<Account<'info, EarningsBucket>>,
+
+    /// Operator's bond (required when the platform sets a minimum bond)
+    #[account(
+        mut,
+        seeds = [OPERATOR_BOND_SEED, operator.key().as_ref()],
+        bump = operator_bond.bump
+    )]
+    pub operator_bond: Option<Account<'info, OperatorBond>>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn settle_merkle_batch(
+    ctx: Context<SettleMerkleBatch>,
+    batch_id: u64,
+    root: [u8; 32],
+    leaf_count: u32,
+    total_chunks: u64,
+    total_amount: u64,
+) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    video.check_publish_window(clock.unix_timestamp)?;
+    // Batch leaves carry no per-viewer voucher
+    require!(
+        !video.require_viewer_cosign_settlement,
+        StreamingError::SettlementCosignRequired
+    );
+
+    // Leaves must fit a proof of at most MAX_MERKLE_PROOF_DEPTH siblings, and
+    // the batch can't charge more than the list price for its chunks
+    let max_amount = (video.unit_price() as u128)
+        .checked_mul(total_chunks as u128)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    require!(
+        root != [0u8; 32]
+            && leaf_count > 0
+            && leaf_count <= 1 << MAX_MERKLE_PROOF_DEPTH
+            && total_amount > 0
+            && total_amount as u128 <= max_amount,
+        StreamingError::InvalidMerkleBatch
+    );
+
+    if platform.min_operator_bond > 0 {
+        let operator_bond = ctx
+            .accounts
+            .operator_bond
+            .as_mut()
+            .ok_or(StreamingError::OperatorBondRequired)?;
+        require!(
+            operator_bond.amount >= platform.min_operator_bond,
+            StreamingError::InsufficientOperatorBond
+        );
+        operator_bond.last_settled_at = clock.unix_timestamp;
+    }
+
+    require!(
+        ctx.accounts.operator_pool.amount >= total_amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let fee_basis_points = platform.effective_fee_basis_points(
+        ctx.accounts.creator_tiers.as_deref(),
+        creator_earnings.total_chunks_sold,
+        ctx.accounts.creator_stake.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    let mut amounts = SettlementAmounts::compute(platform, total_amount, fee_basis_points)?;
+    amounts.withhold_penalty(creator_earnings);
+
+    // The operator signs for its own pool, so the platform-PDA fee routing
+    // (burns, insurance and fee splits) isn't available here
+    require!(
+        !platform.fee_split_enabled && amounts.fee_burned == 0 && amounts.fee_insured == 0,
+        StreamingError::BatchFeeRoutingUnsupported
+    );
+
+    let operator = ctx.accounts.operator.to_account_info();
+    let pool = ctx.accounts.operator_pool.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    for (to, amount) in [
+        (
+            ctx.accounts.creator_token_account.to_account_info(),
+            amounts.creator_amount,
+        ),
+        (
+            ctx.accounts.platform_token_account.to_account_info(),
+            amounts.platform_transfer_amount()?,
+        ),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let cpi_accounts = Transfer {
+            from: pool.clone(),
+            to,
+            authority: operator.clone(),
+        };
+        token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), amount)?;
+    }
+
+    // Aggregate bookkeeping; per-viewer detail lives in the Merkle leaves
+    video.total_chunks_served = video
+        .total_chunks_served
+        .checked_add(total_chunks)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    creator_earnings.total_earned = creator_earnings
+        .total_earned
+        .checked_add(amounts.creator_amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    creator_earnings.total_chunks_sold = creator_earnings
+        .total_chunks_sold
+        .checked_add(total_chunks)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    platform.total_revenue = platform
+        .total_revenue
+        .checked_add(amounts.distributable_fee())
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    record_earnings_bucket(
+        ctx.accounts.earnings_bucket.as_deref_mut(),
+        total_chunks,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+
+    let merkle_batch = &mut ctx.accounts.merkle_batch;
+    merkle_batch.video = video.key();
+    merkle_batch.operator = ctx.accounts.operator.key();
+    merkle_batch.batch_id = batch_id;
+    merkle_batch.root = root;
+    merkle_batch.leaf_count = leaf_count;
+    merkle_batch.total_chunks = total_chunks;
+    merkle_batch.total_amount = total_amount;
+    merkle_batch.disputed_leaves = 0;
+    merkle_batch.settled_at = clock.unix_timestamp;
+    merkle_batch.bump = ctx.bumps.merkle_batch;
+
+    emit!(MerkleBatchSettled {
+        video: video.key(),
+        operator: merkle_batch.operator,
+        batch: merkle_batch.key(),
+        batch_id,
+        root,
+        leaf_count,
+        total_chunks,
+        total_amount,
+        platform_fee: amounts.platform_fee,
+        creator_amount: amounts.creator_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Merkle batch {} settled: {} viewers, {} chunks, {} tokens",
+        batch_id,
+        leaf_count,
+        total_chunks,
+        total_amount
+    );
+
+    Ok(())
+}
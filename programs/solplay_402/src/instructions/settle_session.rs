@@ -3,20 +3,34 @@
 // =============================================================================
 // This instruction settles a batch of chunks consumed via x402 off-chain payments
 // Called by backend after accumulating chunk views from HTTP streaming
+// The viewer consents by signing the transaction, through a passkey consent
+// recorded by passkey_consent for these exact terms, or, for sessions with a
+// registered EVM address, through a secp256k1 `personal_sign` voucher verified
+// by a precompile instruction placed right before this one. A settlement may
+// instead reference a verified x402 payment proof (see verify_x402_proof),
+// which both authorizes it and caps its payment; each proof settles once.
+// Videos requiring co-signed settlements accept none of these alone: every
+// settlement needs an operator signature plus an ed25519 voucher from the
+// viewer over its exact terms, verified by a precompile right before this one.
+// The same holds for trial sessions paid from the trial vault, whose operator
+// must also not be the video's creator.
 // =============================================================================
 
 use crate::constants::*;
 use crate::errors::*;
 use crate::events::*;
+use crate::payments::*;
+use crate::sig_verify::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
 pub struct SettleSession<'info> {
     #[account(
         mut,
-        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref()],
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
         bump = viewer_session.bump,
         constraint = viewer_session.viewer == viewer.key() @ StreamingError::Unauthorized,
         constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
@@ -55,13 +69,47 @@ pub struct SettleSession<'info> {
     )]
     pub viewer_token_account: Account<'info, TokenAccount>,
 
-    /// Creator's token account (receives payment)
+    /// Gift escrow (required for gifted sessions, which pay from it instead
+    /// of the viewer's token account)
     #[account(
         mut,
-        constraint = creator_token_account.owner == video.creator,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump
+    )]
+    pub session_escrow: Option<Account<'info, TokenAccount>>,
+
+    /// Viewer's lifetime stats (updated when provided)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, viewer_session.viewer.as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Account<'info, ViewerProfile>>,
+
+    /// Viewer's recently watched videos (updated when provided)
+    #[account(
+        mut,
+        seeds = [WATCH_HISTORY_SEED, viewer_session.viewer.as_ref()],
+        bump = watch_history.bump
+    )]
+    pub watch_history: Option<Account<'info, WatchHistory>>,
+
+    /// Video's earnings bucket for the current day (updated when provided)
+    #[account(
+        mut,
+        seeds = [EARNINGS_BUCKET_SEED, video.key().as_ref(), &earnings_bucket.day.to_le_bytes()],
+        bump = earnings_bucket.bump,
+    )]
+    pub earnings_bucket: Option<Account<'info, EarningsBucket>>,
+
+    /// Creator's token account (receives payment; not needed when netting,
+    /// as the creator claims from the payout vault instead)
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator_earnings.payout_owner(),
         constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
 
     /// Platform's token account (receives fees)
     #[account(
@@ -71,16 +119,142 @@ pub struct SettleSession<'info> {
     )]
     pub platform_token_account: Account<'info, TokenAccount>,
 
-    /// Viewer wallet (must sign the settlement transaction)
-    pub viewer: Signer<'info>,
+    /// Volume fee tiers (required when the platform has tiers enabled)
+    #[account(
+        seeds = [CREATOR_TIER_SEED],
+        bump = creator_tiers.bump
+    )]
+    pub creator_tiers: Option<Account<'info, CreatorTierConfig>>,
+
+    /// Creator's boost stake (earns a fee discount while locked)
+    #[account(
+        seeds = [CREATOR_STAKE_SEED, video.creator.as_ref()],
+        bump = creator_stake.bump
+    )]
+    pub creator_stake: Option<Account<'info, CreatorStake>>,
+
+    /// Platform fee split configuration (required when fee splitting is enabled).
+    /// Recipient token accounts are passed as remaining accounts, in config order.
+    #[account(
+        seeds = [FEE_RECIPIENTS_SEED],
+        bump = fee_recipients.bump
+    )]
+    pub fee_recipients: Option<Account<'info, FeeRecipientConfig>>,
+
+    /// Token mint (required when the platform burns a share of fees)
+    #[account(
+        mut,
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Option<Account<'info, Mint>>,
+
+    /// Insurance fund (required when the platform insures a share of fees)
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED],
+        bump
+    )]
+    pub insurance_fund: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Watch-to-earn pool (rewards accrue when passed with viewer_rewards)
+    #[account(
+        mut,
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump
+    )]
+    pub rewards_pool: Option<Account<'info, RewardsPool>>,
+
+    /// Viewer's opt-in rewards balance
+    #[account(
+        mut,
+        seeds = [VIEWER_REWARDS_SEED, viewer_session.viewer.as_ref()],
+        bump = viewer_rewards.bump
+    )]
+    pub viewer_rewards: Option<Account<'info, ViewerRewards>>,
+
+    /// Platform-token emission schedule (emissions accrue when passed with
+    /// both the creator's and the viewer's emission balances)
+    #[account(
+        mut,
+        seeds = [EMISSION_SCHEDULE_SEED],
+        bump = emission_schedule.bump
+    )]
+    pub emission_schedule: Option<Box<Account<'info, EmissionSchedule>>>,
+
+    /// Creator's opt-in emission balance
+    #[account(
+        mut,
+        seeds = [EMISSION_BALANCE_SEED, video.creator.as_ref()],
+        bump = creator_emissions.bump
+    )]
+    pub creator_emissions: Option<Box<Account<'info, EmissionBalance>>>,
+
+    /// Viewer's opt-in emission balance
+    #[account(
+        mut,
+        seeds = [EMISSION_BALANCE_SEED, viewer_session.viewer.as_ref()],
+        bump = viewer_emissions.bump
+    )]
+    pub viewer_emissions: Option<Box<Account<'info, EmissionBalance>>>,
+
+    /// Ad campaign subsidizing this settlement (ad-supported videos of its
+    /// target creators only)
+    #[account(
+        mut,
+        seeds = [AD_CAMPAIGN_SEED, ad_campaign.advertiser.as_ref(), &ad_campaign.campaign_id.to_le_bytes()],
+        bump = ad_campaign.bump
+    )]
+    pub ad_campaign: Option<Account<'info, AdCampaign>>,
+
+    /// Ad campaign escrow (required with ad_campaign)
+    #[account(mut)]
+    pub ad_campaign_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Payout vault (required when the platform nets settlements)
+    #[account(
+        mut,
+        seeds = [PAYOUT_VAULT_SEED],
+        bump
+    )]
+    pub payout_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Settling operator's bond (required when the platform sets a minimum bond)
+    #[account(
+        mut,
+        seeds = [OPERATOR_BOND_SEED, operator_bond.operator.as_ref()],
+        bump = operator_bond.bump
+    )]
+    pub operator_bond: Option<Account<'info, OperatorBond>>,
+
+    /// x402 payment proof backing this settlement (consumed when provided)
+    #[account(
+        mut,
+        seeds = [X402_PROOF_SEED, viewer_session.key().as_ref(), &x402_proof.nonce],
+        bump = x402_proof.bump
+    )]
+    pub x402_proof: Option<Box<Account<'info, X402Proof>>>,
+
+    /// Operator submitting the settlement (signs alongside the viewer)
+    pub operator: Option<Signer<'info>>,
+
+    /// CHECK: Viewer wallet; must sign unless a passkey consent, EVM voucher or
+    /// x402 proof authorizes the settlement
+    pub viewer: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar (required for EVM voucher and co-signed settlements)
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
 
     pub token_program: Program<'info, Token>,
 }
 
-pub fn settle_session(
-    ctx: Context<SettleSession>,
+pub fn settle_session<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleSession<'info>>,
     chunk_count: u32,
     settlement_timestamp: i64,
+    seconds_watched: u32,
+    settlement_id: [u8; 16],
+    last_watched_chunk: Option<u32>,
 ) -> Result<()> {
     let viewer_session = &mut ctx.accounts.viewer_session;
     let video = &mut ctx.accounts.video;
@@ -88,147 +262,367 @@ pub fn settle_session(
     let platform = &mut ctx.accounts.platform;
     let clock = Clock::get()?;
 
-    // ═══════════════════════════════════════════════════════════
-    // VALIDATION 1: Check chunk count is valid
-    // ═══════════════════════════════════════════════════════════
-    require!(chunk_count > 0, StreamingError::InvalidChunkCount);
-
-    // ═══════════════════════════════════════════════════════════
-    // VALIDATION 2: Check Session State
-    // ═══════════════════════════════════════════════════════════
-    require!(
-        !viewer_session.is_expired(clock.unix_timestamp),
-        StreamingError::SessionExpired
+    // Viewer consent: a wallet signature, an x402 payment proof, a passkey
+    // consent, or a voucher from the session's EVM address
+    let voucher_message = settlement_voucher_message(
+        &viewer_session.key(),
+        viewer_session.settlement_count,
+        chunk_count,
+        seconds_watched,
+        settlement_timestamp,
     );
+    // Trial escrows cost the viewer nothing, so a creator must not be able to
+    // settle trials from throwaway wallets to itself
+    let trial_funded = viewer_session.is_trial_funded();
+    if video.require_viewer_cosign_settlement || trial_funded {
+        let operator = ctx
+            .accounts
+            .operator
+            .as_ref()
+            .ok_or(StreamingError::SettlementCosignRequired)?;
+        require_keys_neq!(
+            operator.key(),
+            viewer_session.viewer,
+            StreamingError::SettlementCosignRequired
+        );
+        require!(
+            !trial_funded || operator.key() != video.creator,
+            StreamingError::TrialSettlementCosignRequired
+        );
+        let instructions = ctx
+            .accounts
+            .instructions
+            .as_ref()
+            .ok_or(StreamingError::SettlementCosignRequired)?;
+        verify_ed25519_signature(instructions, &viewer_session.viewer, &voucher_message)?;
+    } else if !ctx.accounts.viewer.is_signer
+        && ctx.accounts.x402_proof.is_none()
+        && !viewer_session.take_settlement_consent(&voucher_message)
+    {
+        let instructions = ctx
+            .accounts
+            .instructions
+            .as_ref()
+            .ok_or(StreamingError::ViewerConsentRequired)?;
+        require!(
+            viewer_session.evm_address != [0u8; 20],
+            StreamingError::ViewerConsentRequired
+        );
+        verify_secp256k1_signature(instructions, &viewer_session.evm_address, &voucher_message)?;
+    }
 
-    // Allow settlement even if inactive (backend might have queued chunks)
-    // But ensure settlement timestamp is not too old
+    // Idempotency: workers retrying a settlement must not double-charge
     require!(
-        settlement_timestamp >= viewer_session.session_start,
-        StreamingError::SettlementTooOld
+        !viewer_session.is_duplicate_settlement(&settlement_id),
+        StreamingError::DuplicateSettlement
     );
 
-    require!(
-        settlement_timestamp <= clock.unix_timestamp,
-        StreamingError::SettlementInFuture
-    );
+    // Resume position must be a chunk of the video
+    if let Some(chunk_index) = last_watched_chunk {
+        require!(
+            video.is_valid_chunk_index(chunk_index),
+            StreamingError::InvalidChunkIndex
+        );
+    }
+
+    // Per-second sessions bill (and cap approval) in seconds rather than chunks
+    platform.check_settlement_size(chunk_count)?;
+    let chunk_count = viewer_session.billable_units(chunk_count, seconds_watched)?;
 
     // ═══════════════════════════════════════════════════════════
-    // VALIDATION 3: Check Approval Limits
+    // VALIDATION 1-4: Chunk count, session state, approval limits, locked price
     // ═══════════════════════════════════════════════════════════
-    let new_total_chunks = viewer_session
-        .chunks_consumed
-        .checked_add(chunk_count)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
+    let total_payment_u64 = viewer_session.validate_settlement(
+        chunk_count,
+        settlement_timestamp,
+        clock.unix_timestamp,
+    )?;
+    viewer_session.check_settlement_interval(
+        platform.min_settlement_interval,
+        chunk_count,
+        clock.unix_timestamp,
+    )?;
+    viewer_session.record_settled_value(
+        platform.max_settlement_value_per_hour_per_session,
+        total_payment_u64,
+        clock.unix_timestamp,
+    )?;
+
+    // Chunks must have been served inside the video's publish window
+    video.check_publish_window(settlement_timestamp)?;
+
+    // A referenced x402 proof must cover the payment
+    if let Some(x402_proof) = ctx.accounts.x402_proof.as_ref() {
+        require!(!x402_proof.settled, StreamingError::X402ProofAlreadySettled);
+        require!(
+            total_payment_u64 <= x402_proof.amount,
+            StreamingError::X402ProofAmountExceeded
+        );
+    }
 
-    require!(
-        new_total_chunks <= viewer_session.max_approved_chunks,
-        StreamingError::SettlementExceedsApproval
-    );
+    // Bonded operators back the off-chain chunk count with their stake
+    let operator = match (
+        ctx.accounts.operator_bond.as_ref(),
+        ctx.accounts.operator.as_ref(),
+    ) {
+        (Some(operator_bond), Some(operator)) => {
+            require_keys_eq!(
+                operator_bond.operator,
+                operator.key(),
+                StreamingError::OperatorBondMismatch
+            );
+            require!(
+                operator_bond.amount >= platform.min_operator_bond,
+                StreamingError::InsufficientOperatorBond
+            );
+            operator.key()
+        }
+        (None, None) => {
+            require!(
+                platform.min_operator_bond == 0,
+                StreamingError::OperatorBondRequired
+            );
+            Pubkey::default()
+        }
+        _ => return err!(StreamingError::OperatorBondRequired),
+    };
+
+    let payment_account = session_payment_account(
+        viewer_session,
+        &ctx.accounts.viewer_token_account,
+        ctx.accounts.session_escrow.as_ref(),
+    )?;
 
     // ═══════════════════════════════════════════════════════════
-    // VALIDATION 4: Price Consistency
+    // PAYMENT DISTRIBUTION
     // ═══════════════════════════════════════════════════════════
-    // Use locked price from approval time (protects viewer)
-    let price_per_chunk = viewer_session.approved_price_per_chunk;
-    let total_payment = (price_per_chunk as u128)
-        .checked_mul(chunk_count as u128)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    let total_payment_u64 =
-        u64::try_from(total_payment).map_err(|_| StreamingError::ArithmeticOverflow)?;
+    let fee_basis_points = platform.effective_fee_basis_points(
+        ctx.accounts.creator_tiers.as_deref(),
+        creator_earnings.total_chunks_sold,
+        ctx.accounts.creator_stake.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    let batch_discount_bps = platform.batch_discount_for(chunk_count);
+    let fee_basis_points = fee_basis_points.saturating_sub(batch_discount_bps);
+    let mut amounts = SettlementAmounts::compute(platform, total_payment_u64, fee_basis_points)?;
+    amounts.withhold_penalty(creator_earnings);
+    amounts.apply_completion_rebate(video, viewer_session, chunk_count)?;
+
+    // Ad-supported viewing: the campaign escrow covers part (or all) of the payment
+    let ad_source = match (
+        ctx.accounts.ad_campaign.as_ref(),
+        ctx.accounts.ad_campaign_vault.as_ref(),
+    ) {
+        (Some(ad_campaign), Some(ad_campaign_vault)) => {
+            require!(video.ad_supported, StreamingError::VideoNotAdSupported);
+            require!(
+                ad_campaign.targets(&video.creator),
+                StreamingError::AdCampaignCreatorNotTargeted
+            );
+            require_keys_eq!(
+                ad_campaign_vault.key(),
+                ad_campaign.vault,
+                StreamingError::AdCampaignVaultMismatch
+            );
+            amounts.ad_subsidy = ad_campaign.subsidy_for(
+                chunk_count,
+                amounts.total_payment,
+                amounts.creator_amount,
+                ad_campaign_vault.amount,
+            )?;
+            Some(PaymentSource {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: ad_campaign_vault.to_account_info(),
+                platform: platform.to_account_info(),
+                platform_bump: platform.bump,
+            })
+        }
+        (None, None) => None,
+        _ => return err!(StreamingError::AdCampaignVaultMismatch),
+    };
 
     // ═══════════════════════════════════════════════════════════
     // VALIDATION 5: Check viewer has sufficient balance
     // ═══════════════════════════════════════════════════════════
     require!(
-        ctx.accounts.viewer_token_account.amount >= total_payment_u64,
+        payment_account.amount >= amounts.viewer_paid(),
         StreamingError::InsufficientBalance
     );
-
-    // ═══════════════════════════════════════════════════════════
-    // PAYMENT DISTRIBUTION
-    // ═══════════════════════════════════════════════════════════
-    let platform_fee = platform.calculate_platform_fee(total_payment_u64)?;
-    let creator_amount = total_payment_u64
-        .checked_sub(platform_fee)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    // Transfer to creator (90%)
-    let platform_seeds = &[PLATFORM_SEED, &[platform.bump]];
-    let signer = &[&platform_seeds[..]];
-
-    let transfer_to_creator = Transfer {
-        from: ctx.accounts.viewer_token_account.to_account_info(),
-        to: ctx.accounts.creator_token_account.to_account_info(),
-        authority: platform.to_account_info(),
+    check_delegation(
+        viewer_session,
+        payment_account,
+        &platform.key(),
+        amounts.viewer_paid(),
+    )?;
+
+    let fee_recipients = platform.resolve_fee_recipients(ctx.accounts.fee_recipients.as_deref())?;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: payment_account.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
     };
-    let cpi_ctx_creator = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_to_creator,
-        signer,
-    );
-    token::transfer(cpi_ctx_creator, creator_amount)?;
-
-    // Transfer platform fee (10%)
-    if platform_fee > 0 {
-        let transfer_to_platform = Transfer {
-            from: ctx.accounts.viewer_token_account.to_account_info(),
-            to: ctx.accounts.platform_token_account.to_account_info(),
-            authority: platform.to_account_info(),
-        };
-        let cpi_ctx_platform = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            transfer_to_platform,
-            signer,
+
+    if platform.netting_enabled {
+        // Netting: a single transfer into the payout vault; creator and platform
+        // balances are credited here and paid out in bulk by flush_payouts
+        require!(
+            fee_recipients.is_none() && amounts.fee_burned == 0 && amounts.fee_insured == 0,
+            StreamingError::NettingUnsupported
         );
-        token::transfer(cpi_ctx_platform, platform_fee)?;
+        let payout_vault = ctx
+            .accounts
+            .payout_vault
+            .as_ref()
+            .ok_or(StreamingError::PayoutVaultRequired)?;
+        if let Some(ad_source) = ad_source.as_ref() {
+            ad_source.transfer(payout_vault.to_account_info(), amounts.ad_subsidy)?;
+        }
+        source.transfer(payout_vault.to_account_info(), amounts.viewer_paid())?;
+        credit_netted_payout(creator_earnings, platform, &amounts, clock.unix_timestamp)?;
+    } else {
+        let creator_token_account = ctx
+            .accounts
+            .creator_token_account
+            .as_ref()
+            .ok_or(StreamingError::CreatorTokenAccountRequired)?;
+
+        // Transfer to creator (90%), subsidized portion first
+        let creator_subsidy = amounts.ad_subsidy.min(amounts.creator_amount);
+        if let Some(ad_source) = ad_source.as_ref() {
+            ad_source.transfer(creator_token_account.to_account_info(), creator_subsidy)?;
+        }
+        source.transfer(
+            creator_token_account.to_account_info(),
+            amounts.creator_amount - creator_subsidy,
+        )?;
+
+        // Transfer platform fee (10%) plus any withheld penalty
+        // (from the campaign only when it covers the whole payment)
+        let platform_source = match ad_source.as_ref() {
+            Some(ad_source) if amounts.ad_subsidy > creator_subsidy => ad_source,
+            _ => &source,
+        };
+        platform_source.pay_platform_share(
+            ctx.accounts.platform_token_account.to_account_info(),
+            ctx.accounts
+                .token_mint
+                .as_ref()
+                .map(|token_mint| token_mint.to_account_info()),
+            ctx.accounts
+                .insurance_fund
+                .as_ref()
+                .map(|insurance_fund| insurance_fund.to_account_info()),
+            fee_recipients,
+            ctx.remaining_accounts,
+            &amounts,
+        )?;
     }
 
     // ═══════════════════════════════════════════════════════════
     // STATE UPDATES (Bulk Update - Not Per Chunk!)
     // ═══════════════════════════════════════════════════════════
-    viewer_session.chunks_consumed = new_total_chunks;
-    viewer_session.total_spent = viewer_session
-        .total_spent
-        .checked_add(total_payment_u64)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-    viewer_session.last_activity = clock.unix_timestamp;
-
-    // Update video stats
-    video.total_chunks_served = video
-        .total_chunks_served
-        .checked_add(chunk_count as u64)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    // Update creator earnings
-    creator_earnings.total_earned = creator_earnings
-        .total_earned
-        .checked_add(creator_amount)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    creator_earnings.total_chunks_sold = creator_earnings
-        .total_chunks_sold
-        .checked_add(chunk_count as u64)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
-
-    // Track unique sessions (increment only on first settlement)
-    if viewer_session.chunks_consumed == chunk_count {
-        video.total_sessions = video
-            .total_sessions
-            .checked_add(1)
+    record_settlement(
+        viewer_session,
+        video,
+        creator_earnings,
+        platform,
+        ctx.accounts.viewer_profile.as_deref_mut(),
+        chunk_count,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    record_earnings_bucket(
+        ctx.accounts.earnings_bucket.as_deref_mut(),
+        chunk_count as u64,
+        &amounts,
+        clock.unix_timestamp,
+    )?;
+    if let Some(watch_history) = ctx.accounts.watch_history.as_mut() {
+        watch_history.record(
+            video.key(),
+            viewer_session.chunks_consumed,
+            clock.unix_timestamp,
+        );
+    }
+    viewer_session.remember_settlement_id(settlement_id);
+    if let Some(chunk_index) = last_watched_chunk {
+        viewer_session.last_watched_chunk = chunk_index;
+    }
+    viewer_session.last_operator = operator;
+    if let Some(operator_bond) = ctx.accounts.operator_bond.as_mut() {
+        operator_bond.last_settled_at = clock.unix_timestamp;
+    }
+    let x402_proof = match ctx.accounts.x402_proof.as_mut() {
+        Some(x402_proof) => {
+            x402_proof.settled = true;
+            x402_proof.key()
+        }
+        None => Pubkey::default(),
+    };
+
+    // Record ad impressions against the campaign
+    if let Some(ad_campaign) = ctx.accounts.ad_campaign.as_mut() {
+        ad_campaign.impressions = ad_campaign
+            .impressions
+            .checked_add(chunk_count as u64)
             .ok_or(StreamingError::ArithmeticOverflow)?;
-        creator_earnings.total_sessions = creator_earnings
-            .total_sessions
-            .checked_add(1)
+        ad_campaign.total_spent = ad_campaign
+            .total_spent
+            .checked_add(amounts.ad_subsidy)
             .ok_or(StreamingError::ArithmeticOverflow)?;
+
+        emit!(AdImpressionsRecorded {
+            ad_campaign: ad_campaign.key(),
+            video: video.key(),
+            viewer_session: viewer_session.key(),
+            impressions: chunk_count,
+            subsidy: amounts.ad_subsidy,
+            timestamp: clock.unix_timestamp,
+        });
     }
 
-    // Update platform revenue
-    platform.total_revenue = platform
-        .total_revenue
-        .checked_add(platform_fee)
-        .ok_or(StreamingError::ArithmeticOverflow)?;
+    // Accrue watch-to-earn rewards (opt-in)
+    if let (Some(rewards_pool), Some(viewer_rewards)) = (
+        ctx.accounts.rewards_pool.as_mut(),
+        ctx.accounts.viewer_rewards.as_mut(),
+    ) {
+        let reward = rewards_pool.accrue(viewer_rewards, chunk_count)?;
+        if reward > 0 {
+            emit!(RewardsAccrued {
+                viewer: viewer_session.viewer,
+                viewer_session: viewer_session.key(),
+                chunk_count,
+                reward,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    // Accrue platform-token emissions (opt-in on both sides)
+    if let (Some(emission_schedule), Some(creator_emissions), Some(viewer_emissions)) = (
+        ctx.accounts.emission_schedule.as_mut(),
+        ctx.accounts.creator_emissions.as_mut(),
+        ctx.accounts.viewer_emissions.as_mut(),
+    ) {
+        let (creator_amount, viewer_amount) = emission_schedule.accrue(
+            creator_emissions,
+            viewer_emissions,
+            chunk_count,
+            clock.unix_timestamp,
+        )?;
+        if creator_amount + viewer_amount > 0 {
+            emit!(EmissionsAccrued {
+                viewer_session: viewer_session.key(),
+                creator: video.creator,
+                viewer: viewer_session.viewer,
+                chunk_count,
+                creator_amount,
+                viewer_amount,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
 
     // ═══════════════════════════════════════════════════════════
     // EMIT EVENT (Critical for Backend Sync!)
@@ -238,21 +632,29 @@ pub fn settle_session(
         video: video.key(),
         viewer_session: viewer_session.key(),
         chunk_count,
-        total_payment: total_payment_u64,
-        platform_fee,
-        creator_amount,
+        total_payment: amounts.total_payment,
+        platform_fee: amounts.platform_fee,
+        creator_amount: amounts.creator_amount,
+        penalty_withheld: amounts.penalty_withheld,
+        fee_burned: amounts.fee_burned,
         chunks_consumed: viewer_session.chunks_consumed,
         chunks_remaining: viewer_session.max_approved_chunks - viewer_session.chunks_consumed,
         settlement_timestamp,
+        x402_proof,
+        watermark_id: viewer_session.watermark_id,
+        client_tag: viewer_session.client_tag,
+        fee_basis_points,
+        batch_discount_bps,
+        completion_rebate: amounts.completion_rebate,
         timestamp: clock.unix_timestamp,
     });
 
     msg!(
         "Session settled: {} chunks, {} tokens (creator: {}, fee: {})",
         chunk_count,
-        total_payment_u64,
-        creator_amount,
-        platform_fee
+        amounts.total_payment,
+        amounts.creator_amount,
+        amounts.platform_fee
     );
 
     Ok(())
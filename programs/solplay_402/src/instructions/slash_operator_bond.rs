@@ -0,0 +1,128 @@
+// =============================================================================
+// Slash Operator Bond Instruction
+// =============================================================================
+// The arbiter resolves an open dispute as a fraudulent settlement: the viewer
+// is refunded out of the settling operator's bond instead of the dispute
+// reserve, and the creator's held earnings are released since the operator,
+// not the creator, misreported the chunk count. The refund is capped at the
+// remaining bond.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct SlashOperatorBond<'info> {
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, dispute.viewer_session.as_ref(), &dispute.nonce.to_le_bytes()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ StreamingError::DisputeAlreadyResolved
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: Disputed session; its dispute count is released (unless it has
+    /// since been closed)
+    #[account(mut, address = dispute.viewer_session)]
+    pub viewer_session: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, dispute.video.as_ref()],
+        bump = creator_earnings.bump
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        mut,
+        seeds = [OPERATOR_BOND_SEED, operator_bond.operator.as_ref()],
+        bump = operator_bond.bump,
+        constraint = operator_bond.operator == dispute.operator @ StreamingError::OperatorBondMismatch
+    )]
+    pub operator_bond: Account<'info, OperatorBond>,
+
+    #[account(
+        mut,
+        address = operator_bond.vault @ StreamingError::OperatorBondMismatch
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = arbiter @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (receives the refund)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == dispute.viewer @ StreamingError::Unauthorized,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    pub arbiter: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn slash_operator_bond(ctx: Context<SlashOperatorBond>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let operator_bond = &mut ctx.accounts.operator_bond;
+    let platform = &ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    let slash_amount = dispute.amount.min(operator_bond.amount);
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.bond_vault.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.viewer_token_account.to_account_info(),
+        slash_amount,
+    )?;
+
+    operator_bond.amount -= slash_amount;
+    operator_bond.total_slashed = operator_bond
+        .total_slashed
+        .checked_add(slash_amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    // The creator isn't at fault - release the hold
+    creator_earnings.disputed_amount = creator_earnings
+        .disputed_amount
+        .checked_sub(dispute.amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    dispute.status = DisputeStatus::Slashed;
+    dispute.resolved_at = clock.unix_timestamp;
+    dispute.release_session(&ctx.accounts.viewer_session.to_account_info(), true)?;
+
+    emit!(OperatorBondSlashed {
+        operator: operator_bond.operator,
+        operator_bond: operator_bond.key(),
+        dispute: dispute.key(),
+        viewer: dispute.viewer,
+        amount: slash_amount,
+        remaining_bond: operator_bond.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Operator bond slashed: {} tokens (remaining: {})",
+        slash_amount,
+        operator_bond.amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// =============================================================================
+// Snapshot Platform Stats Instruction
+// =============================================================================
+// Copies the platform's running totals into a PDA keyed by the current
+// Solana epoch, giving growth charts a history without an indexer. Anyone
+// may call it; the epoch-keyed `init` allows one snapshot per epoch.
+// =============================================================================
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SnapshotPlatformStats<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PlatformStatsSnapshot::LEN,
+        seeds = [PLATFORM_STATS_SEED, &Clock::get()?.epoch.to_le_bytes()],
+        bump
+    )]
+    pub platform_stats: Account<'info, PlatformStatsSnapshot>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn snapshot_platform_stats(ctx: Context<SnapshotPlatformStats>) -> Result<()> {
+    let platform = &ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.epoch = clock.epoch;
+    platform_stats.total_videos = platform.total_videos;
+    platform_stats.total_sessions = platform.total_sessions;
+    platform_stats.total_revenue = platform.total_revenue;
+    platform_stats.total_burned = platform.total_burned;
+    platform_stats.taken_at = clock.unix_timestamp;
+    platform_stats.bump = ctx.bumps.platform_stats;
+
+    emit!(PlatformStatsSnapshotted {
+        epoch: clock.epoch,
+        total_videos: platform.total_videos,
+        total_sessions: platform.total_sessions,
+        total_revenue: platform.total_revenue,
+        total_burned: platform.total_burned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Platform stats snapshotted for epoch {}", clock.epoch);
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// =============================================================================
+// Stake For Boost Instruction
+// =============================================================================
+// Creators lock platform tokens into a platform-owned vault for `duration`
+// seconds. The stake's weight (amount scaled by lock length, full weight at a
+// one-year lock) is public for ranking frontends and, while the lock runs,
+// lowers the creator's platform fee by one basis point per
+// `platform.stake_weight_per_discount_bps` of weight, up to
+// MAX_BOOST_FEE_DISCOUNT_BPS. Staking again adds weight and keeps the later
+// of the two unlock times. Withdrawn with unstake_boost after the lock.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct StakeForBoost<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorStake::LEN,
+        seeds = [CREATOR_STAKE_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stake: Account<'info, CreatorStake>,
+
+    /// Stake vault, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [CREATOR_STAKE_VAULT_SEED, creator.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Creator's token account (funds the stake)
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key()
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn stake_for_boost(ctx: Context<StakeForBoost>, amount: u64, duration: i64) -> Result<()> {
+    require!(
+        (MIN_BOOST_LOCK_DURATION..=MAX_BOOST_LOCK_DURATION).contains(&duration),
+        StreamingError::InvalidLockDuration
+    );
+    require!(
+        amount > 0 && ctx.accounts.creator_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.creator_token_account.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.creator.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let creator_stake = &mut ctx.accounts.creator_stake;
+    creator_stake.creator = ctx.accounts.creator.key();
+    creator_stake.vault = ctx.accounts.stake_vault.key();
+    creator_stake.amount = creator_stake
+        .amount
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    creator_stake.weight = creator_stake
+        .weight
+        .checked_add(CreatorStake::weight_for(amount, duration)?)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    creator_stake.lock_until = creator_stake
+        .lock_until
+        .max(clock.unix_timestamp + duration);
+    creator_stake.bump = ctx.bumps.creator_stake;
+
+    emit!(BoostStaked {
+        creator: creator_stake.creator,
+        creator_stake: creator_stake.key(),
+        amount,
+        total_staked: creator_stake.amount,
+        weight: creator_stake.weight,
+        lock_until: creator_stake.lock_until,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Boost staked: {} tokens (weight: {}, locked until {})",
+        amount,
+        creator_stake.weight,
+        creator_stake.lock_until
+    );
+
+    Ok(())
+}
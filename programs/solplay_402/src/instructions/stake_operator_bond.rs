@@ -0,0 +1,105 @@
+// =============================================================================
+// Stake Operator Bond Instruction
+// =============================================================================
+// Settlement operators (the backends submitting x402 chunk counts) stake a
+// bond into a platform-owned vault. When the platform sets a minimum bond,
+// settle_session requires a bonded operator co-signer, and the arbiter can
+// slash the bond to refund viewers for fraudulent settlements.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct StakeOperatorBond<'info> {
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = OperatorBond::LEN,
+        seeds = [OPERATOR_BOND_SEED, operator.key().as_ref()],
+        bump
+    )]
+    pub operator_bond: Account<'info, OperatorBond>,
+
+    /// Bond vault, owned by the platform PDA
+    #[account(
+        init_if_needed,
+        payer = operator,
+        seeds = [OPERATOR_BOND_VAULT_SEED, operator.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Operator's token account (funds the bond)
+    #[account(
+        mut,
+        constraint = operator_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = operator_token_account.owner == operator.key()
+    )]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn stake_operator_bond(ctx: Context<StakeOperatorBond>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.operator_token_account.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.operator_token_account.to_account_info(),
+        to: ctx.accounts.bond_vault.to_account_info(),
+        authority: ctx.accounts.operator.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let operator_bond = &mut ctx.accounts.operator_bond;
+    operator_bond.operator = ctx.accounts.operator.key();
+    operator_bond.vault = ctx.accounts.bond_vault.key();
+    operator_bond.amount = operator_bond
+        .amount
+        .checked_add(amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    operator_bond.bump = ctx.bumps.operator_bond;
+
+    emit!(OperatorBondStaked {
+        operator: operator_bond.operator,
+        operator_bond: operator_bond.key(),
+        amount,
+        total_bonded: operator_bond.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Operator bond staked: {} tokens (total: {})",
+        amount,
+        operator_bond.amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// =============================================================================
+// Start Live Stream Instruction
+// =============================================================================
+// Marks a video as a live stream. While live, the creator grows the chunk
+// count with append_chunks as segments are produced, and viewers may pay for
+// chunks slightly ahead of the recorded count. A finalized stream cannot be
+// restarted.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct StartLiveStream<'info> {
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        has_one = creator @ StreamingError::Unauthorized,
+        constraint = !video.is_immutable @ StreamingError::VideoImmutable
+    )]
+    pub video: Account<'info, Video>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn start_live_stream(ctx: Context<StartLiveStream>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    let clock = Clock::get()?;
+
+    require!(!video.live_finalized, StreamingError::LiveStreamFinalized);
+
+    video.is_live = true;
+
+    emit!(LiveStreamStarted {
+        video: video.key(),
+        creator: ctx.accounts.creator.key(),
+        total_chunks: video.total_chunks,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Live stream started at {} chunks", video.total_chunks);
+
+    Ok(())
+}
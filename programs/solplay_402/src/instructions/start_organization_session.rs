@@ -0,0 +1,298 @@
+// =============================================================================
+// Start Organization Session Instruction
+// =============================================================================
+// A member opens a viewing session paid from their organization's shared
+// budget. Works like a gifted session funded by the organization: the cost of
+// `max_chunks` at the current price moves from the treasury into the session
+// escrow, settlements are paid from the escrow, and closing the session
+// returns the unspent balance to the treasury (the organization is the
+// session's funder, and the treasury its refund pool). Sessions count towards
+// the member's per-viewer approval rate limits.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(max_chunks: u32, session_nonce: u8)]
+pub struct StartOrganizationSession<'info> {
+    #[account(
+        mut,
+        seeds = [ORGANIZATION_SEED, organization.admin.as_ref()],
+        bump = organization.bump
+    )]
+    pub organization: Box<Account<'info, Organization>>,
+
+    /// Shared budget paying for the session
+    #[account(
+        mut,
+        seeds = [ORGANIZATION_TREASURY_SEED, organization.key().as_ref()],
+        bump
+    )]
+    pub organization_treasury: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = member,
+        space = ViewerSession::LEN,
+        seeds = [VIEWER_SESSION_SEED, member.key().as_ref(), video.key().as_ref(), &[session_nonce]],
+        bump
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    /// Escrow holding the session's budget, owned by the platform PDA
+    #[account(
+        init,
+        payer = member,
+        seeds = [SESSION_ESCROW_SEED, viewer_session.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = platform
+    )]
+    pub session_escrow: Account<'info, TokenAccount>,
+
+    /// First-view marker; counts the member towards the video's unique viewers
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = ViewedMarker::LEN,
+        seeds = [VIEWED_MARKER_SEED, video.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub viewed_marker: Box<Account<'info, ViewedMarker>>,
+
+    #[account(
+        mut,
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump,
+        constraint = video.is_active @ StreamingError::VideoNotActive
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Allowlist entry for the member (required when the video is private)
+    #[account(
+        seeds = [ALLOWED_VIEWER_SEED, video.key().as_ref(), member.key().as_ref()],
+        bump = allowed_viewer.bump
+    )]
+    pub allowed_viewer: Option<Account<'info, AllowedViewer>>,
+
+    /// Age attestation for the member (required when the video is rated mature)
+    #[account(
+        seeds = [AGE_ATTESTATION_SEED, member.key().as_ref()],
+        bump = age_attestation.bump
+    )]
+    pub age_attestation: Option<Box<Account<'info, AgeAttestation>>>,
+
+    /// CHECK: Creator suspension marker; must not exist for new sessions
+    #[account(
+        seeds = [SUSPENDED_CREATOR_SEED, video.creator.as_ref()],
+        bump,
+        constraint = suspended_creator.data_is_empty() @ StreamingError::CreatorSuspended
+    )]
+    pub suspended_creator: UncheckedAccount<'info>,
+
+    /// CHECK: Ban marker; must not exist for the member to open sessions
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, member.key().as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// Member's viewer profile (required while the platform rate limits approvals)
+    #[account(
+        mut,
+        seeds = [VIEWER_PROFILE_SEED, member.key().as_ref()],
+        bump = viewer_profile.bump
+    )]
+    pub viewer_profile: Option<Box<Account<'info, ViewerProfile>>>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn start_organization_session(
+    ctx: Context<StartOrganizationSession>,
+    max_chunks: u32,
+    session_nonce: u8,
+) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    require!(
+        ctx.accounts.organization.is_member(&member),
+        StreamingError::NotOrganizationMember
+    );
+    require!(
+        max_chunks > 0 && max_chunks <= ctx.accounts.video.max_units_per_approval(),
+        StreamingError::MaxChunksPerApprovalExceeded
+    );
+    require!(
+        !ctx.accounts.video.is_private || ctx.accounts.allowed_viewer.is_some(),
+        StreamingError::ViewerNotAllowed
+    );
+    require!(
+        !ctx.accounts.video.requires_age_attestation() || ctx.accounts.age_attestation.is_some(),
+        StreamingError::AgeAttestationRequired
+    );
+    // Region-restricted videos need an attested approval (approve_streaming_delegate)
+    require!(
+        ctx.accounts.video.region_policy.is_none(),
+        StreamingError::RegionAttestationRequired
+    );
+
+    let clock = Clock::get()?;
+
+    // Count first-time viewers of the video
+    let video_key = ctx.accounts.video.key();
+    ctx.accounts.viewed_marker.record_view(
+        &mut ctx.accounts.video,
+        video_key,
+        member,
+        clock.unix_timestamp,
+        ctx.bumps.viewed_marker,
+    )?;
+
+    // Per-viewer hourly limits on new sessions and approved chunks
+    if ctx.accounts.platform.has_approval_rate_limits() {
+        let viewer_profile = ctx
+            .accounts
+            .viewer_profile
+            .as_mut()
+            .ok_or(StreamingError::ViewerProfileRequired)?;
+        viewer_profile.record_approval(
+            &ctx.accounts.platform,
+            true,
+            max_chunks,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let video = &ctx.accounts.video;
+    let price_per_chunk = video.unit_price();
+
+    video.check_publish_window(clock.unix_timestamp)?;
+
+    let escrow_amount = u64::try_from(
+        (price_per_chunk as u128)
+            .checked_mul(max_chunks as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?,
+    )
+    .map_err(|_| StreamingError::ArithmeticOverflow)?;
+
+    require!(
+        ctx.accounts.organization_treasury.amount >= escrow_amount,
+        StreamingError::InsufficientBalance
+    );
+
+    // Fund the escrow from the shared budget
+    let organization = &mut ctx.accounts.organization;
+    let organization_seeds = &[
+        ORGANIZATION_SEED,
+        organization.admin.as_ref(),
+        &[organization.bump],
+    ];
+    let signer = &[&organization_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.organization_treasury.to_account_info(),
+        to: ctx.accounts.session_escrow.to_account_info(),
+        authority: organization.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::transfer(cpi_ctx, escrow_amount)?;
+
+    organization.total_spent = organization
+        .total_spent
+        .checked_add(escrow_amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    // Create the member's session at the current price
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    viewer_session.viewer = member;
+    viewer_session.video = video.key();
+    viewer_session.max_approved_chunks = max_chunks;
+    viewer_session.chunks_consumed = 0;
+    viewer_session.total_spent = 0;
+    viewer_session.approved_price_per_chunk = price_per_chunk;
+    viewer_session.list_price_per_chunk = price_per_chunk;
+    viewer_session.session_start = clock.unix_timestamp;
+    viewer_session.last_activity = clock.unix_timestamp;
+    viewer_session.bump = ctx.bumps.viewer_session;
+    viewer_session.quality_reports = 0;
+    viewer_session.verified_quality_reports = 0;
+    viewer_session.funded_by = organization.key();
+    viewer_session.billing_mode = video.billing_mode;
+    viewer_session.quality_tier = None;
+    viewer_session.settlement_count = 0;
+    viewer_session.last_settled_at = 0;
+    viewer_session.disputed_chunks = 0;
+    viewer_session.last_operator = Pubkey::default();
+    viewer_session.recent_settlement_ids = [[0u8; 16]; SETTLEMENT_ID_HISTORY];
+    viewer_session.last_watched_chunk = 0;
+    viewer_session.session_nonce = session_nonce;
+    viewer_session.auto_renew = false;
+    viewer_session.renewal_chunks = 0;
+    viewer_session.renewal_budget_chunks = 0;
+    viewer_session.rent_sponsored = false;
+    viewer_session.session_key = Pubkey::default();
+    viewer_session.evm_address = [0u8; 20];
+    viewer_session.passkey = [0u8; 33];
+    viewer_session.settlement_consent = [0u8; 32];
+    viewer_session.watermark_id = viewer_session.derive_watermark_id();
+    viewer_session.attested_region = [0u8; 2];
+    viewer_session.price_breaks = video.session_price_breaks(true);
+    viewer_session.refund_policy = video.refund_policy;
+    viewer_session.value_window_start = 0;
+    viewer_session.value_window_settled = 0;
+    viewer_session.client_tag = [0u8; 32];
+    viewer_session.refund_pool = ctx.accounts.organization_treasury.key();
+    viewer_session.refunded_chunks = 0;
+
+    let platform = &mut ctx.accounts.platform;
+    platform.total_sessions = platform
+        .total_sessions
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    emit!(OrganizationSessionStarted {
+        organization: organization.key(),
+        member,
+        video: video.key(),
+        viewer_session: viewer_session.key(),
+        max_chunks,
+        price_per_chunk,
+        escrow_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Organization session for {}: {} chunks, {} tokens in escrow",
+        member,
+        max_chunks,
+        escrow_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// =============================================================================
+// Suspend Creator Instruction
+// =============================================================================
+// Moderation hold on a creator pending review. While the suspension marker
+// exists the creator cannot create videos, no new sessions can be opened on
+// their videos, and their netted earnings stay frozen in the payout vault
+// (claim_creator_payout fails and flush_payouts skips them). Lifted with
+// lift_creator_suspension.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct SuspendCreator<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SuspendedCreator::LEN,
+        seeds = [SUSPENDED_CREATOR_SEED, creator.as_ref()],
+        bump
+    )]
+    pub suspended_creator: Account<'info, SuspendedCreator>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn suspend_creator(ctx: Context<SuspendCreator>, creator: Pubkey) -> Result<()> {
+    let suspended_creator = &mut ctx.accounts.suspended_creator;
+    let clock = Clock::get()?;
+
+    suspended_creator.creator = creator;
+    suspended_creator.suspended_at = clock.unix_timestamp;
+    suspended_creator.bump = ctx.bumps.suspended_creator;
+
+    emit!(CreatorSuspended {
+        authority: ctx.accounts.authority.key(),
+        creator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator {} suspended", creator);
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+// =============================================================================
+// Transfer Session Instruction
+// =============================================================================
+// Moves a viewer session to a new wallet (e.g. after key rotation). The session
+// is re-created under the new viewer's PDA with its counters and locked price
+// carried over, and the old session is closed. The new wallet co-signs and
+// delegates the session's remaining allowance to the platform. The old
+// wallet's delegation is shared with its other sessions and is left as is.
+// Signers registered by the old wallet (session key, EVM address, passkey and
+// any passkey consent) are dropped; the new wallet registers its own. The
+// watermark ID is re-derived so leaked content traces to the new wallet.
+// Sessions whose rent was sponsored must pass the sponsorship vault, which
+// receives the old session's rent; the new wallet pays for its own session.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, ApproveChecked, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(new_viewer: Pubkey)]
+pub struct TransferSession<'info> {
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer.key().as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.viewer == viewer.key(),
+        constraint = viewer_session.video == video.key(),
+        close = viewer
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        init,
+        payer = viewer,
+        space = ViewerSession::LEN,
+        seeds = [VIEWER_SESSION_SEED, new_viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump
+    )]
+    pub new_viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Token mint account - must match platform's configured mint
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// New wallet's token account (delegated to the platform)
+    #[account(
+        mut,
+        constraint = new_viewer_token_account.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = new_viewer_token_account.owner == new_viewer
+    )]
+    pub new_viewer_token_account: Account<'info, TokenAccount>,
+
+    /// Rent sponsorship vault (required for sponsored sessions)
+    #[account(
+        mut,
+        seeds = [SPONSORSHIP_VAULT_SEED],
+        bump = sponsorship_vault.bump
+    )]
+    pub sponsorship_vault: Option<Account<'info, SponsorshipVault>>,
+
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+
+    /// CHECK: Ban marker; must not exist for the new wallet
+    #[account(
+        seeds = [BANNED_VIEWER_SEED, new_viewer.as_ref()],
+        bump,
+        constraint = banned_viewer.data_is_empty() @ StreamingError::ViewerBanned
+    )]
+    pub banned_viewer: UncheckedAccount<'info>,
+
+    /// New wallet (must match `new_viewer`)
+    #[account(
+        constraint = new_viewer_authority.key() == new_viewer @ StreamingError::Unauthorized
+    )]
+    pub new_viewer_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_session(ctx: Context<TransferSession>, new_viewer: Pubkey) -> Result<()> {
+    let viewer_session = &ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    require!(
+        new_viewer != ctx.accounts.viewer.key(),
+        StreamingError::InvalidSessionTransfer
+    );
+    // Gift escrows and disputes are keyed by the session address
+    require!(
+        !viewer_session.is_gifted() && viewer_session.disputed_chunks == 0,
+        StreamingError::SessionNotTransferable
+    );
+
+    // Carry the session over to the new wallet
+    let mut transferred = (**viewer_session).clone();
+    transferred.viewer = new_viewer;
+    transferred.bump = ctx.bumps.new_viewer_session;
+    transferred.last_activity = clock.unix_timestamp;
+    transferred.rent_sponsored = false;
+    transferred.session_key = Pubkey::default();
+    transferred.evm_address = [0u8; 20];
+    transferred.passkey = [0u8; 33];
+    transferred.settlement_consent = [0u8; 32];
+    transferred.watermark_id = transferred.derive_watermark_id();
+    let allowance = transferred.remaining_allowance()?;
+    ctx.accounts.new_viewer_session.set_inner(transferred);
+
+    // Sponsored rent goes back to the vault (leaving nothing for the viewer)
+    if viewer_session.rent_sponsored {
+        let Some(sponsorship_vault) = ctx.accounts.sponsorship_vault.as_mut() else {
+            return err!(StreamingError::SponsorshipVaultRequired);
+        };
+        return_session_rent(sponsorship_vault, &viewer_session.to_account_info())?;
+    }
+
+    require!(
+        ctx.accounts.new_viewer_token_account.amount >= allowance,
+        StreamingError::InsufficientBalanceForApproval
+    );
+
+    // Fresh delegation from the new wallet, keeping its other sessions' share
+    let new_viewer_token_account = &ctx.accounts.new_viewer_token_account;
+    let other_sessions_allowance =
+        if new_viewer_token_account.delegate == COption::Some(ctx.accounts.platform.key()) {
+            new_viewer_token_account.delegated_amount
+        } else {
+            0
+        };
+    let delegated_amount = allowance
+        .checked_add(other_sessions_allowance)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    let cpi_accounts = ApproveChecked {
+        to: new_viewer_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        delegate: ctx.accounts.platform.to_account_info(),
+        authority: ctx.accounts.new_viewer_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::approve_checked(cpi_ctx, delegated_amount, ctx.accounts.token_mint.decimals)?;
+
+    let new_viewer_session = &ctx.accounts.new_viewer_session;
+
+    emit!(SessionTransferred {
+        old_viewer: ctx.accounts.viewer.key(),
+        new_viewer,
+        video: ctx.accounts.video.key(),
+        old_session: viewer_session.key(),
+        new_session: new_viewer_session.key(),
+        max_approved_chunks: new_viewer_session.max_approved_chunks,
+        chunks_consumed: new_viewer_session.chunks_consumed,
+        delegated_amount: allowance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Session transferred to {}: {}/{} chunks consumed, {} tokens delegated",
+        new_viewer,
+        new_viewer_session.chunks_consumed,
+        new_viewer_session.max_approved_chunks,
+        allowance
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// =============================================================================
+// Unban Viewer Instruction
+// =============================================================================
+// Lifts a ban_viewer ban by closing the viewer's ban marker.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnbanViewer<'info> {
+    #[account(
+        mut,
+        seeds = [BANNED_VIEWER_SEED, banned_viewer.viewer.as_ref()],
+        bump = banned_viewer.bump,
+        close = authority
+    )]
+    pub banned_viewer: Account<'info, BannedViewer>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn unban_viewer(ctx: Context<UnbanViewer>) -> Result<()> {
+    let viewer = ctx.accounts.banned_viewer.viewer;
+    let clock = Clock::get()?;
+
+    emit!(ViewerUnbanned {
+        authority: ctx.accounts.authority.key(),
+        viewer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Viewer {} unbanned", viewer);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+// =============================================================================
+// Unstake Boost Instruction
+// =============================================================================
+// Withdraws a creator's whole boost stake once its lock has ended, resetting
+// its weight.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct UnstakeBoost<'info> {
+    #[account(
+        mut,
+        seeds = [CREATOR_STAKE_SEED, creator.key().as_ref()],
+        bump = creator_stake.bump,
+        has_one = creator @ StreamingError::Unauthorized
+    )]
+    pub creator_stake: Account<'info, CreatorStake>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_STAKE_VAULT_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Creator's token account (receives the stake)
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key()
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn unstake_boost(ctx: Context<UnstakeBoost>) -> Result<()> {
+    let platform = &ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= ctx.accounts.creator_stake.lock_until,
+        StreamingError::StakeLocked
+    );
+
+    let amount = ctx.accounts.creator_stake.amount;
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.stake_vault.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
+    source.transfer(ctx.accounts.creator_token_account.to_account_info(), amount)?;
+
+    let creator_stake = &mut ctx.accounts.creator_stake;
+    creator_stake.amount = 0;
+    creator_stake.weight = 0;
+
+    emit!(BoostUnstaked {
+        creator: creator_stake.creator,
+        creator_stake: creator_stake.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Boost unstaked: {} tokens", amount);
+
+    Ok(())
+}
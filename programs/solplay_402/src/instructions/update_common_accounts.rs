@@ -0,0 +1,87 @@
+// =============================================================================
+// Update Common Accounts Instruction
+// =============================================================================
+// Maintains the CommonAccounts registry PDA: the static accounts that appear
+// in almost every streaming transaction (platform, treasury, mint, programs).
+// Clients read it to build address lookup tables deterministically, so
+// settlement transactions only carry the per-session accounts inline
+// (viewer session, video, creator earnings, and the two token accounts).
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct UpdateCommonAccounts<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CommonAccounts::LEN,
+        seeds = [COMMON_ACCOUNTS_SEED],
+        bump
+    )]
+    pub common_accounts: Account<'info, CommonAccounts>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        constraint = token_mint.key() == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Platform's fee token account registered as the treasury
+    #[account(
+        constraint = treasury.mint == token_mint.key() @ StreamingError::InvalidTokenMint,
+        constraint = treasury.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_common_accounts(
+    ctx: Context<UpdateCommonAccounts>,
+    lookup_table: Pubkey,
+) -> Result<()> {
+    let common_accounts = &mut ctx.accounts.common_accounts;
+    let clock = Clock::get()?;
+
+    let (creator_tiers, _) = Pubkey::find_program_address(&[CREATOR_TIER_SEED], ctx.program_id);
+
+    common_accounts.platform = ctx.accounts.platform.key();
+    common_accounts.token_mint = ctx.accounts.token_mint.key();
+    common_accounts.treasury = ctx.accounts.treasury.key();
+    common_accounts.token_program = ctx.accounts.token_program.key();
+    common_accounts.system_program = ctx.accounts.system_program.key();
+    common_accounts.creator_tiers = creator_tiers;
+    common_accounts.lookup_table = lookup_table;
+    common_accounts.updated_at = clock.unix_timestamp;
+    common_accounts.bump = ctx.bumps.common_accounts;
+
+    emit!(CommonAccountsUpdated {
+        common_accounts: common_accounts.key(),
+        treasury: common_accounts.treasury,
+        lookup_table,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Common accounts registry updated (lookup table: {})",
+        lookup_table
+    );
+
+    Ok(())
+}
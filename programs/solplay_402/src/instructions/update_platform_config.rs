@@ -0,0 +1,180 @@
+// =============================================================================
+// Update Platform Config Instruction
+// =============================================================================
+// Admin-only tuning of platform policies. Fields left as `None` are unchanged.
+// NOTE: The platform fee itself is NOT updated here - it goes through the
+// propose_fee_change / execute_fee_change timelock.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PlatformConfigUpdate {
+    pub quality_report_threshold: Option<u8>,
+    pub quality_refund_bps: Option<u16>,
+    pub burn_bps: Option<u16>,
+    pub dispute_window: Option<i64>,
+    pub arbiter: Option<Pubkey>,
+    pub min_operator_bond: Option<u64>,
+    pub max_sessions_per_hour: Option<u16>,
+    pub max_chunks_per_hour: Option<u32>,
+    pub trial_spend_cap: Option<u64>,
+    pub free_chunks_per_day: Option<u32>,
+    pub stake_weight_per_discount_bps: Option<u64>,
+    pub age_attestor: Option<Pubkey>,
+    pub region_attestor: Option<Pubkey>,
+    pub max_price_per_chunk: Option<u64>,
+    pub min_settlement_interval: Option<i64>,
+    pub batch_discount_threshold: Option<u32>,
+    pub batch_discount_bps: Option<u16>,
+    pub payout_maturation_period: Option<i64>,
+    pub max_chunks_per_settlement: Option<u32>,
+    pub max_settlement_value_per_hour_per_session: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn update_platform_config(
+    ctx: Context<UpdatePlatformConfig>,
+    update: PlatformConfigUpdate,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    if let Some(threshold) = update.quality_report_threshold {
+        require!(
+            threshold <= MAX_QUALITY_REPORTS_PER_SESSION,
+            StreamingError::TooManyQualityReports
+        );
+        platform.quality_report_threshold = threshold;
+    }
+
+    if let Some(refund_bps) = update.quality_refund_bps {
+        require!(
+            refund_bps as u64 <= BASIS_POINTS,
+            StreamingError::InvalidBasisPoints
+        );
+        platform.quality_refund_bps = refund_bps;
+    }
+
+    if let Some(burn_bps) = update.burn_bps {
+        require!(
+            burn_bps as u64 <= BASIS_POINTS,
+            StreamingError::InvalidBasisPoints
+        );
+        platform.burn_bps = burn_bps;
+    }
+
+    if let Some(dispute_window) = update.dispute_window {
+        require!(
+            (0..=MAX_DISPUTE_WINDOW).contains(&dispute_window),
+            StreamingError::InvalidDisputeWindow
+        );
+        platform.dispute_window = dispute_window;
+    }
+
+    if let Some(arbiter) = update.arbiter {
+        platform.arbiter = arbiter;
+    }
+
+    if let Some(min_operator_bond) = update.min_operator_bond {
+        platform.min_operator_bond = min_operator_bond;
+    }
+
+    if let Some(max_sessions_per_hour) = update.max_sessions_per_hour {
+        platform.max_sessions_per_hour = max_sessions_per_hour;
+    }
+
+    if let Some(max_chunks_per_hour) = update.max_chunks_per_hour {
+        platform.max_chunks_per_hour = max_chunks_per_hour;
+    }
+
+    if let Some(trial_spend_cap) = update.trial_spend_cap {
+        platform.trial_spend_cap = trial_spend_cap;
+    }
+
+    if let Some(free_chunks_per_day) = update.free_chunks_per_day {
+        platform.free_chunks_per_day = free_chunks_per_day;
+    }
+
+    if let Some(stake_weight_per_discount_bps) = update.stake_weight_per_discount_bps {
+        platform.stake_weight_per_discount_bps = stake_weight_per_discount_bps;
+    }
+
+    if let Some(age_attestor) = update.age_attestor {
+        platform.age_attestor = age_attestor;
+    }
+
+    if let Some(region_attestor) = update.region_attestor {
+        platform.region_attestor = region_attestor;
+    }
+
+    if let Some(max_price_per_chunk) = update.max_price_per_chunk {
+        require!(
+            max_price_per_chunk == 0 || max_price_per_chunk >= platform.min_price_per_chunk,
+            StreamingError::PriceTooLow
+        );
+        platform.max_price_per_chunk = max_price_per_chunk;
+    }
+
+    if let Some(min_settlement_interval) = update.min_settlement_interval {
+        require!(
+            (0..=SESSION_INACTIVITY_DURATION).contains(&min_settlement_interval),
+            StreamingError::InvalidSettlementInterval
+        );
+        platform.min_settlement_interval = min_settlement_interval;
+    }
+
+    if let Some(batch_discount_threshold) = update.batch_discount_threshold {
+        platform.batch_discount_threshold = batch_discount_threshold;
+    }
+
+    if let Some(batch_discount_bps) = update.batch_discount_bps {
+        require!(
+            batch_discount_bps as u64 <= BASIS_POINTS,
+            StreamingError::InvalidBasisPoints
+        );
+        platform.batch_discount_bps = batch_discount_bps;
+    }
+
+    if let Some(payout_maturation_period) = update.payout_maturation_period {
+        require!(
+            (0..=MAX_PAYOUT_MATURATION_PERIOD).contains(&payout_maturation_period),
+            StreamingError::InvalidMaturationPeriod
+        );
+        platform.payout_maturation_period = payout_maturation_period;
+    }
+
+    if let Some(max_chunks_per_settlement) = update.max_chunks_per_settlement {
+        platform.max_chunks_per_settlement = max_chunks_per_settlement;
+    }
+
+    if let Some(max_value) = update.max_settlement_value_per_hour_per_session {
+        platform.max_settlement_value_per_hour_per_session = max_value;
+    }
+
+    emit!(PlatformConfigUpdated {
+        platform: platform.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Platform config updated");
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// =============================================================================
+// Update Prices Batch Instruction
+// =============================================================================
+// Re-prices a creator's catalog in one transaction: every Video passed as a
+// remaining account (writable) gets `new_price` per chunk. All of them must
+// belong to the signing creator; the platform price bounds are checked once.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdatePricesBatch<'info> {
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn update_prices_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdatePricesBatch<'info>>,
+    new_price: u64,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        StreamingError::InvalidVideoBatch
+    );
+    ctx.accounts.platform.check_chunk_price(new_price)?;
+
+    let creator = ctx.accounts.creator.key();
+    let clock = Clock::get()?;
+
+    for video_info in ctx.remaining_accounts {
+        let mut video = Account::<Video>::try_from(video_info)?;
+        require_keys_eq!(video.creator, creator, StreamingError::Unauthorized);
+
+        video.price_per_chunk = new_price;
+        video.exit(&crate::ID)?;
+
+        emit!(VideoUpdated {
+            video: video_info.key(),
+            creator,
+            price_per_chunk: Some(new_price),
+            is_active: None,
+            thumbnail_cid: None,
+            poster_cid: None,
+            ipfs_hash: None,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!(
+        "Repriced {} videos to {} tokens/chunk",
+        ctx.remaining_accounts.len(),
+        new_price
+    );
+
+    Ok(())
+}
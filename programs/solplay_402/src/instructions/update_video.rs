@@ -1,6 +1,10 @@
 // =============================================================================
 // Update Video Instruction
 // =============================================================================
+// Callable by the creator or by a manager on the creator's team. The content
+// hash can only be replaced before the first chunk is sold (and while the
+// metadata is unlocked), so content can't be swapped after sales begin.
+// =============================================================================
 
 use crate::constants::*;
 use crate::errors::*;
@@ -13,8 +17,7 @@ pub struct UpdateVideo<'info> {
     #[account(
         mut,
         seeds = [VIDEO_SEED, video.video_id.as_bytes()],
-        bump = video.bump,
-        has_one = creator @ StreamingError::Unauthorized
+        bump = video.bump
     )]
     pub video: Account<'info, Video>,
 
@@ -24,6 +27,14 @@ pub struct UpdateVideo<'info> {
     )]
     pub platform: Account<'info, Platform>,
 
+    /// Creator's team (required when a manager signs)
+    #[account(
+        seeds = [CREATOR_TEAM_SEED, video.creator.as_ref()],
+        bump = creator_team.bump
+    )]
+    pub creator_team: Option<Account<'info, CreatorTeam>>,
+
+    /// Video creator, or a manager on the creator's team
     pub creator: Signer<'info>,
 }
 
@@ -31,12 +42,37 @@ pub fn update_video(
     ctx: Context<UpdateVideo>,
     price_per_chunk: Option<u64>,
     is_active: Option<bool>,
+    thumbnail_cid: Option<String>,
+    poster_cid: Option<String>,
+    ipfs_hash: Option<String>,
 ) -> Result<()> {
     // Require at least one update
     require!(
-        price_per_chunk.is_some() || is_active.is_some(),
+        price_per_chunk.is_some()
+            || is_active.is_some()
+            || thumbnail_cid.is_some()
+            || poster_cid.is_some()
+            || ipfs_hash.is_some(),
         StreamingError::NoUpdateProvided
     );
+    require!(
+        thumbnail_cid
+            .iter()
+            .chain(poster_cid.iter())
+            .all(|cid| cid.len() <= MAX_IPFS_HASH_LENGTH),
+        StreamingError::IpfsHashTooLong
+    );
+
+    let signer = ctx.accounts.creator.key();
+    require!(
+        signer == ctx.accounts.video.creator
+            || ctx
+                .accounts
+                .creator_team
+                .as_ref()
+                .is_some_and(|team| team.has_role(&signer, TeamRole::Manager)),
+        StreamingError::Unauthorized
+    );
 
     let video = &mut ctx.accounts.video;
     let platform = &ctx.accounts.platform;
@@ -44,10 +80,7 @@ pub fn update_video(
 
     // Update price if provided
     if let Some(new_price) = price_per_chunk {
-        require!(
-            new_price >= platform.min_price_per_chunk,
-            StreamingError::PriceTooLow
-        );
+        platform.check_chunk_price(new_price)?;
         video.price_per_chunk = new_price;
     }
 
@@ -56,11 +89,36 @@ pub fn update_video(
         video.is_active = active;
     }
 
+    // Replace content only before any sales
+    if let Some(hash) = ipfs_hash.as_ref() {
+        require!(!video.is_immutable, StreamingError::VideoImmutable);
+        require!(
+            video.total_chunks_served == 0,
+            StreamingError::ContentAlreadySold
+        );
+        require!(
+            !hash.is_empty() && hash.len() <= MAX_IPFS_HASH_LENGTH,
+            StreamingError::IpfsHashTooLong
+        );
+        video.ipfs_hash = hash.clone();
+    }
+
+    // Update images if provided (empty string clears)
+    if let Some(cid) = thumbnail_cid.as_ref() {
+        video.thumbnail_cid = cid.clone();
+    }
+    if let Some(cid) = poster_cid.as_ref() {
+        video.poster_cid = cid.clone();
+    }
+
     emit!(VideoUpdated {
         video: video.key(),
-        creator: ctx.accounts.creator.key(),
+        creator: video.creator,
         price_per_chunk,
         is_active,
+        thumbnail_cid,
+        poster_cid,
+        ipfs_hash,
         timestamp: clock.unix_timestamp,
     });
 
@@ -0,0 +1,58 @@
+// =============================================================================
+// Verify Creator Instruction
+// =============================================================================
+// Marks a creator as verified by the platform, so clients can badge their
+// videos and flag look-alike accounts. Creates the creator's profile on first
+// use. Undone with revoke_verification.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct VerifyCreator<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CreatorProfile::LEN,
+        seeds = [CREATOR_PROFILE_SEED, creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn verify_creator(ctx: Context<VerifyCreator>, creator: Pubkey) -> Result<()> {
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    let clock = Clock::get()?;
+
+    creator_profile.creator = creator;
+    creator_profile.verified = true;
+    creator_profile.verified_at = clock.unix_timestamp;
+    creator_profile.bump = ctx.bumps.creator_profile;
+
+    emit!(CreatorVerified {
+        authority: ctx.accounts.authority.key(),
+        creator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator {} verified", creator);
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+// =============================================================================
+// Verify Quality Report Instruction
+// =============================================================================
+// The platform authority confirms a viewer's quality report. Once a session
+// accumulates `quality_report_threshold` verified reports, each further
+// verification refunds `quality_refund_bps` of the reported chunks' value.
+//
+// The refund is fronted from the platform fee account (signed by the authority)
+// and recorded as `penalty_outstanding` on the creator's earnings, which is
+// withheld from the creator's share of subsequent payments.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct VerifyQualityReport<'info> {
+    #[account(
+        mut,
+        constraint = quality_report.viewer_session == viewer_session.key() @ StreamingError::InvalidSession,
+        constraint = !quality_report.is_verified @ StreamingError::QualityReportAlreadyVerified
+    )]
+    pub quality_report: Account<'info, QualityReport>,
+
+    #[account(
+        mut,
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), video.key().as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump,
+        constraint = viewer_session.video == video.key() @ StreamingError::InvalidSession
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    #[account(
+        seeds = [VIDEO_SEED, video.video_id.as_bytes()],
+        bump = video.bump
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_EARNINGS_SEED, video.key().as_ref()],
+        bump = creator_earnings.bump,
+        constraint = creator_earnings.video == video.key() @ StreamingError::InvalidCreatorEarnings
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump,
+        has_one = authority @ StreamingError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Viewer's token account (receives the refund)
+    #[account(
+        mut,
+        constraint = viewer_token_account.owner == viewer_session.viewer @ StreamingError::Unauthorized,
+        constraint = viewer_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint
+    )]
+    pub viewer_token_account: Account<'info, TokenAccount>,
+
+    /// Platform's token account (fronts the refund)
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = platform_token_account.owner == platform.authority @ StreamingError::InvalidPlatformAccount
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn verify_quality_report(ctx: Context<VerifyQualityReport>) -> Result<()> {
+    let quality_report = &mut ctx.accounts.quality_report;
+    let viewer_session = &mut ctx.accounts.viewer_session;
+    let creator_earnings = &mut ctx.accounts.creator_earnings;
+    let platform = &ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    quality_report.is_verified = true;
+    viewer_session.verified_quality_reports = viewer_session
+        .verified_quality_reports
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    // Refund policy applies only once the session crosses the threshold
+    let refund_enabled = platform.quality_report_threshold > 0
+        && viewer_session.verified_quality_reports >= platform.quality_report_threshold;
+
+    let mut refund_amount = 0u64;
+    if refund_enabled {
+        let reported_value = (viewer_session.approved_price_per_chunk as u128)
+            .checked_mul(quality_report.chunk_count() as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        let refund_u128 = reported_value
+            .checked_mul(platform.quality_refund_bps as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        refund_amount =
+            u64::try_from(refund_u128).map_err(|_| StreamingError::ArithmeticOverflow)?;
+    }
+
+    if refund_amount > 0 {
+        let refund = Transfer {
+            from: ctx.accounts.platform_token_account.to_account_info(),
+            to: ctx.accounts.viewer_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), refund);
+        token::transfer(cpi_ctx, refund_amount)?;
+
+        // Creator bears the refund out of future earnings
+        creator_earnings.penalty_outstanding = creator_earnings
+            .penalty_outstanding
+            .checked_add(refund_amount)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+
+    quality_report.refund_amount = refund_amount;
+
+    emit!(QualityReportVerified {
+        viewer: viewer_session.viewer,
+        video: ctx.accounts.video.key(),
+        quality_report: quality_report.key(),
+        verified_reports: viewer_session.verified_quality_reports,
+        refund_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Quality report verified ({} verified in session), refund: {}",
+        viewer_session.verified_quality_reports,
+        refund_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// =============================================================================
+// Verify x402 Proof Instruction
+// =============================================================================
+// Records an x402 payment proof on-chain: the structured payload of an HTTP
+// 402 `X-PAYMENT` header (amount, resource hash, nonce) signed with ed25519 by
+// the viewer or their session key (`x402_payment_message`). The signature is
+// checked by an Ed25519 precompile instruction right before this one. The
+// proof is bound to the session under its nonce, so each payload is recorded
+// once, and a settlement can then reference it (see settle_session) instead of
+// an unverifiable chunk count.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::sig_verify::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+#[derive(Accounts)]
+#[instruction(signer: Pubkey, amount: u64, resource_hash: [u8; 32], nonce: [u8; 16])]
+pub struct VerifyX402Proof<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = X402Proof::LEN,
+        seeds = [X402_PROOF_SEED, viewer_session.key().as_ref(), &nonce],
+        bump
+    )]
+    pub x402_proof: Account<'info, X402Proof>,
+
+    #[account(
+        seeds = [VIEWER_SESSION_SEED, viewer_session.viewer.as_ref(), viewer_session.video.as_ref(), &[viewer_session.session_nonce]],
+        bump = viewer_session.bump
+    )]
+    pub viewer_session: Account<'info, ViewerSession>,
+
+    /// CHECK: Instructions sysvar, read to find the Ed25519 precompile check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn verify_x402_proof(
+    ctx: Context<VerifyX402Proof>,
+    signer: Pubkey,
+    amount: u64,
+    resource_hash: [u8; 32],
+    nonce: [u8; 16],
+) -> Result<()> {
+    let viewer_session = &ctx.accounts.viewer_session;
+    let clock = Clock::get()?;
+
+    require!(
+        amount > 0 && viewer_session.is_payment_signer(&signer),
+        StreamingError::InvalidX402Proof
+    );
+
+    let message = x402_payment_message(&viewer_session.key(), amount, &resource_hash, &nonce);
+    verify_ed25519_signature(&ctx.accounts.instructions, &signer, &message)?;
+
+    let x402_proof = &mut ctx.accounts.x402_proof;
+    x402_proof.viewer_session = viewer_session.key();
+    x402_proof.signer = signer;
+    x402_proof.amount = amount;
+    x402_proof.resource_hash = resource_hash;
+    x402_proof.nonce = nonce;
+    x402_proof.verified_at = clock.unix_timestamp;
+    x402_proof.settled = false;
+    x402_proof.bump = ctx.bumps.x402_proof;
+
+    emit!(X402ProofVerified {
+        viewer_session: viewer_session.key(),
+        x402_proof: x402_proof.key(),
+        signer,
+        amount,
+        resource_hash,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "x402 proof verified for session {}: {} tokens",
+        viewer_session.key(),
+        amount
+    );
+
+    Ok(())
+}
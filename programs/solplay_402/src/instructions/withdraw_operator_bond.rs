@@ -0,0 +1,95 @@
+// =============================================================================
+// Withdraw Operator Bond Instruction
+// =============================================================================
+// Operators can withdraw their bond once the platform dispute window has
+// passed since their latest settlement, so a bond can't be pulled out from
+// under a pending dispute.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::payments::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawOperatorBond<'info> {
+    #[account(
+        mut,
+        seeds = [OPERATOR_BOND_SEED, operator.key().as_ref()],
+        bump = operator_bond.bump,
+        has_one = operator @ StreamingError::Unauthorized
+    )]
+    pub operator_bond: Account<'info, OperatorBond>,
+
+    #[account(
+        mut,
+        seeds = [OPERATOR_BOND_VAULT_SEED, operator.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [PLATFORM_SEED],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    /// Operator's token account (receives the bond)
+    #[account(
+        mut,
+        constraint = operator_token_account.mint == platform.token_mint @ StreamingError::InvalidTokenMint,
+        constraint = operator_token_account.owner == operator.key()
+    )]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_operator_bond(ctx: Context<WithdrawOperatorBond>, amount: u64) -> Result<()> {
+    let platform = &ctx.accounts.platform;
+    let clock = Clock::get()?;
+
+    require!(
+        amount <= ctx.accounts.operator_bond.amount,
+        StreamingError::InsufficientBalance
+    );
+    require!(
+        clock.unix_timestamp > ctx.accounts.operator_bond.last_settled_at + platform.dispute_window,
+        StreamingError::OperatorBondLocked
+    );
+
+    let source = PaymentSource {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        from: ctx.accounts.bond_vault.to_account_info(),
+        platform: platform.to_account_info(),
+        platform_bump: platform.bump,
+    };
+    source.transfer(
+        ctx.accounts.operator_token_account.to_account_info(),
+        amount,
+    )?;
+
+    let operator_bond = &mut ctx.accounts.operator_bond;
+    operator_bond.amount -= amount;
+
+    emit!(OperatorBondWithdrawn {
+        operator: operator_bond.operator,
+        operator_bond: operator_bond.key(),
+        amount,
+        total_bonded: operator_bond.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Operator bond withdrawn: {} tokens (remaining: {})",
+        amount,
+        operator_bond.amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// =============================================================================
+// Withdraw Organization Funds Instruction
+// =============================================================================
+// The admin takes unspent budget back out of the organization treasury.
+// Funds already moved into members' session escrows stay there until those
+// sessions are closed.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct WithdrawOrganizationFunds<'info> {
+    #[account(
+        seeds = [ORGANIZATION_SEED, admin.key().as_ref()],
+        bump = organization.bump,
+        has_one = admin @ StreamingError::Unauthorized
+    )]
+    pub organization: Box<Account<'info, Organization>>,
+
+    #[account(
+        mut,
+        seeds = [ORGANIZATION_TREASURY_SEED, organization.key().as_ref()],
+        bump
+    )]
+    pub organization_treasury: Account<'info, TokenAccount>,
+
+    /// Admin's token account (receives the funds)
+    #[account(
+        mut,
+        constraint = admin_token_account.mint == organization_treasury.mint @ StreamingError::InvalidTokenMint,
+        constraint = admin_token_account.owner == admin.key()
+    )]
+    pub admin_token_account: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_organization_funds(
+    ctx: Context<WithdrawOrganizationFunds>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.organization_treasury.amount >= amount,
+        StreamingError::InsufficientBalance
+    );
+
+    let organization = &ctx.accounts.organization;
+    let clock = Clock::get()?;
+
+    let organization_seeds = &[
+        ORGANIZATION_SEED,
+        organization.admin.as_ref(),
+        &[organization.bump],
+    ];
+    let signer = &[&organization_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.organization_treasury.to_account_info(),
+        to: ctx.accounts.admin_token_account.to_account_info(),
+        authority: organization.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(OrganizationFundsWithdrawn {
+        organization: organization.key(),
+        admin: organization.admin,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Organization funds withdrawn: {} tokens", amount);
+
+    Ok(())
+}
@@ -10,13 +10,20 @@ use anchor_lang::prelude::*;
 declare_id!("CM19aL9CP8dRjVzRUEW6AMxYgftdSvPgQ5Yzniq5sPXV");
 
 // Module imports
+pub mod cctp;
 pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod merkle;
+pub mod nft_gate;
+pub mod payments;
+pub mod sig_verify;
 pub mod state;
+pub mod wormhole;
 
 use instructions::*;
+use state::*;
 
 #[program]
 pub mod solplay_402 {
@@ -32,6 +39,7 @@ pub mod solplay_402 {
     }
 
     /// Register a new video for streaming
+    #[allow(clippy::too_many_arguments)]
     pub fn create_video(
         ctx: Context<CreateVideo>,
         video_id: String,
@@ -39,7 +47,11 @@ pub mod solplay_402 {
         total_chunks: u32,
         price_per_chunk: u64,
         title: String,
-        description: String,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+        chunk_merkle_root: [u8; 32],
+        thumbnail_cid: String,
+        poster_cid: String,
     ) -> Result<()> {
         instructions::create_video(
             ctx,
@@ -48,45 +60,99 @@ pub mod solplay_402 {
             total_chunks,
             price_per_chunk,
             title,
-            description,
+            metadata_uri,
+            metadata_hash,
+            chunk_merkle_root,
+            thumbnail_cid,
+            poster_cid,
         )
     }
 
     /// Update video metadata or pricing
+    /// `ipfs_hash` may only be replaced before the first chunk is sold
     pub fn update_video(
         ctx: Context<UpdateVideo>,
         price_per_chunk: Option<u64>,
         is_active: Option<bool>,
+        thumbnail_cid: Option<String>,
+        poster_cid: Option<String>,
+        ipfs_hash: Option<String>,
     ) -> Result<()> {
-        instructions::update_video(ctx, price_per_chunk, is_active)
+        instructions::update_video(
+            ctx,
+            price_per_chunk,
+            is_active,
+            thumbnail_cid,
+            poster_cid,
+            ipfs_hash,
+        )
     }
 
-    /// Approve platform to spend tokens for streaming
-    pub fn approve_streaming_delegate(
-        ctx: Context<ApproveDelegate>,
+    /// Approve platform to spend tokens for streaming (opening the session on
+    /// first use; pay_for_chunk(0) may follow in the same transaction)
+    /// Holders of a gated video's NFT collection pass their token account and
+    /// metadata account as remaining accounts to lock in the gated price.
+    /// `quality_tier` selects a per-resolution price (None = base price)
+    /// `session_nonce` distinguishes concurrent sessions on the same video
+    /// `region` is the viewer's region, co-signed for region-restricted videos
+    /// `client_tag` is a hashed client app/device identifier, echoed in
+    /// settlement events for per-client analytics (None = leave unchanged)
+    pub fn approve_streaming_delegate<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveDelegate<'info>>,
         max_chunks: u32,
+        quality_tier: Option<u8>,
+        session_nonce: u8,
+        region: Option<[u8; 2]>,
+        client_tag: Option<[u8; 32]>,
     ) -> Result<()> {
-        instructions::approve_streaming_delegate(ctx, max_chunks)
+        instructions::approve_streaming_delegate(
+            ctx,
+            max_chunks,
+            quality_tier,
+            session_nonce,
+            region,
+            client_tag,
+        )
     }
 
     /// Settle a batch of chunks consumed via x402 HTTP streaming
     /// Called by backend after accumulating chunk views off-chain
-    pub fn settle_session(
-        ctx: Context<SettleSession>,
+    /// (per-second videos pass `seconds_watched` and a zero `chunk_count`).
+    /// `settlement_id` is an idempotency key; replays are rejected (zero = none)
+    /// `last_watched_chunk` records the player's resume position (None = unchanged)
+    /// Sessions with a linked EVM address may settle with a secp256k1 voucher
+    /// instead of the viewer's signature.
+    pub fn settle_session<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleSession<'info>>,
         chunk_count: u32,
         settlement_timestamp: i64,
+        seconds_watched: u32,
+        settlement_id: [u8; 16],
+        last_watched_chunk: Option<u32>,
     ) -> Result<()> {
-        instructions::settle_session(ctx, chunk_count, settlement_timestamp)
+        instructions::settle_session(
+            ctx,
+            chunk_count,
+            settlement_timestamp,
+            seconds_watched,
+            settlement_id,
+            last_watched_chunk,
+        )
     }
 
     /// Pay for a single chunk (sequential only)
     /// NOTE: This instruction is kept for high-value content but bypassed
     /// for x402 micropayments. Use settle_session() for batch settlement.
-    pub fn pay_for_chunk(ctx: Context<PayForChunk>, chunk_index: u32) -> Result<()> {
+    /// Signed by the viewer or the session's registered session key.
+    pub fn pay_for_chunk<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayForChunk<'info>>,
+        chunk_index: u32,
+    ) -> Result<()> {
         instructions::pay_for_chunk(ctx, chunk_index)
     }
 
     /// Revoke streaming delegation
+    /// (the token delegation is shared, so this ends all of the viewer's sessions)
     pub fn revoke_streaming_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
         instructions::revoke_streaming_delegate(ctx)
     }
@@ -95,4 +161,913 @@ pub mod solplay_402 {
     pub fn close_viewer_session(ctx: Context<CloseViewerSession>) -> Result<()> {
         instructions::close_viewer_session(ctx)
     }
+
+    /// Restart an expired or inactive session's clocks, keeping its history
+    pub fn renew_session(ctx: Context<RenewSession>) -> Result<()> {
+        instructions::renew_session(ctx)
+    }
+
+    /// Read-only session health check (run via simulateTransaction)
+    pub fn get_session_status(ctx: Context<GetSessionStatus>) -> Result<SessionStatus> {
+        instructions::get_session_status(ctx)
+    }
+
+    /// Propose a new platform fee (applies after the timelock delay)
+    pub fn propose_fee_change(
+        ctx: Context<ProposeFeeChange>,
+        new_fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::propose_fee_change(ctx, new_fee_basis_points)
+    }
+
+    /// Apply a previously proposed platform fee once the timelock has elapsed
+    pub fn execute_fee_change(ctx: Context<ExecuteFeeChange>) -> Result<()> {
+        instructions::execute_fee_change(ctx)
+    }
+
+    /// Update admin-tunable platform policies
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        update: PlatformConfigUpdate,
+    ) -> Result<()> {
+        instructions::update_platform_config(ctx, update)
+    }
+
+    /// Report an A/V quality issue on a range of paid chunks
+    pub fn report_quality_issue(
+        ctx: Context<ReportQualityIssue>,
+        chunk_start: u32,
+        chunk_end: u32,
+        issue_code: QualityIssueCode,
+    ) -> Result<()> {
+        instructions::report_quality_issue(ctx, chunk_start, chunk_end, issue_code)
+    }
+
+    /// Verify a quality report (platform authority), refunding per platform policy
+    pub fn verify_quality_report(ctx: Context<VerifyQualityReport>) -> Result<()> {
+        instructions::verify_quality_report(ctx)
+    }
+
+    /// Configure volume-based platform fee tiers for creators (admin)
+    pub fn set_creator_tiers(ctx: Context<SetCreatorTiers>, tiers: Vec<CreatorTier>) -> Result<()> {
+        instructions::set_creator_tiers(ctx, tiers)
+    }
+
+    /// Record a validated settlement batch without transferring tokens (phase 1)
+    pub fn prepare_settlement(
+        ctx: Context<PrepareSettlement>,
+        chunk_count: u32,
+        settlement_timestamp: i64,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::prepare_settlement(ctx, chunk_count, settlement_timestamp, merkle_root)
+    }
+
+    /// Perform the token transfers for a prepared settlement batch (phase 2)
+    pub fn execute_settlement<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteSettlement<'info>>,
+    ) -> Result<()> {
+        instructions::execute_settlement(ctx)
+    }
+
+    /// Discard a prepared settlement batch
+    pub fn cancel_settlement(ctx: Context<CancelSettlement>) -> Result<()> {
+        instructions::cancel_settlement(ctx)
+    }
+
+    /// Register the static accounts clients should place in address lookup tables
+    pub fn update_common_accounts(
+        ctx: Context<UpdateCommonAccounts>,
+        lookup_table: Pubkey,
+    ) -> Result<()> {
+        instructions::update_common_accounts(ctx, lookup_table)
+    }
+
+    /// Configure how the platform fee is split across recipient accounts (admin)
+    pub fn set_fee_recipients(
+        ctx: Context<SetFeeRecipients>,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        instructions::set_fee_recipients(ctx, recipients)
+    }
+
+    /// Create a promo coupon (creator-scoped, or platform-wide when issued by the authority)
+    pub fn create_coupon(
+        ctx: Context<CreateCoupon>,
+        code_hash: [u8; 32],
+        discount_bps: u16,
+        max_uses: u32,
+        expiry: i64,
+    ) -> Result<()> {
+        instructions::create_coupon(ctx, code_hash, discount_bps, max_uses, expiry)
+    }
+
+    /// Configure NFT-gated pricing for a video (creator)
+    pub fn set_video_gate(
+        ctx: Context<SetVideoGate>,
+        gate_collection: Option<Pubkey>,
+        gated_price_per_chunk: u64,
+    ) -> Result<()> {
+        instructions::set_video_gate(ctx, gate_collection, gated_price_per_chunk)
+    }
+
+    /// Make a video private (allowlist-only) or public (creator)
+    pub fn set_video_visibility(ctx: Context<SetVideoVisibility>, is_private: bool) -> Result<()> {
+        instructions::set_video_visibility(ctx, is_private)
+    }
+
+    /// Add a viewer to a private video's allowlist (creator)
+    pub fn add_allowed_viewer(ctx: Context<AddAllowedViewer>, viewer: Pubkey) -> Result<()> {
+        instructions::add_allowed_viewer(ctx, viewer)
+    }
+
+    /// Gift a prepaid viewing session to another wallet
+    pub fn gift_session(
+        ctx: Context<GiftSession>,
+        recipient: Pubkey,
+        max_chunks: u32,
+        session_nonce: u8,
+    ) -> Result<()> {
+        instructions::gift_session(ctx, recipient, max_chunks, session_nonce)
+    }
+
+    /// Fund a prepaid gift card identified by the public key derived from its code
+    pub fn create_gift_card(
+        ctx: Context<CreateGiftCard>,
+        amount: u64,
+        code_key: Pubkey,
+    ) -> Result<()> {
+        instructions::create_gift_card(ctx, amount, code_key)
+    }
+
+    /// Redeem a gift card to the signing wallet
+    /// The transaction must verify the code key's Ed25519 signature over
+    /// `gift_card_redeem_message` in a precompile instruction placed right
+    /// before this one.
+    pub fn redeem_gift_card(ctx: Context<RedeemGiftCard>) -> Result<()> {
+        instructions::redeem_gift_card(ctx)
+    }
+
+    /// Create or update the watch-to-earn rewards pool (admin only)
+    pub fn configure_rewards_pool(
+        ctx: Context<ConfigureRewardsPool>,
+        reward_per_chunk: u64,
+    ) -> Result<()> {
+        instructions::configure_rewards_pool(ctx, reward_per_chunk)
+    }
+
+    /// Deposit tokens into the rewards pool (admin only)
+    pub fn fund_rewards_pool(ctx: Context<FundRewardsPool>, amount: u64) -> Result<()> {
+        instructions::fund_rewards_pool(ctx, amount)
+    }
+
+    /// Opt in to watch-to-earn rewards
+    pub fn open_viewer_rewards(ctx: Context<OpenViewerRewards>) -> Result<()> {
+        instructions::open_viewer_rewards(ctx)
+    }
+
+    /// Claim accrued watch-to-earn rewards
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        instructions::claim_rewards(ctx)
+    }
+
+    /// Opt in to on-chain lifetime viewing stats
+    pub fn open_viewer_profile(ctx: Context<OpenViewerProfile>) -> Result<()> {
+        instructions::open_viewer_profile(ctx)
+    }
+
+    /// Mint a soulbound milestone badge to a viewer
+    pub fn claim_badge(ctx: Context<ClaimBadge>, level: u8) -> Result<()> {
+        instructions::claim_badge(ctx, level)
+    }
+
+    /// Opt a video in or out of ad-supported viewing (creator)
+    pub fn set_video_ad_mode(ctx: Context<SetVideoAdMode>, ad_supported: bool) -> Result<()> {
+        instructions::set_video_ad_mode(ctx, ad_supported)
+    }
+
+    /// Fund an ad campaign that subsidizes chunks on ad-supported videos
+    /// `target_creators` are the only creators whose videos it may subsidize
+    pub fn create_ad_campaign(
+        ctx: Context<CreateAdCampaign>,
+        campaign_id: u64,
+        subsidy_per_chunk: u64,
+        deposit: u64,
+        target_creators: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_ad_campaign(
+            ctx,
+            campaign_id,
+            subsidy_per_chunk,
+            deposit,
+            target_creators,
+        )
+    }
+
+    /// Close an ad campaign and refund the unspent budget (advertiser)
+    pub fn close_ad_campaign(ctx: Context<CloseAdCampaign>) -> Result<()> {
+        instructions::close_ad_campaign(ctx)
+    }
+
+    /// Fund a sponsored placement for a video
+    pub fn create_promotion(
+        ctx: Context<CreatePromotion>,
+        budget: u64,
+        bid_per_impression: u64,
+    ) -> Result<()> {
+        instructions::create_promotion(ctx, budget, bid_per_impression)
+    }
+
+    /// Charge promoted impressions against a promotion budget (admin only)
+    pub fn record_promoted_impressions(
+        ctx: Context<RecordPromotedImpressions>,
+        impressions: u64,
+    ) -> Result<()> {
+        instructions::record_promoted_impressions(ctx, impressions)
+    }
+
+    /// End a promotion and refund the unspent budget (sponsor)
+    pub fn end_promotion(ctx: Context<EndPromotion>) -> Result<()> {
+        instructions::end_promotion(ctx)
+    }
+
+    /// Open a crowdfunding campaign for an unreleased video
+    pub fn create_campaign(
+        ctx: Context<CreateCampaign>,
+        video_id: String,
+        goal: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::create_campaign(ctx, video_id, goal, deadline)
+    }
+
+    /// Pledge tokens to a crowdfunding campaign
+    pub fn pledge(ctx: Context<MakePledge>, amount: u64) -> Result<()> {
+        instructions::pledge(ctx, amount)
+    }
+
+    /// Claim a successful campaign (creator) or refund a pledge (backer)
+    pub fn claim_or_refund(ctx: Context<ClaimOrRefund>) -> Result<()> {
+        instructions::claim_or_refund(ctx)
+    }
+
+    /// Schedule when a video can be streamed (creator)
+    pub fn set_publish_window(
+        ctx: Context<SetPublishWindow>,
+        publish_at: Option<i64>,
+        unpublish_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_publish_window(ctx, publish_at, unpublish_at)
+    }
+
+    /// Mark a video as a live stream with a growing chunk count
+    pub fn start_live_stream(ctx: Context<StartLiveStream>) -> Result<()> {
+        instructions::start_live_stream(ctx)
+    }
+
+    /// Append newly published chunks to a live stream
+    pub fn append_chunks(ctx: Context<AppendChunks>, count: u32) -> Result<()> {
+        instructions::append_chunks(ctx, count)
+    }
+
+    /// End a live stream and freeze its chunk count
+    pub fn finalize_live_stream(ctx: Context<FinalizeLiveStream>) -> Result<()> {
+        instructions::finalize_live_stream(ctx)
+    }
+
+    /// Switch a video between per-chunk and per-second billing (creator)
+    pub fn set_billing_mode(
+        ctx: Context<SetBillingMode>,
+        billing_mode: BillingMode,
+        price_per_second: u64,
+    ) -> Result<()> {
+        instructions::set_billing_mode(ctx, billing_mode, price_per_second)
+    }
+
+    /// Set per-resolution chunk prices for a video (creator)
+    pub fn set_quality_tiers(ctx: Context<SetQualityTiers>, tiers: Vec<QualityTier>) -> Result<()> {
+        instructions::set_quality_tiers(ctx, tiers)
+    }
+
+    /// Publish per-rendition storage CIDs for a video (creator)
+    pub fn set_renditions(ctx: Context<SetRenditions>, renditions: Vec<Rendition>) -> Result<()> {
+        instructions::set_renditions(ctx, renditions)
+    }
+
+    /// Open a delivery dispute for a chunk that doesn't match the video's manifest
+    pub fn challenge_delivery(
+        ctx: Context<ChallengeDelivery>,
+        chunk_index: u32,
+        expected_hash: [u8; 32],
+        received_hash: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::challenge_delivery(ctx, chunk_index, expected_hash, received_hash, proof)
+    }
+
+    /// Contest a settlement within the platform's dispute window (viewer)
+    pub fn dispute_settlement(
+        ctx: Context<DisputeSettlement>,
+        nonce: u32,
+        disputed_chunks: u32,
+    ) -> Result<()> {
+        instructions::dispute_settlement(ctx, nonce, disputed_chunks)
+    }
+
+    /// Rule on an open settlement dispute (arbiter)
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, uphold: bool) -> Result<()> {
+        instructions::resolve_dispute(ctx, uphold)
+    }
+
+    /// Stake a settlement operator bond
+    pub fn stake_operator_bond(ctx: Context<StakeOperatorBond>, amount: u64) -> Result<()> {
+        instructions::stake_operator_bond(ctx, amount)
+    }
+
+    /// Withdraw an operator bond after the dispute window
+    pub fn withdraw_operator_bond(ctx: Context<WithdrawOperatorBond>, amount: u64) -> Result<()> {
+        instructions::withdraw_operator_bond(ctx, amount)
+    }
+
+    /// Refund a disputed fraudulent settlement from the operator's bond (arbiter)
+    pub fn slash_operator_bond(ctx: Context<SlashOperatorBond>) -> Result<()> {
+        instructions::slash_operator_bond(ctx)
+    }
+
+    /// Enable or disable netted settlement (admin)
+    pub fn set_netting_mode(ctx: Context<SetNettingMode>, enabled: bool) -> Result<()> {
+        instructions::set_netting_mode(ctx, enabled)
+    }
+
+    /// Pay out netted creator and platform balances in bulk
+    /// Creators are passed as (CreatorEarnings, creator token account, suspension marker) remaining account triples
+    pub fn flush_payouts<'info>(
+        ctx: Context<'_, '_, '_, 'info, FlushPayouts<'info>>,
+    ) -> Result<()> {
+        instructions::flush_payouts(ctx)
+    }
+
+    /// Claim a video's netted earnings from the payout vault
+    pub fn claim_creator_payout(ctx: Context<ClaimCreatorPayout>) -> Result<()> {
+        instructions::claim_creator_payout(ctx)
+    }
+
+    /// Goodwill refund: return part of a video's netted earnings to a viewer
+    pub fn refund_viewer(ctx: Context<RefundViewer>, amount: u64) -> Result<()> {
+        instructions::refund_viewer(ctx, amount)
+    }
+
+    /// Settle many viewers of a video at once against a Merkle root of
+    /// (viewer, chunk_count) leaves, paid from the operator's pool
+    pub fn settle_merkle_batch(
+        ctx: Context<SettleMerkleBatch>,
+        batch_id: u64,
+        root: [u8; 32],
+        leaf_count: u32,
+        total_chunks: u64,
+        total_amount: u64,
+    ) -> Result<()> {
+        instructions::settle_merkle_batch(
+            ctx,
+            batch_id,
+            root,
+            leaf_count,
+            total_chunks,
+            total_amount,
+        )
+    }
+
+    /// Contest a viewer's leaf in a Merkle batch within the dispute window
+    pub fn dispute_batch_leaf(
+        ctx: Context<DisputeBatchLeaf>,
+        leaf_index: u32,
+        chunk_count: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::dispute_batch_leaf(ctx, leaf_index, chunk_count, proof)
+    }
+
+    /// Move a legacy video's on-chain description to off-chain metadata,
+    /// shrinking the account and refunding the freed rent to the creator
+    pub fn migrate_video_metadata(
+        ctx: Context<MigrateVideoMetadata>,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::migrate_video_metadata(ctx, metadata_uri, metadata_hash)
+    }
+
+    /// Check accounting identities and emit InvariantViolation for any broken
+    /// Videos are passed as (Video, CreatorEarnings) remaining account pairs
+    pub fn assert_invariants<'info>(
+        ctx: Context<'_, '_, '_, 'info, AssertInvariants<'info>>,
+    ) -> Result<()> {
+        instructions::assert_invariants(ctx)
+    }
+
+    /// Open a video's revenue bucket for a UTC day (today or tomorrow)
+    pub fn open_earnings_bucket(ctx: Context<OpenEarningsBucket>, day: i64) -> Result<()> {
+        instructions::open_earnings_bucket(ctx, day)
+    }
+
+    /// Record platform totals for the current epoch (once per epoch, anyone)
+    pub fn snapshot_platform_stats(ctx: Context<SnapshotPlatformStats>) -> Result<()> {
+        instructions::snapshot_platform_stats(ctx)
+    }
+
+    /// Opt in to an on-chain list of recently watched videos
+    pub fn open_watch_history(ctx: Context<OpenWatchHistory>) -> Result<()> {
+        instructions::open_watch_history(ctx)
+    }
+
+    /// Move a viewer session to a new wallet (signed by both wallets)
+    /// The new wallet delegates the session's remaining allowance.
+    pub fn transfer_session(ctx: Context<TransferSession>, new_viewer: Pubkey) -> Result<()> {
+        instructions::transfer_session(ctx, new_viewer)
+    }
+
+    /// Opt a session into auto-renewal (viewer)
+    /// Delegates `renewal_budget_chunks` up front so approvals can be extended
+    /// `renewal_chunks` at a time without a new signature.
+    pub fn set_auto_renew(
+        ctx: Context<SetAutoRenew>,
+        auto_renew: bool,
+        renewal_chunks: u32,
+        renewal_budget_chunks: u32,
+    ) -> Result<()> {
+        instructions::set_auto_renew(ctx, auto_renew, renewal_chunks, renewal_budget_chunks)
+    }
+
+    /// Extend a nearly exhausted auto-renew approval from its budget (platform authority)
+    pub fn renew_approval(ctx: Context<RenewApproval>) -> Result<()> {
+        instructions::renew_approval(ctx)
+    }
+
+    /// Open a session from a viewer's off-chain signed approval (relayer pays fees and rent)
+    /// The transaction must verify the viewer's signature with an Ed25519
+    /// precompile instruction placed right before this one.
+    /// `valid_until` is the signer-chosen deadline (up to 30 days out), so the
+    /// approval can be signed offline and submitted any time before it.
+    pub fn approve_with_signature(
+        ctx: Context<ApproveWithSignature>,
+        viewer: Pubkey,
+        max_chunks: u32,
+        session_nonce: u8,
+        max_price_per_chunk: u64,
+        valid_until: i64,
+    ) -> Result<()> {
+        instructions::approve_with_signature(
+            ctx,
+            viewer,
+            max_chunks,
+            session_nonce,
+            max_price_per_chunk,
+            valid_until,
+        )
+    }
+
+    /// Create or toggle the rent sponsorship vault for new sessions (platform authority)
+    pub fn configure_sponsorship_vault(
+        ctx: Context<ConfigureSponsorshipVault>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::configure_sponsorship_vault(ctx, enabled)
+    }
+
+    /// Deposit lamports into the rent sponsorship vault
+    pub fn fund_sponsorship_vault(ctx: Context<FundSponsorshipVault>, amount: u64) -> Result<()> {
+        instructions::fund_sponsorship_vault(ctx, amount)
+    }
+
+    /// Register (or remove, with the default pubkey) a session key that may sign pay_for_chunk
+    pub fn set_session_key(ctx: Context<SetSessionKey>, session_key: Pubkey) -> Result<()> {
+        instructions::set_session_key(ctx, session_key)
+    }
+
+    /// Link (or unlink, with the zero address) an EVM wallet whose vouchers can authorize settlements
+    pub fn set_evm_address(ctx: Context<SetEvmAddress>, evm_address: [u8; 20]) -> Result<()> {
+        instructions::set_evm_address(ctx, evm_address)
+    }
+
+    /// Register (or remove, with the zero key) a passkey that can authorize renewals and settlements
+    pub fn set_passkey(ctx: Context<SetPasskey>, passkey: [u8; 33]) -> Result<()> {
+        instructions::set_passkey(ctx, passkey)
+    }
+
+    /// Authorize a session action with a WebAuthn passkey signature (secp256r1 precompile)
+    pub fn passkey_consent(
+        ctx: Context<PasskeyConsent>,
+        action: PasskeyAction,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    ) -> Result<()> {
+        instructions::passkey_consent(ctx, action, authenticator_data, client_data_json)
+    }
+
+    /// Trust a payment contract on another chain whose Wormhole messages credit sessions (platform authority)
+    pub fn register_cross_chain_emitter(
+        ctx: Context<RegisterCrossChainEmitter>,
+        chain_id: u16,
+        emitter_address: [u8; 32],
+        core_bridge: Pubkey,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::register_cross_chain_emitter(
+            ctx,
+            chain_id,
+            emitter_address,
+            core_bridge,
+            enabled,
+        )
+    }
+
+    /// Open a prepaid session from a Wormhole-attested payment made on another chain
+    pub fn credit_cross_chain_payment(
+        ctx: Context<CreditCrossChainPayment>,
+        chain_id: u16,
+        sequence: u64,
+        viewer: Pubkey,
+        session_nonce: u8,
+    ) -> Result<()> {
+        instructions::credit_cross_chain_payment(ctx, chain_id, sequence, viewer, session_nonce)
+    }
+
+    /// Fund (or top up) a viewer's prepaid session with USDC bridged through Circle CCTP
+    pub fn credit_from_cctp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreditFromCctp<'info>>,
+        viewer: Pubkey,
+        session_nonce: u8,
+        message: Vec<u8>,
+        attestation: Vec<u8>,
+    ) -> Result<()> {
+        instructions::credit_from_cctp(ctx, viewer, session_nonce, message, attestation)
+    }
+
+    /// Verify a signed x402 payment payload and bind it to the session for settlement
+    pub fn verify_x402_proof(
+        ctx: Context<VerifyX402Proof>,
+        signer: Pubkey,
+        amount: u64,
+        resource_hash: [u8; 32],
+        nonce: [u8; 16],
+    ) -> Result<()> {
+        instructions::verify_x402_proof(ctx, signer, amount, resource_hash, nonce)
+    }
+
+    /// Validate payment for a chunk and authorize the license server to release its key
+    pub fn request_key_release(ctx: Context<RequestKeyRelease>, chunk_index: u32) -> Result<()> {
+        instructions::request_key_release(ctx, chunk_index)
+    }
+
+    /// Ban a wallet from opening or extending sessions (platform authority)
+    pub fn ban_viewer(ctx: Context<BanViewer>, viewer: Pubkey) -> Result<()> {
+        instructions::ban_viewer(ctx, viewer)
+    }
+
+    /// Lift a viewer ban (platform authority)
+    pub fn unban_viewer(ctx: Context<UnbanViewer>) -> Result<()> {
+        instructions::unban_viewer(ctx)
+    }
+
+    /// Suspend a creator pending review: no new videos or sessions, payouts frozen (platform authority)
+    pub fn suspend_creator(ctx: Context<SuspendCreator>, creator: Pubkey) -> Result<()> {
+        instructions::suspend_creator(ctx, creator)
+    }
+
+    /// Lift a creator suspension (platform authority)
+    pub fn lift_creator_suspension(ctx: Context<LiftCreatorSuspension>) -> Result<()> {
+        instructions::lift_creator_suspension(ctx)
+    }
+
+    /// Top up the promo vault that funds trial sessions (platform authority)
+    pub fn fund_trial_vault(ctx: Context<FundTrialVault>, amount: u64) -> Result<()> {
+        instructions::fund_trial_vault(ctx, amount)
+    }
+
+    /// Start a viewer's one free trial of a video, funded from the trial vault
+    pub fn start_trial_session(ctx: Context<StartTrialSession>, session_nonce: u8) -> Result<()> {
+        instructions::start_trial_session(ctx, session_nonce)
+    }
+
+    /// Claim part of the daily free chunk allowance as a promo-funded session
+    pub fn claim_free_chunks(
+        ctx: Context<ClaimFreeChunks>,
+        session_nonce: u8,
+        chunks: u32,
+    ) -> Result<()> {
+        instructions::claim_free_chunks(ctx, session_nonce, chunks)
+    }
+
+    /// Set the share of platform fees paid into the insurance fund (platform authority)
+    pub fn configure_insurance_fund(
+        ctx: Context<ConfigureInsuranceFund>,
+        insurance_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_insurance_fund(ctx, insurance_bps)
+    }
+
+    /// Uphold a dispute and refund the viewer from the insurance fund (authority or arbiter)
+    pub fn compensate_viewer(ctx: Context<CompensateViewer>) -> Result<()> {
+        instructions::compensate_viewer(ctx)
+    }
+
+    /// Route a video's creator share to a treasury or other wallet (default = creator)
+    pub fn set_payout_address(
+        ctx: Context<SetPayoutAddress>,
+        payout_address: Pubkey,
+    ) -> Result<()> {
+        instructions::set_payout_address(ctx, payout_address)
+    }
+
+    /// Grant scoped roles (manager / uploader / analyst) to keys acting for the creator
+    pub fn set_creator_team(ctx: Context<SetCreatorTeam>, members: Vec<TeamMember>) -> Result<()> {
+        instructions::set_creator_team(ctx, members)
+    }
+
+    /// Mark a creator as verified (platform authority)
+    pub fn verify_creator(ctx: Context<VerifyCreator>, creator: Pubkey) -> Result<()> {
+        instructions::verify_creator(ctx, creator)
+    }
+
+    /// Revoke a creator's verification (platform authority)
+    pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
+        instructions::revoke_verification(ctx)
+    }
+
+    /// Lock platform tokens for placement weight and a platform fee discount
+    pub fn stake_for_boost(ctx: Context<StakeForBoost>, amount: u64, duration: i64) -> Result<()> {
+        instructions::stake_for_boost(ctx, amount, duration)
+    }
+
+    /// Withdraw a creator's boost stake after its lock has ended
+    pub fn unstake_boost(ctx: Context<UnstakeBoost>) -> Result<()> {
+        instructions::unstake_boost(ctx)
+    }
+
+    /// Create or update the platform-token emission schedule (platform authority)
+    pub fn configure_emissions(
+        ctx: Context<ConfigureEmissions>,
+        emission_per_chunk: u64,
+        halving_interval: i64,
+        creator_share_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_emissions(
+            ctx,
+            emission_per_chunk,
+            halving_interval,
+            creator_share_bps,
+        )
+    }
+
+    /// Deposit platform tokens into the emission vault (platform authority)
+    pub fn fund_emissions(ctx: Context<FundEmissions>, amount: u64) -> Result<()> {
+        instructions::fund_emissions(ctx, amount)
+    }
+
+    /// Opt a wallet (creator or viewer) into platform-token emissions
+    pub fn open_emission_balance(ctx: Context<OpenEmissionBalance>) -> Result<()> {
+        instructions::open_emission_balance(ctx)
+    }
+
+    /// Claim accrued platform-token emissions
+    pub fn claim_emissions(ctx: Context<ClaimEmissions>) -> Result<()> {
+        instructions::claim_emissions(ctx)
+    }
+
+    /// Sponsor a quadratic matching round for creators
+    pub fn create_matching_round(
+        ctx: Context<CreateMatchingRound>,
+        round_id: u64,
+        pool: u64,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<()> {
+        instructions::create_matching_round(ctx, round_id, pool, start_time, end_time)
+    }
+
+    /// Count a paying viewer as a creator's unique supporter in a round
+    pub fn record_matching_support(ctx: Context<RecordMatchingSupport>) -> Result<()> {
+        instructions::record_matching_support(ctx)
+    }
+
+    /// Pay a creator's quadratic match after the round ends (platform authority)
+    pub fn distribute_match(ctx: Context<DistributeMatch>) -> Result<()> {
+        instructions::distribute_match(ctx)
+    }
+
+    /// Refund the undistributed pool and close a matching round (sponsor)
+    pub fn close_matching_round(ctx: Context<CloseMatchingRound>) -> Result<()> {
+        instructions::close_matching_round(ctx)
+    }
+
+    /// Escrow a reward for a subtitle track in a language on a video
+    pub fn post_bounty(ctx: Context<PostBounty>, language: String, amount: u64) -> Result<()> {
+        instructions::post_bounty(ctx, language, amount)
+    }
+
+    /// Pay a subtitle bounty to a contributor (co-signed by the video creator)
+    pub fn claim_bounty(ctx: Context<ClaimBounty>, subtitle_cid: String) -> Result<()> {
+        instructions::claim_bounty(ctx, subtitle_cid)
+    }
+
+    /// Refund and close an unclaimed subtitle bounty (poster)
+    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        instructions::cancel_bounty(ctx)
+    }
+
+    /// Register a subtitle or alternate audio track on a video
+    pub fn add_track(ctx: Context<AddTrack>, track: MediaTrack) -> Result<()> {
+        instructions::add_track(ctx, track)
+    }
+
+    /// Remove a video's subtitle or audio track by kind and language
+    pub fn remove_track(
+        ctx: Context<RemoveTrack>,
+        kind: TrackKind,
+        language: String,
+    ) -> Result<()> {
+        instructions::remove_track(ctx, kind, language)
+    }
+
+    /// Set a video's duration, chunk length, and codec/container (creator)
+    pub fn set_video_format(
+        ctx: Context<SetVideoFormat>,
+        duration_seconds: u32,
+        chunk_duration_ms: u32,
+        media_format: MediaFormat,
+    ) -> Result<()> {
+        instructions::set_video_format(ctx, duration_seconds, chunk_duration_ms, media_format)
+    }
+
+    /// Set a video's audience rating and content warnings (creator)
+    pub fn set_content_rating(
+        ctx: Context<SetContentRating>,
+        rating: ContentRating,
+        content_flags: u16,
+    ) -> Result<()> {
+        instructions::set_content_rating(ctx, rating, content_flags)
+    }
+
+    /// Attest a viewer's age for mature-rated videos (platform age attestor)
+    pub fn attest_age(ctx: Context<AttestAge>, viewer: Pubkey) -> Result<()> {
+        instructions::attest_age(ctx, viewer)
+    }
+
+    /// Revoke a viewer's age attestation (platform age attestor)
+    pub fn revoke_age_attestation(ctx: Context<RevokeAgeAttestation>) -> Result<()> {
+        instructions::revoke_age_attestation(ctx)
+    }
+
+    /// Restrict a video to (or block it from) a set of regions (creator)
+    pub fn set_region_policy(
+        ctx: Context<SetRegionPolicy>,
+        region_policy: Option<RegionPolicy>,
+    ) -> Result<()> {
+        instructions::set_region_policy(ctx, region_policy)
+    }
+
+    /// Irreversibly lock a video's content and chunk layout (creator)
+    pub fn lock_metadata(ctx: Context<LockMetadata>) -> Result<()> {
+        instructions::lock_metadata(ctx)
+    }
+
+    /// Archive a dead video into a compact stub and close the full account
+    pub fn archive_video(ctx: Context<ArchiveVideo>) -> Result<()> {
+        instructions::archive_video(ctx)
+    }
+
+    /// Register several videos in one transaction (accounts in remaining_accounts)
+    pub fn create_videos_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateVideosBatch<'info>>,
+        videos: Vec<VideoParams>,
+    ) -> Result<()> {
+        instructions::create_videos_batch(ctx, videos)
+    }
+
+    /// Create a new creator's profile, first video and earnings in one go
+    pub fn onboard_creator_and_video(
+        ctx: Context<OnboardCreatorAndVideo>,
+        params: VideoParams,
+        first_tier: Option<QualityTier>,
+    ) -> Result<()> {
+        instructions::onboard_creator_and_video(ctx, params, first_tier)
+    }
+
+    /// Set the chunk price of every video passed in remaining_accounts (creator)
+    pub fn update_prices_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdatePricesBatch<'info>>,
+        new_price: u64,
+    ) -> Result<()> {
+        instructions::update_prices_batch(ctx, new_price)
+    }
+
+    /// Deactivate every video passed in remaining_accounts (platform authority)
+    pub fn deactivate_videos_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeactivateVideosBatch<'info>>,
+    ) -> Result<()> {
+        instructions::deactivate_videos_batch(ctx)
+    }
+
+    /// Set a video's per-position pricing curve (creator)
+    pub fn set_price_curve(
+        ctx: Context<SetPriceCurve>,
+        price_breaks: Vec<PriceBreak>,
+    ) -> Result<()> {
+        instructions::set_price_curve(ctx, price_breaks)
+    }
+
+    /// Set a video's recurring happy-hour discounts (creator)
+    pub fn set_discount_windows(
+        ctx: Context<SetDiscountWindows>,
+        discount_windows: Vec<DiscountWindow>,
+    ) -> Result<()> {
+        instructions::set_discount_windows(ctx, discount_windows)
+    }
+
+    /// Set the rebate viewers get back on finishing a video (creator)
+    pub fn set_completion_rebate(
+        ctx: Context<SetCompletionRebate>,
+        completion_rebate_bps: u16,
+    ) -> Result<()> {
+        instructions::set_completion_rebate(ctx, completion_rebate_bps)
+    }
+
+    /// Set the refunds offered to viewers of new sessions (creator)
+    pub fn set_refund_policy(
+        ctx: Context<SetRefundPolicy>,
+        refund_policy: RefundPolicy,
+    ) -> Result<()> {
+        instructions::set_refund_policy(ctx, refund_policy)
+    }
+
+    /// Require operator + viewer co-signed settlements for a video (creator)
+    pub fn set_settlement_cosign(ctx: Context<SetSettlementCosign>, required: bool) -> Result<()> {
+        instructions::set_settlement_cosign(ctx, required)
+    }
+
+    /// Create an organization with a shared viewing budget and `seats` member seats
+    pub fn create_organization(ctx: Context<CreateOrganization>, seats: u16) -> Result<()> {
+        instructions::create_organization(ctx, seats)
+    }
+
+    /// Top up an organization's shared budget (anyone)
+    pub fn fund_organization(ctx: Context<FundOrganization>, amount: u64) -> Result<()> {
+        instructions::fund_organization(ctx, amount)
+    }
+
+    /// Withdraw unspent budget from an organization (admin)
+    pub fn withdraw_organization_funds(
+        ctx: Context<WithdrawOrganizationFunds>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_organization_funds(ctx, amount)
+    }
+
+    /// Give a wallet a seat in an organization (admin)
+    pub fn add_organization_member(
+        ctx: Context<AddOrganizationMember>,
+        member: Pubkey,
+    ) -> Result<()> {
+        instructions::add_organization_member(ctx, member)
+    }
+
+    /// Free a member's seat in an organization (admin)
+    pub fn remove_organization_member(
+        ctx: Context<RemoveOrganizationMember>,
+        member: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_organization_member(ctx, member)
+    }
+
+    /// Open a session paid from the member's organization budget (member)
+    pub fn start_organization_session(
+        ctx: Context<StartOrganizationSession>,
+        max_chunks: u32,
+        session_nonce: u8,
+    ) -> Result<()> {
+        instructions::start_organization_session(ctx, max_chunks, session_nonce)
+    }
+
+    /// Approve a channel pass: one token budget for chunks of any of a creator's videos
+    /// `max_price_per_chunk` is the highest chunk price the viewer accepts
+    pub fn approve_channel_session(
+        ctx: Context<ApproveChannelSession>,
+        max_spend: u64,
+        max_price_per_chunk: u64,
+    ) -> Result<()> {
+        instructions::approve_channel_session(ctx, max_spend, max_price_per_chunk)
+    }
+
+    /// Pay for a chunk of one of the creator's videos from a channel session
+    pub fn pay_channel_chunk<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayChannelChunk<'info>>,
+        chunk_index: u32,
+    ) -> Result<()> {
+        instructions::pay_channel_chunk(ctx, chunk_index)
+    }
+
+    /// Close a channel session and reclaim its rent
+    pub fn close_channel_session(ctx: Context<CloseChannelSession>) -> Result<()> {
+        instructions::close_channel_session(ctx)
+    }
 }
@@ -0,0 +1,69 @@
+// =============================================================================
+// Merkle Proofs - Chunk delivery and batch settlement commitments
+// =============================================================================
+// `Video.chunk_merkle_root` commits to the sha256 hash of every HLS chunk, and
+// `MerkleBatch.root` commits to the (viewer, chunk_count) leaves of a batch
+// settlement. Leaves and nodes are domain-separated so a node can't be passed
+// off as a leaf:
+//   chunk leaf = sha256(0x00 || chunk_index (u32 LE) || chunk_hash)
+//   batch leaf = sha256(0x00 || leaf_index (u32 LE) || viewer || chunk_count (u32 LE))
+//   node       = sha256(0x01 || left || right)
+// Siblings are ordered by the bits of the leaf index, lowest level first.
+// =============================================================================
+
+use crate::constants::*;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+pub fn chunk_leaf(chunk_index: u32, chunk_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &chunk_index.to_le_bytes(), chunk_hash]).to_bytes()
+}
+
+pub fn batch_leaf(leaf_index: u32, viewer: &Pubkey, chunk_count: u32) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &leaf_index.to_le_bytes(),
+        viewer.as_ref(),
+        &chunk_count.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Check that `chunk_hash` is the committed hash of chunk `chunk_index`.
+pub fn verify_chunk_proof(
+    root: &[u8; 32],
+    chunk_index: u32,
+    chunk_hash: &[u8; 32],
+    proof: &[[u8; 32]],
+) -> bool {
+    verify_proof(
+        root,
+        chunk_leaf(chunk_index, chunk_hash),
+        chunk_index,
+        proof,
+    )
+}
+
+/// Check that `leaf` sits at position `index` under `root`.
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], index: u32, proof: &[[u8; 32]]) -> bool {
+    if proof.len() > MAX_MERKLE_PROOF_DEPTH {
+        return false;
+    }
+
+    let mut node = leaf;
+    let mut index = index;
+    for sibling in proof {
+        node = if index & 1 == 0 {
+            hashv(&[NODE_PREFIX, &node, sibling]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, sibling, &node]).to_bytes()
+        };
+        index >>= 1;
+    }
+
+    // Leftover index bits mean the proof is too short for this leaf
+    index == 0 && node == *root
+}
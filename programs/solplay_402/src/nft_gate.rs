@@ -0,0 +1,145 @@
+// =============================================================================
+// NFT Gate - Collection ownership proofs via Metaplex token metadata
+// =============================================================================
+// Gated videos charge a reduced price to holders of an NFT from a verified
+// Metaplex collection. The viewer passes, as remaining accounts:
+//   [0] a token account they own holding the NFT (amount >= 1)
+//   [1] the NFT's Metaplex metadata account
+// The metadata is parsed directly (no mpl-token-metadata dependency) up to the
+// `collection` field, which must be verified and match the video's gate.
+// =============================================================================
+
+use crate::errors::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+use anchor_spl::token::{Token, TokenAccount};
+
+pub const METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const METADATA_SEED: &[u8] = b"metadata";
+
+/// Verify that `owner` holds an NFT from the verified collection `collection`.
+pub fn verify_collection_ownership(
+    remaining_accounts: &[AccountInfo],
+    owner: &Pubkey,
+    collection: &Pubkey,
+) -> Result<()> {
+    let [token_account_info, metadata_info, ..] = remaining_accounts else {
+        return err!(StreamingError::NftGateVerificationFailed);
+    };
+
+    require_keys_eq!(
+        *token_account_info.owner,
+        Token::id(),
+        StreamingError::NftGateVerificationFailed
+    );
+    let token_account =
+        TokenAccount::try_deserialize(&mut &token_account_info.try_borrow_data()?[..])
+            .map_err(|_| StreamingError::NftGateVerificationFailed)?;
+    require!(
+        token_account.owner == *owner && token_account.amount >= 1,
+        StreamingError::NftGateVerificationFailed
+    );
+
+    require_keys_eq!(
+        *metadata_info.owner,
+        METADATA_PROGRAM_ID,
+        StreamingError::NftGateVerificationFailed
+    );
+    let (expected_metadata, _) = Pubkey::find_program_address(
+        &[
+            METADATA_SEED,
+            METADATA_PROGRAM_ID.as_ref(),
+            token_account.mint.as_ref(),
+        ],
+        &METADATA_PROGRAM_ID,
+    );
+    require_keys_eq!(
+        metadata_info.key(),
+        expected_metadata,
+        StreamingError::NftGateVerificationFailed
+    );
+
+    let data = metadata_info.try_borrow_data()?;
+    let verified_collection =
+        parse_verified_collection(&data).ok_or(StreamingError::NftGateVerificationFailed)?;
+    require_keys_eq!(
+        verified_collection,
+        *collection,
+        StreamingError::NftGateVerificationFailed
+    );
+
+    Ok(())
+}
+
+/// Minimal Borsh cursor over a Metaplex metadata account
+struct MetadataReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MetadataReader<'a> {
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.offset = self.offset.checked_add(len)?;
+        (self.offset <= self.data.len()).then_some(())
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        let value = *self.data.get(self.offset)?;
+        self.offset += 1;
+        Some(value)
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        let bytes = self.data.get(self.offset..self.offset + 4)?;
+        self.offset += 4;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    fn read_pubkey(&mut self) -> Option<Pubkey> {
+        let bytes = self.data.get(self.offset..self.offset + 32)?;
+        self.offset += 32;
+        Pubkey::try_from(bytes).ok()
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        let len = self.read_u32()? as usize;
+        self.skip(len)
+    }
+
+    fn skip_option_u8(&mut self) -> Option<()> {
+        if self.read_u8()? == 1 {
+            self.skip(1)?;
+        }
+        Some(())
+    }
+}
+
+/// Returns the collection key if the metadata has a *verified* collection
+fn parse_verified_collection(data: &[u8]) -> Option<Pubkey> {
+    let mut reader = MetadataReader { data, offset: 0 };
+
+    reader.skip(1)?; // key
+    reader.skip(32)?; // update_authority
+    reader.skip(32)?; // mint
+    reader.skip_string()?; // name
+    reader.skip_string()?; // symbol
+    reader.skip_string()?; // uri
+    reader.skip(2)?; // seller_fee_basis_points
+    if reader.read_u8()? == 1 {
+        // creators: Vec<Creator { address, verified, share }>
+        let creators = reader.read_u32()? as usize;
+        reader.skip(creators.checked_mul(34)?)?;
+    }
+    reader.skip(1)?; // primary_sale_happened
+    reader.skip(1)?; // is_mutable
+    reader.skip_option_u8()?; // edition_nonce
+    reader.skip_option_u8()?; // token_standard
+
+    if reader.read_u8()? != 1 {
+        return None; // no collection
+    }
+    let verified = reader.read_u8()? == 1;
+    let collection = reader.read_pubkey()?;
+
+    verified.then_some(collection)
+}
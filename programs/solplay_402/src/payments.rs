@@ -0,0 +1,474 @@
+// =============================================================================
+// Payment Helpers - Shared by the chunk payment and settlement instructions
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Burn, CloseAccount, TokenAccount, Transfer};
+
+/// Token amounts produced by a single payment or settlement
+pub struct SettlementAmounts {
+    pub total_payment: u64,
+    pub platform_fee: u64,
+    pub creator_amount: u64,
+    pub penalty_withheld: u64,
+    pub fee_burned: u64, // Portion of the platform fee burned instead of transferred
+    pub fee_insured: u64, // Portion of the platform fee paid into the insurance fund
+    pub ad_subsidy: u64, // Portion of the payment covered by an ad campaign
+    pub completion_rebate: u64, // Creator-funded rebate netted off the viewer's payment
+}
+
+impl SettlementAmounts {
+    /// Split a payment into platform fee and creator share
+    pub fn compute(platform: &Platform, total_payment: u64, fee_basis_points: u16) -> Result<Self> {
+        let platform_fee = platform.calculate_fee_at(total_payment, fee_basis_points)?;
+        Self::split(platform, total_payment, platform_fee)
+    }
+
+    /// Build amounts for an already-determined platform fee, applying the
+    /// platform's burn and insurance shares
+    pub fn split(platform: &Platform, total_payment: u64, platform_fee: u64) -> Result<Self> {
+        let creator_amount = total_payment
+            .checked_sub(platform_fee)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        let fee_burned = platform.calculate_fee_at(platform_fee, platform.burn_bps)?;
+        let fee_insured =
+            platform.calculate_fee_at(platform_fee - fee_burned, platform.insurance_bps)?;
+
+        Ok(Self {
+            total_payment,
+            platform_fee,
+            creator_amount,
+            penalty_withheld: 0,
+            fee_burned,
+            fee_insured,
+            ad_subsidy: 0,
+            completion_rebate: 0,
+        })
+    }
+
+    /// Platform fee left after burning and insuring, distributed to the fee
+    /// account or recipients
+    pub fn distributable_fee(&self) -> u64 {
+        self.platform_fee - self.fee_burned - self.fee_insured
+    }
+
+    /// Amount paid by the viewer (the payment less any ad subsidy)
+    pub fn viewer_paid(&self) -> u64 {
+        self.total_payment - self.ad_subsidy
+    }
+
+    /// Recover quality refunds previously fronted by the platform
+    /// out of the creator share
+    pub fn withhold_penalty(&mut self, creator_earnings: &mut CreatorEarnings) {
+        self.penalty_withheld = creator_earnings.withhold_penalty(self.creator_amount);
+        self.creator_amount -= self.penalty_withheld;
+    }
+
+    /// Net the video's completion rebate off a settlement that brings the
+    /// session to the last chunk: the viewer gets `completion_rebate_bps` of
+    /// everything paid in the session back, out of this settlement's creator
+    /// share (which caps it)
+    pub fn apply_completion_rebate(
+        &mut self,
+        video: &Video,
+        viewer_session: &ViewerSession,
+        chunk_count: u32,
+    ) -> Result<()> {
+        let completes = viewer_session.billing_mode == BillingMode::PerChunk
+            && (!video.is_live || video.live_finalized)
+            && viewer_session.chunks_consumed < video.total_chunks
+            && viewer_session.chunks_consumed.saturating_add(chunk_count) >= video.total_chunks;
+        if video.completion_rebate_bps == 0 || !completes {
+            return Ok(());
+        }
+
+        let session_paid = viewer_session
+            .total_spent
+            .checked_add(self.viewer_paid())
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        let rebate = (session_paid as u128 * video.completion_rebate_bps as u128
+            / BASIS_POINTS as u128) as u64;
+        self.completion_rebate = rebate.min(self.creator_amount);
+        self.creator_amount -= self.completion_rebate;
+        self.total_payment -= self.completion_rebate;
+        Ok(())
+    }
+
+    /// Amount routed to the platform fee account (unburned fee plus recovered penalties)
+    pub fn platform_transfer_amount(&self) -> Result<u64> {
+        Ok(self
+            .distributable_fee()
+            .checked_add(self.penalty_withheld)
+            .ok_or(StreamingError::ArithmeticOverflow)?)
+    }
+}
+
+/// Resolve the token account a session pays from: the session escrow for
+/// gifted sessions, otherwise the viewer's delegated token account
+pub fn session_payment_account<'a, 'info>(
+    viewer_session: &ViewerSession,
+    viewer_token_account: &'a Account<'info, TokenAccount>,
+    session_escrow: Option<&'a Account<'info, TokenAccount>>,
+) -> Result<&'a Account<'info, TokenAccount>> {
+    if viewer_session.is_gifted() {
+        session_escrow.ok_or_else(|| error!(StreamingError::SessionEscrowRequired))
+    } else {
+        Ok(viewer_token_account)
+    }
+}
+
+/// Check the platform still holds enough of the viewer's delegation to debit
+/// `amount`. SPL approve replaces any previous delegate, so another dApp can
+/// silently clobber ours; the distinct error lets clients prompt re-approval.
+/// Gifted sessions pay from their escrow and need no delegation.
+pub fn check_delegation(
+    viewer_session: &ViewerSession,
+    payment_account: &TokenAccount,
+    platform: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if viewer_session.is_gifted() || amount == 0 {
+        return Ok(());
+    }
+    require!(
+        payment_account.delegate == COption::Some(*platform)
+            && payment_account.delegated_amount >= amount,
+        StreamingError::DelegationMissing
+    );
+    Ok(())
+}
+
+/// Token source for a payment, debited using the platform PDA as signing
+/// authority (the PDA is the SPL delegate on viewer accounts and the owner
+/// of session escrows)
+pub struct PaymentSource<'info> {
+    pub token_program: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub platform: AccountInfo<'info>,
+    pub platform_bump: u8,
+}
+
+impl<'info> PaymentSource<'info> {
+    /// Transfer `amount` to `to`. Zero amounts are skipped.
+    pub fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let platform_seeds = &[PLATFORM_SEED, &[self.platform_bump]];
+        let signer = &[&platform_seeds[..]];
+
+        let transfer = Transfer {
+            from: self.from.clone(),
+            to,
+            authority: self.platform.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.clone(), transfer, signer);
+        token::transfer(cpi_ctx, amount)
+    }
+
+    /// Burn `amount` from the source account. Zero amounts are skipped.
+    pub fn burn(&self, token_mint: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let platform_seeds = &[PLATFORM_SEED, &[self.platform_bump]];
+        let signer = &[&platform_seeds[..]];
+
+        let burn = Burn {
+            mint: token_mint,
+            from: self.from.clone(),
+            authority: self.platform.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.clone(), burn, signer);
+        token::burn(cpi_ctx, amount)
+    }
+
+    /// Close the source account (an escrow owned by the platform PDA),
+    /// returning its rent to `destination`
+    pub fn close(&self, destination: AccountInfo<'info>) -> Result<()> {
+        let platform_seeds = &[PLATFORM_SEED, &[self.platform_bump]];
+        let signer = &[&platform_seeds[..]];
+
+        let close = CloseAccount {
+            account: self.from.clone(),
+            destination,
+            authority: self.platform.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.clone(), close, signer);
+        token::close_account(cpi_ctx)
+    }
+
+    /// Pay the platform's share of a payment. The configured burn share of the
+    /// fee is burned first (requires the mint account) and the insurance share
+    /// paid into the insurance fund (requires the fund account). When fee recipients are
+    /// configured, the remaining fee is split by weight across the recipient
+    /// token accounts passed (in order) as remaining accounts; rounding dust
+    /// and withheld penalties go to the platform fee account.
+    pub fn pay_platform_share(
+        &self,
+        platform_token_account: AccountInfo<'info>,
+        token_mint: Option<AccountInfo<'info>>,
+        insurance_fund: Option<AccountInfo<'info>>,
+        fee_recipients: Option<&FeeRecipientConfig>,
+        remaining_accounts: &[AccountInfo<'info>],
+        amounts: &SettlementAmounts,
+    ) -> Result<()> {
+        if amounts.fee_burned > 0 {
+            let token_mint = token_mint.ok_or(StreamingError::TokenMintRequiredForBurn)?;
+            self.burn(token_mint, amounts.fee_burned)?;
+        }
+
+        if amounts.fee_insured > 0 {
+            let insurance_fund = insurance_fund.ok_or(StreamingError::InsuranceFundRequired)?;
+            self.transfer(insurance_fund, amounts.fee_insured)?;
+        }
+
+        let mut platform_amount = amounts.platform_transfer_amount()?;
+
+        if let Some(fee_recipients) = fee_recipients {
+            require!(
+                remaining_accounts.len() >= fee_recipients.recipients.len(),
+                StreamingError::FeeRecipientAccountMismatch
+            );
+
+            for (recipient, account) in fee_recipients.recipients.iter().zip(remaining_accounts) {
+                require_keys_eq!(
+                    account.key(),
+                    recipient.token_account,
+                    StreamingError::FeeRecipientAccountMismatch
+                );
+                let share = recipient.share_of(amounts.distributable_fee())?;
+                self.transfer(account.clone(), share)?;
+                platform_amount = platform_amount
+                    .checked_sub(share)
+                    .ok_or(StreamingError::ArithmeticOverflow)?;
+            }
+        }
+
+        self.transfer(platform_token_account, platform_amount)
+    }
+}
+
+/// Credit a netted settlement's shares, held in the payout vault until
+/// the creator claims them or flush_payouts transfers them (once matured)
+pub fn credit_netted_payout(
+    creator_earnings: &mut CreatorEarnings,
+    platform: &mut Platform,
+    amounts: &SettlementAmounts,
+    timestamp: i64,
+) -> Result<()> {
+    creator_earnings.credit(
+        amounts.creator_amount,
+        platform.payout_maturation_period,
+        timestamp,
+    )?;
+    platform.fee_owed = platform
+        .fee_owed
+        .checked_add(amounts.platform_transfer_amount()?)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Add a settlement to the video's earnings bucket for the current day (when passed)
+pub fn record_earnings_bucket(
+    earnings_bucket: Option<&mut EarningsBucket>,
+    chunk_count: u64,
+    amounts: &SettlementAmounts,
+    timestamp: i64,
+) -> Result<()> {
+    let Some(earnings_bucket) = earnings_bucket else {
+        return Ok(());
+    };
+    require!(
+        earnings_bucket.day == EarningsBucket::day_of(timestamp),
+        StreamingError::EarningsBucketDayMismatch
+    );
+
+    earnings_bucket.chunks_sold = earnings_bucket
+        .chunks_sold
+        .checked_add(chunk_count)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    earnings_bucket.creator_revenue = earnings_bucket
+        .creator_revenue
+        .checked_add(amounts.creator_amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    earnings_bucket.platform_fee = earnings_bucket
+        .platform_fee
+        .checked_add(amounts.platform_fee)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    earnings_bucket.settlements = earnings_bucket
+        .settlements
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Reimburse a new session's rent to whoever paid it from the sponsorship
+/// vault's spare lamports, and mark the session as sponsored. Returns the
+/// lamports reimbursed, or None (the payer keeps the cost) when sponsorship
+/// is disabled or the vault is depleted.
+pub fn sponsor_session_rent<'info>(
+    vault: &mut Account<'info, SponsorshipVault>,
+    viewer_session: &mut Account<'info, ViewerSession>,
+    rent_payer: &AccountInfo<'info>,
+) -> Result<Option<u64>> {
+    let vault_info = vault.to_account_info();
+    let rent = viewer_session.to_account_info().lamports();
+    let spare = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(SponsorshipVault::LEN));
+    if !vault.enabled || spare < rent {
+        return Ok(None);
+    }
+
+    **vault_info.try_borrow_mut_lamports()? -= rent;
+    **rent_payer.try_borrow_mut_lamports()? += rent;
+
+    vault.total_sponsored = vault
+        .total_sponsored
+        .checked_add(rent)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    vault.sessions_sponsored = vault
+        .sessions_sponsored
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    viewer_session.rent_sponsored = true;
+    Ok(Some(rent))
+}
+
+/// Move a sponsored session's rent back to the sponsorship vault before the
+/// session is closed
+pub fn return_session_rent<'info>(
+    vault: &mut Account<'info, SponsorshipVault>,
+    viewer_session: &AccountInfo<'info>,
+) -> Result<u64> {
+    let rent = viewer_session.lamports();
+    **viewer_session.try_borrow_mut_lamports()? -= rent;
+    **vault.to_account_info().try_borrow_mut_lamports()? += rent;
+
+    vault.total_returned = vault
+        .total_returned
+        .checked_add(rent)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    Ok(rent)
+}
+
+/// Apply the bookkeeping for a settled batch of chunks
+#[allow(clippy::too_many_arguments)]
+pub fn record_settlement(
+    viewer_session: &mut ViewerSession,
+    video: &mut Video,
+    creator_earnings: &mut CreatorEarnings,
+    platform: &mut Platform,
+    viewer_profile: Option<&mut ViewerProfile>,
+    chunk_count: u32,
+    amounts: &SettlementAmounts,
+    timestamp: i64,
+) -> Result<()> {
+    viewer_session.chunks_consumed = viewer_session
+        .chunks_consumed
+        .checked_add(chunk_count)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    viewer_session.total_spent = viewer_session
+        .total_spent
+        .checked_add(amounts.viewer_paid())
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    viewer_session.last_activity = timestamp;
+    viewer_session.settlement_count = viewer_session
+        .settlement_count
+        .checked_add(1)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    viewer_session.last_settled_at = timestamp;
+
+    // Track unique sessions (increment only on first settlement)
+    let is_first_settlement = viewer_session.chunks_consumed == chunk_count;
+    record_sale(
+        video,
+        creator_earnings,
+        platform,
+        viewer_profile,
+        chunk_count,
+        amounts,
+        is_first_settlement,
+        timestamp,
+    )
+}
+
+/// Video, creator, platform and viewer stats for chunks sold, shared by
+/// per-video sessions and channel sessions
+#[allow(clippy::too_many_arguments)]
+pub fn record_sale(
+    video: &mut Video,
+    creator_earnings: &mut CreatorEarnings,
+    platform: &mut Platform,
+    viewer_profile: Option<&mut ViewerProfile>,
+    chunk_count: u32,
+    amounts: &SettlementAmounts,
+    is_first_settlement: bool,
+    timestamp: i64,
+) -> Result<()> {
+    // Update video stats
+    video.total_chunks_served = video
+        .total_chunks_served
+        .checked_add(chunk_count as u64)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    // Update creator earnings
+    creator_earnings.total_earned = creator_earnings
+        .total_earned
+        .checked_add(amounts.creator_amount)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    creator_earnings.total_chunks_sold = creator_earnings
+        .total_chunks_sold
+        .checked_add(chunk_count as u64)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    if is_first_settlement {
+        video.total_sessions = video
+            .total_sessions
+            .checked_add(1)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        creator_earnings.total_sessions = creator_earnings
+            .total_sessions
+            .checked_add(1)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+    }
+
+    // Update platform revenue (burned fees are tracked separately)
+    platform.total_revenue = platform
+        .total_revenue
+        .checked_add(amounts.distributable_fee())
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+    platform.total_burned = platform
+        .total_burned
+        .checked_add(amounts.fee_burned)
+        .ok_or(StreamingError::ArithmeticOverflow)?;
+
+    // Update lifetime viewer stats (opt-in)
+    if let Some(viewer_profile) = viewer_profile {
+        viewer_profile.total_chunks_watched = viewer_profile
+            .total_chunks_watched
+            .checked_add(chunk_count as u64)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        viewer_profile.total_spent = viewer_profile
+            .total_spent
+            .checked_add(amounts.viewer_paid())
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        if is_first_settlement {
+            viewer_profile.total_sessions = viewer_profile
+                .total_sessions
+                .checked_add(1)
+                .ok_or(StreamingError::ArithmeticOverflow)?;
+        }
+        viewer_profile.last_activity = timestamp;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,348 @@
+// =============================================================================
+// Signature Verification - Off-chain signed messages via native precompiles
+// =============================================================================
+// Programs cannot verify signatures cheaply themselves; instead the relayer
+// places a signature-verification precompile instruction immediately before
+// ours in the same transaction. The runtime rejects the transaction if that
+// signature is invalid, so we only need to check (via the instructions
+// sysvar) that the precompile verified the expected signer and message.
+// Passkeys sign WebAuthn assertions, so their message is additionally bound
+// to the action through the challenge in the client data.
+// =============================================================================
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::pubkey;
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+pub const SECP256R1_PROGRAM_ID: Pubkey = pubkey!("Secp256r1SigVerify1111111111111111111111111");
+
+// Ed25519 / Secp256r1 precompile instruction layout (single signature, data inline)
+const ED25519_HEADER_LEN: usize = 2; // num_signatures, padding
+const ED25519_OFFSETS_LEN: usize = 14; // seven u16 offsets/indices
+const ED25519_SIGNATURE_LEN: usize = 64; // Same for Secp256r1 (r || s)
+const CURRENT_INSTRUCTION: u16 = u16::MAX; // Offsets point into the precompile's own data
+
+// WebAuthn assertion checks
+const WEBAUTHN_AUTH_DATA_MIN_LEN: usize = 37; // rpIdHash, flags, signCount
+const WEBAUTHN_FLAGS_INDEX: usize = 32;
+const WEBAUTHN_USER_PRESENT: u8 = 0x01;
+const WEBAUTHN_GET_TYPE: &[u8] = b"\"type\":\"webauthn.get\"";
+
+// Secp256k1 precompile instruction layout (single signature)
+const SECP256K1_HEADER_LEN: usize = 1; // num_signatures
+const SECP256K1_OFFSETS_LEN: usize = 11; // u16 offsets with u8 instruction indices
+const ETH_ADDRESS_LEN: usize = 20;
+const ETH_PERSONAL_SIGN_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Message a viewer signs off-chain to open a session via a relayer
+pub fn signed_approval_message(
+    viewer: &Pubkey,
+    video: &Pubkey,
+    session_nonce: u8,
+    max_chunks: u32,
+    max_price_per_chunk: u64,
+    valid_until: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(SIGNED_APPROVAL_DOMAIN.len() + 32 * 3 + 1 + 4 + 8 + 8);
+    message.extend_from_slice(SIGNED_APPROVAL_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(viewer.as_ref());
+    message.extend_from_slice(video.as_ref());
+    message.push(session_nonce);
+    message.extend_from_slice(&max_chunks.to_le_bytes());
+    message.extend_from_slice(&max_price_per_chunk.to_le_bytes());
+    message.extend_from_slice(&valid_until.to_le_bytes());
+    message
+}
+
+/// Message an EVM wallet (or, for co-signed videos, the viewer's own key)
+/// signs to authorize one settlement of a session. Binding the session's
+/// settlement count makes each voucher single-use.
+pub fn settlement_voucher_message(
+    viewer_session: &Pubkey,
+    settlement_count: u32,
+    chunk_count: u32,
+    seconds_watched: u32,
+    settlement_timestamp: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(SETTLEMENT_VOUCHER_DOMAIN.len() + 32 * 2 + 4 * 3 + 8);
+    message.extend_from_slice(SETTLEMENT_VOUCHER_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(viewer_session.as_ref());
+    message.extend_from_slice(&settlement_count.to_le_bytes());
+    message.extend_from_slice(&chunk_count.to_le_bytes());
+    message.extend_from_slice(&seconds_watched.to_le_bytes());
+    message.extend_from_slice(&settlement_timestamp.to_le_bytes());
+    message
+}
+
+/// Wrap `message` the way Ethereum `personal_sign` does before hashing
+pub fn eth_personal_message(message: &[u8]) -> Vec<u8> {
+    let length = message.len().to_string();
+    let mut wrapped =
+        Vec::with_capacity(ETH_PERSONAL_SIGN_PREFIX.len() + length.len() + message.len());
+    wrapped.extend_from_slice(ETH_PERSONAL_SIGN_PREFIX);
+    wrapped.extend_from_slice(length.as_bytes());
+    wrapped.extend_from_slice(message);
+    wrapped
+}
+
+/// x402 payment payload (the `X-PAYMENT` header) that a viewer or their
+/// session key signs for a paid HTTP resource of the session
+pub fn x402_payment_message(
+    viewer_session: &Pubkey,
+    amount: u64,
+    resource_hash: &[u8; 32],
+    nonce: &[u8; 16],
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(X402_PAYMENT_DOMAIN.len() + 32 * 3 + 8 + 16);
+    message.extend_from_slice(X402_PAYMENT_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(viewer_session.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(resource_hash);
+    message.extend_from_slice(nonce);
+    message
+}
+
+/// Message a gift card's code key signs to pay the card out to `redeemer`
+pub fn gift_card_redeem_message(gift_card: &Pubkey, redeemer: &Pubkey) -> Vec<u8> {
+    let mut message = Vec::with_capacity(GIFT_CARD_REDEEM_DOMAIN.len() + 32 * 3);
+    message.extend_from_slice(GIFT_CARD_REDEEM_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(gift_card.as_ref());
+    message.extend_from_slice(redeemer.as_ref());
+    message
+}
+
+/// Message a passkey signs (as the WebAuthn challenge) to authorize an action
+pub fn passkey_action_message(
+    viewer_session: &ViewerSession,
+    viewer_session_key: &Pubkey,
+    action: &PasskeyAction,
+) -> Vec<u8> {
+    match *action {
+        PasskeyAction::Settlement {
+            chunk_count,
+            seconds_watched,
+            settlement_timestamp,
+        } => settlement_voucher_message(
+            viewer_session_key,
+            viewer_session.settlement_count,
+            chunk_count,
+            seconds_watched,
+            settlement_timestamp,
+        ),
+        // The current approval makes each renewal consent single-use
+        PasskeyAction::RenewApproval => {
+            let mut message = Vec::with_capacity(RENEWAL_CONSENT_DOMAIN.len() + 32 * 2 + 4 * 2);
+            message.extend_from_slice(RENEWAL_CONSENT_DOMAIN);
+            message.extend_from_slice(crate::ID.as_ref());
+            message.extend_from_slice(viewer_session_key.as_ref());
+            message.extend_from_slice(&viewer_session.max_approved_chunks.to_le_bytes());
+            message.extend_from_slice(&viewer_session.renewal_budget_chunks.to_le_bytes());
+            message
+        }
+    }
+}
+
+/// Verify that the instruction preceding the current one is an Ed25519
+/// precompile check of `signer`'s signature over exactly `message`.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    verify_inline_precompile(
+        instructions_sysvar,
+        &ed25519_program::ID,
+        signer.as_ref(),
+        message,
+    )
+}
+
+/// Verify a WebAuthn assertion from `passkey` over `message`: the client data
+/// must be a `webauthn.get` whose challenge is base64url(sha256(message)), and
+/// the preceding Secp256r1 precompile instruction must check the passkey's
+/// signature over `authenticator_data || sha256(client_data_json)`.
+pub fn verify_webauthn_signature(
+    instructions_sysvar: &AccountInfo,
+    passkey: &[u8; 33],
+    message: &[u8],
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+) -> Result<()> {
+    // The authenticator must report user presence
+    require!(
+        authenticator_data.len() >= WEBAUTHN_AUTH_DATA_MIN_LEN
+            && authenticator_data[WEBAUTHN_FLAGS_INDEX] & WEBAUTHN_USER_PRESENT != 0,
+        StreamingError::InvalidSignedMessage
+    );
+
+    let challenge = base64url_encode(&hash(message).to_bytes());
+    let mut challenge_field = b"\"challenge\":\"".to_vec();
+    challenge_field.extend_from_slice(&challenge);
+    challenge_field.push(b'"');
+    require!(
+        contains(client_data_json, WEBAUTHN_GET_TYPE)
+            && contains(client_data_json, &challenge_field),
+        StreamingError::InvalidSignedMessage
+    );
+
+    let mut signed = authenticator_data.to_vec();
+    signed.extend_from_slice(&hash(client_data_json).to_bytes());
+    verify_inline_precompile(instructions_sysvar, &SECP256R1_PROGRAM_ID, passkey, &signed)
+}
+
+/// Check the instruction preceding the current one is a single-signature
+/// Ed25519-layout precompile call (Ed25519 / Secp256r1) with its signature,
+/// public key and message inline, verifying `public_key` over `message`.
+fn verify_inline_precompile(
+    instructions_sysvar: &AccountInfo,
+    precompile: &Pubkey,
+    public_key: &[u8],
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, StreamingError::InvalidSignedMessage);
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        *precompile,
+        StreamingError::InvalidSignedMessage
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        StreamingError::InvalidSignedMessage
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = ED25519_HEADER_LEN;
+    let signature_offset = read_u16(offsets) as usize;
+    let signature_ix = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let pubkey_ix = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_len = read_u16(offsets + 10) as usize;
+    let message_ix = read_u16(offsets + 12);
+
+    // All parts must live in the precompile instruction itself
+    require!(
+        signature_ix == CURRENT_INSTRUCTION
+            && pubkey_ix == CURRENT_INSTRUCTION
+            && message_ix == CURRENT_INSTRUCTION,
+        StreamingError::InvalidSignedMessage
+    );
+    require!(
+        data.len() >= signature_offset + ED25519_SIGNATURE_LEN,
+        StreamingError::InvalidSignedMessage
+    );
+
+    let signed_pubkey = data
+        .get(pubkey_offset..pubkey_offset + public_key.len())
+        .ok_or(StreamingError::InvalidSignedMessage)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_len)
+        .ok_or(StreamingError::InvalidSignedMessage)?;
+    require!(
+        signed_pubkey == public_key && signed_message == message,
+        StreamingError::InvalidSignedMessage
+    );
+
+    Ok(())
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Unpadded base64url, as used for WebAuthn challenges
+fn base64url_encode(bytes: &[u8]) -> Vec<u8> {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut encoded = Vec::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let sextets = [
+            b[0] >> 2,
+            (b[0] & 0x03) << 4 | b[1] >> 4,
+            (b[1] & 0x0f) << 2 | b[2] >> 6,
+            b[2] & 0x3f,
+        ];
+        encoded.extend(
+            sextets[..chunk.len() + 1]
+                .iter()
+                .map(|&sextet| ALPHABET[sextet as usize]),
+        );
+    }
+    encoded
+}
+
+/// Verify that the instruction preceding the current one is a Secp256k1
+/// precompile check of a `personal_sign` signature over `message` by
+/// `eth_address`.
+pub fn verify_secp256k1_signature(
+    instructions_sysvar: &AccountInfo,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, StreamingError::InvalidSignedMessage);
+    let precompile_index = current_index - 1;
+    let ix = load_instruction_at_checked(precompile_index as usize, instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        secp256k1_program::ID,
+        StreamingError::InvalidSignedMessage
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SECP256K1_HEADER_LEN + SECP256K1_OFFSETS_LEN && data[0] == 1,
+        StreamingError::InvalidSignedMessage
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let offsets = SECP256K1_HEADER_LEN;
+    let signature_ix = data[offsets + 2];
+    let eth_address_offset = read_u16(offsets + 3);
+    let eth_address_ix = data[offsets + 5];
+    let message_offset = read_u16(offsets + 6);
+    let message_len = read_u16(offsets + 8);
+    let message_ix = data[offsets + 10];
+
+    // All parts must live in the precompile instruction itself
+    let own_index =
+        u8::try_from(precompile_index).map_err(|_| StreamingError::InvalidSignedMessage)?;
+    require!(
+        signature_ix == own_index && eth_address_ix == own_index && message_ix == own_index,
+        StreamingError::InvalidSignedMessage
+    );
+
+    let signed_address = data
+        .get(eth_address_offset..eth_address_offset + ETH_ADDRESS_LEN)
+        .ok_or(StreamingError::InvalidSignedMessage)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_len)
+        .ok_or(StreamingError::InvalidSignedMessage)?;
+    require!(
+        signed_address == eth_address.as_ref()
+            && signed_message == eth_personal_message(message).as_slice(),
+        StreamingError::InvalidSignedMessage
+    );
+
+    Ok(())
+}
@@ -5,6 +5,7 @@
 use crate::constants::*;
 use crate::errors::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
 
 // =============================================================================
 // Platform Account - Global configuration
@@ -20,6 +21,35 @@ pub struct Platform {
     pub total_sessions: u64,
     pub total_revenue: u64, // Total platform fees collected
     pub bump: u8,
+    pub fee_change_delay: i64, // Minimum delay between proposal and execution
+    pub pending_fee_basis_points: u16, // Proposed fee awaiting timelock
+    pub fee_change_eta: i64,   // Earliest execution time (0 = no pending change)
+    pub quality_report_threshold: u8, // Verified reports per session before refunds apply (0 = disabled)
+    pub quality_refund_bps: u16,      // Share of the reported chunks' value refunded
+    pub creator_tiers_enabled: bool,  // Settlements must resolve fees against CreatorTierConfig
+    pub fee_split_enabled: bool,      // Platform fee is split across FeeRecipientConfig
+    pub burn_bps: u16,                // Share of the platform fee burned at settlement
+    pub total_burned: u64,            // Total platform fees burned
+    pub dispute_window: i64, // Seconds after a settlement a viewer may dispute it (0 = disabled)
+    pub arbiter: Pubkey,     // Key that resolves settlement disputes
+    pub min_operator_bond: u64, // Bond settle_session operators must stake (0 = not required)
+    pub netting_enabled: bool, // settle_session credits balances instead of paying out
+    pub fee_owed: u64,       // Netted platform share held in the payout vault
+    pub max_sessions_per_hour: u16, // New sessions a viewer may open per window (0 = unlimited)
+    pub max_chunks_per_hour: u32, // Chunks a viewer may approve per window (0 = unlimited)
+    pub trial_spend_cap: u64, // Most a trial session may draw from the trial vault (0 = trials off)
+    pub free_chunks_per_day: u32, // Free chunks per viewer per UTC day from the trial vault (0 = off)
+    pub insurance_bps: u16,       // Share of the unburned platform fee paid into the insurance fund
+    pub stake_weight_per_discount_bps: u64, // Creator stake weight per bps of fee discount (0 = off)
+    pub age_attestor: Pubkey,               // Key issuing viewer age attestations (default = none)
+    pub region_attestor: Pubkey, // Key co-signing viewer regions for restricted videos (default = authority only)
+    pub max_price_per_chunk: u64, // Highest price a creator may set per chunk (0 = no cap)
+    pub min_settlement_interval: i64, // Minimum seconds between settlements of a session (0 = off)
+    pub batch_discount_threshold: u32, // Settlements of at least this many units get the batch discount (0 = off)
+    pub batch_discount_bps: u16,       // Fee basis points taken off batch-discounted settlements
+    pub payout_maturation_period: i64, // Seconds netted creator earnings wait before they can be paid out (0 = instant)
+    pub max_chunks_per_settlement: u32, // Most chunks one settlement may charge (0 = no cap)
+    pub max_settlement_value_per_hour_per_session: u64, // Most value a session may settle per hour (0 = no cap)
 }
 
 impl Platform {
@@ -31,11 +61,124 @@ impl Platform {
         8 +  // total_videos
         8 +  // total_sessions
         8 +  // total_revenue
-        1; // bump
+        1 +  // bump
+        8 +  // fee_change_delay
+        2 +  // pending_fee_basis_points
+        8 +  // fee_change_eta
+        1 +  // quality_report_threshold
+        2 +  // quality_refund_bps
+        1 +  // creator_tiers_enabled
+        1 +  // fee_split_enabled
+        2 +  // burn_bps
+        8 +  // total_burned
+        8 +  // dispute_window
+        32 + // arbiter
+        8 +  // min_operator_bond
+        1 +  // netting_enabled
+        8 +  // fee_owed
+        2 +  // max_sessions_per_hour
+        4 +  // max_chunks_per_hour
+        8 +  // trial_spend_cap
+        4 +  // free_chunks_per_day
+        2 +  // insurance_bps
+        8 +  // stake_weight_per_discount_bps
+        32 + // age_attestor
+        32 + // region_attestor
+        8 +  // max_price_per_chunk
+        8 +  // min_settlement_interval
+        4 +  // batch_discount_threshold
+        2 +  // batch_discount_bps
+        8 +  // payout_maturation_period
+        4 +  // max_chunks_per_settlement
+        8; // max_settlement_value_per_hour_per_session
+
+    pub fn has_pending_fee_change(&self) -> bool {
+        self.fee_change_eta != 0
+    }
+
+    /// Whether `key` may attest a viewer's region (the authority, acting as the
+    /// backend operator, or the registered region attestor)
+    pub fn is_region_attestor(&self, key: &Pubkey) -> bool {
+        *key == self.authority
+            || (self.region_attestor != Pubkey::default() && *key == self.region_attestor)
+    }
+
+    /// Check a creator-set per-chunk price against the platform's bounds
+    pub fn check_chunk_price(&self, price_per_chunk: u64) -> Result<()> {
+        require!(
+            price_per_chunk >= self.min_price_per_chunk,
+            StreamingError::PriceTooLow
+        );
+        require!(
+            self.max_price_per_chunk == 0 || price_per_chunk <= self.max_price_per_chunk,
+            StreamingError::PriceTooHigh
+        );
+        Ok(())
+    }
+
+    /// Check a settlement's chunk count against the per-call cap
+    pub fn check_settlement_size(&self, chunk_count: u32) -> Result<()> {
+        require!(
+            self.max_chunks_per_settlement == 0 || chunk_count <= self.max_chunks_per_settlement,
+            StreamingError::SettlementTooLarge
+        );
+        Ok(())
+    }
+
+    /// Whether approvals are rate limited per viewer (requires their ViewerProfile)
+    pub fn has_approval_rate_limits(&self) -> bool {
+        self.max_sessions_per_hour > 0 || self.max_chunks_per_hour > 0
+    }
 
     pub fn calculate_platform_fee(&self, amount: u64) -> Result<u64> {
+        self.calculate_fee_at(amount, self.platform_fee_basis_points)
+    }
+
+    /// Resolve the fee rate for a settlement, applying creator volume tiers when
+    /// enabled and the discount earned by a locked creator stake (when passed)
+    pub fn effective_fee_basis_points(
+        &self,
+        creator_tiers: Option<&CreatorTierConfig>,
+        chunks_sold: u64,
+        creator_stake: Option<&CreatorStake>,
+        current_time: i64,
+    ) -> Result<u16> {
+        let fee_basis_points = if self.creator_tiers_enabled {
+            let creator_tiers = creator_tiers.ok_or(StreamingError::CreatorTierConfigRequired)?;
+            creator_tiers.fee_basis_points_for(chunks_sold, self.platform_fee_basis_points)
+        } else {
+            self.platform_fee_basis_points
+        };
+        let discount = creator_stake.map_or(0, |stake| stake.fee_discount_bps(self, current_time));
+        Ok(fee_basis_points.saturating_sub(discount))
+    }
+
+    /// Fee reduction for a settlement of `chunk_count` units, rewarding fewer
+    /// and larger batches
+    pub fn batch_discount_for(&self, chunk_count: u32) -> u16 {
+        if self.batch_discount_threshold > 0 && chunk_count >= self.batch_discount_threshold {
+            self.batch_discount_bps
+        } else {
+            0
+        }
+    }
+
+    /// Resolve the fee split configuration, required when splitting is enabled
+    pub fn resolve_fee_recipients<'a>(
+        &self,
+        fee_recipients: Option<&'a FeeRecipientConfig>,
+    ) -> Result<Option<&'a FeeRecipientConfig>> {
+        if !self.fee_split_enabled {
+            return Ok(None);
+        }
+        let fee_recipients = fee_recipients.ok_or(StreamingError::FeeRecipientConfigRequired)?;
+        Ok(Some(fee_recipients))
+    }
+
+    /// Fee for `amount` at an explicit rate (e.g. a creator tier discount)
+    pub fn calculate_fee_at(&self, amount: u64, fee_basis_points: u16) -> Result<u64> {
         let fee = (amount as u128)
-            .checked_mul(self.platform_fee_basis_points as u128)
+            .checked_mul(fee_basis_points as u128)
             .ok_or(StreamingError::ArithmeticOverflow)?
             .checked_div(BASIS_POINTS as u128)
             .ok_or(StreamingError::ArithmeticOverflow)?;
@@ -44,10 +187,250 @@ impl Platform {
     }
 }
 
+// =============================================================================
+// CreatorTierConfig - Volume-based platform fee discounts
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CreatorTier {
+    pub min_chunks_sold: u64, // Volume threshold (CreatorEarnings.total_chunks_sold)
+    pub fee_basis_points: u16, // Platform fee applied once the threshold is reached
+}
+
+impl CreatorTier {
+    pub const LEN: usize = 8 + 2;
+}
+
+#[account]
+pub struct CreatorTierConfig {
+    pub tiers: Vec<CreatorTier>, // Sorted by ascending min_chunks_sold
+    pub bump: u8,
+}
+
+impl CreatorTierConfig {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_CREATOR_TIERS * CreatorTier::LEN + // tiers
+        1; // bump
+
+    /// Resolve the fee rate for a creator's sales volume.
+    /// Tiers only ever lower the fee - the platform rate is an upper bound.
+    pub fn fee_basis_points_for(&self, chunks_sold: u64, platform_fee_basis_points: u16) -> u16 {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| chunks_sold >= tier.min_chunks_sold)
+            .map(|tier| tier.fee_basis_points.min(platform_fee_basis_points))
+            .unwrap_or(platform_fee_basis_points)
+    }
+}
+
+// =============================================================================
+// FeeRecipientConfig - Split of the platform fee across several accounts
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FeeRecipient {
+    pub token_account: Pubkey, // Recipient token account (platform mint)
+    pub weight_bps: u16,       // Share of the platform fee
+}
+
+impl FeeRecipient {
+    pub const LEN: usize = 32 + 2;
+
+    pub fn share_of(&self, platform_fee: u64) -> Result<u64> {
+        let share = (platform_fee as u128)
+            .checked_mul(self.weight_bps as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+
+        Ok(share as u64)
+    }
+}
+
+#[account]
+pub struct FeeRecipientConfig {
+    pub recipients: Vec<FeeRecipient>, // Weights sum to BASIS_POINTS
+    pub bump: u8,
+}
+
+impl FeeRecipientConfig {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_FEE_RECIPIENTS * FeeRecipient::LEN + // recipients
+        1; // bump
+}
+
 // =============================================================================
 // Video Account - Video metadata and state
 // =============================================================================
 
+/// How a video's sessions are billed. Per-second sessions lock
+/// `price_per_second` at approval and count approved/consumed units in seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BillingMode {
+    PerChunk,
+    PerSecond,
+}
+
+/// Codec and container of a video's renditions, so players can pick a
+/// compatible stream without fetching the manifest
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MediaFormat {
+    Unspecified,
+    H264Ts,   // H.264 in MPEG-TS segments (classic HLS)
+    H264Fmp4, // H.264 in fragmented MP4 (CMAF)
+    HevcFmp4, // H.265/HEVC in fragmented MP4
+    Av1Fmp4,  // AV1 in fragmented MP4
+    Vp9Webm,  // VP9 in WebM
+}
+
+/// Whether a region policy lists the regions allowed or the regions blocked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RegionMode {
+    Allow,
+    Deny,
+}
+
+/// Geo-restriction for licensed content. Regions are ISO 3166-1 alpha-2 codes
+/// (e.g. b"US"), attested per approval by the platform's region attestor.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RegionPolicy {
+    pub mode: RegionMode,
+    pub regions: Vec<[u8; 2]>,
+}
+
+impl RegionPolicy {
+    pub const LEN: usize = 1 + // mode
+        4 + MAX_POLICY_REGIONS * 2; // regions
+
+    pub fn is_valid(&self) -> bool {
+        self.regions.len() <= MAX_POLICY_REGIONS
+            && self
+                .regions
+                .iter()
+                .all(|code| code.iter().all(u8::is_ascii_uppercase))
+    }
+
+    pub fn permits(&self, region: &[u8; 2]) -> bool {
+        let listed = self.regions.contains(region);
+        match self.mode {
+            RegionMode::Allow => listed,
+            RegionMode::Deny => !listed,
+        }
+    }
+}
+
+/// Audience rating of a video. Mature videos only open sessions for viewers
+/// holding an age attestation from the platform's attestor.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ContentRating {
+    Unrated,
+    General,
+    Teen,
+    Mature,
+}
+
+/// Refunds a video's creator offers, locked into each session at approval.
+/// Viewers claim refunds by disputing a settlement; the platform's dispute
+/// window always bounds how long that stays possible.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RefundPolicy {
+    None,              // Settlements cannot be disputed
+    WithinWindow(u16), // Disputes within this many hours of the latest settlement
+    Always,            // Disputes for the platform's whole dispute window
+}
+
+impl RefundPolicy {
+    pub const LEN: usize = 1 + 2;
+
+    /// Seconds after the latest settlement during which disputes are
+    /// accepted, or None when the policy offers no refunds
+    pub fn dispute_window(&self, platform_window: i64) -> Option<i64> {
+        match self {
+            RefundPolicy::None => None,
+            RefundPolicy::WithinWindow(hours) => {
+                Some((*hours as i64 * SECONDS_PER_HOUR).min(platform_window))
+            }
+            RefundPolicy::Always => Some(platform_window),
+        }
+    }
+}
+
+/// Per-resolution price (e.g. 480p / 720p / 1080p / 4K)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct QualityTier {
+    pub label: String,        // Rendition label, e.g. "1080p"
+    pub price_per_chunk: u64, // Price per chunk at this quality
+}
+
+impl QualityTier {
+    pub const LEN: usize = 4 + MAX_QUALITY_LABEL_LENGTH + 8;
+
+    /// A labelled tier priced within the platform's chunk price bounds
+    pub fn is_valid(&self, platform: &Platform) -> bool {
+        !self.label.is_empty()
+            && self.label.len() <= MAX_QUALITY_LABEL_LENGTH
+            && platform.check_chunk_price(self.price_per_chunk).is_ok()
+    }
+}
+
+/// Pricing curve bracket: from the `from_chunk`-th chunk of a session onward,
+/// chunks cost `multiplier_bps` of the session's locked price per chunk
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PriceBreak {
+    pub from_chunk: u32,
+    pub multiplier_bps: u16,
+}
+
+impl PriceBreak {
+    pub const LEN: usize = 4 + 2;
+}
+
+/// Recurring daily discount window in UTC hours [start_hour, end_hour);
+/// wraps past midnight when end_hour < start_hour
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DiscountWindow {
+    pub start_hour: u8,
+    pub end_hour: u8,
+    pub discount_bps: u16,
+}
+
+impl DiscountWindow {
+    pub const LEN: usize = 1 + 1 + 2;
+
+    pub fn is_valid(&self) -> bool {
+        self.start_hour < 24
+            && self.end_hour < 24
+            && self.start_hour != self.end_hour
+            && self.discount_bps > 0
+            && self.discount_bps as u64 <= BASIS_POINTS
+    }
+
+    pub fn contains(&self, timestamp: i64) -> bool {
+        let hour = (timestamp.rem_euclid(SECONDS_PER_DAY) / 3600) as u8;
+        if self.start_hour < self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// Arguments of create_video, one per entry of create_videos_batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VideoParams {
+    pub video_id: String,
+    pub ipfs_hash: String,
+    pub total_chunks: u32,
+    pub price_per_chunk: u64,
+    pub title: String,
+    pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
+    pub chunk_merkle_root: [u8; 32],
+    pub thumbnail_cid: String,
+    pub poster_cid: String,
+}
+
 #[account]
 pub struct Video {
     pub creator: Pubkey,          // Video creator
@@ -56,12 +439,40 @@ pub struct Video {
     pub total_chunks: u32,        // Total HLS chunks
     pub price_per_chunk: u64,     // Price per chunk in tokens
     pub title: String,            // Video title
-    pub description: String,      // Video description
+    pub metadata_uri: String,     // Off-chain metadata JSON (description etc.)
+    pub metadata_hash: [u8; 32],  // sha256 of the metadata JSON (zero = none)
     pub is_active: bool,          // Can be streamed?
     pub total_sessions: u64,      // Unique viewing sessions
     pub total_chunks_served: u64, // Total chunks paid for
     pub created_at: i64,          // Unix timestamp
     pub bump: u8,
+    pub gate_collection: Pubkey, // NFT collection granting gated pricing (default = no gate)
+    pub gated_price_per_chunk: u64, // Price for holders of the gate collection
+    pub is_private: bool,        // Only allowlisted viewers may open sessions
+    pub ad_supported: bool,      // Settlements may draw subsidies from ad campaigns
+    pub publish_at: i64,         // Streaming opens at this Unix timestamp (0 = immediately)
+    pub unpublish_at: i64,       // Streaming closes at this Unix timestamp (0 = never)
+    pub is_live: bool,           // Live stream - total_chunks grows via append_chunks
+    pub live_finalized: bool,    // Live stream ended; chunk count frozen
+    pub billing_mode: BillingMode, // Unit new sessions are billed in
+    pub price_per_second: u64,   // Price per second watched (PerSecond billing)
+    pub quality_tiers: Vec<QualityTier>, // Optional per-resolution prices (empty = single price)
+    pub chunk_merkle_root: [u8; 32], // Commitment to every chunk hash (zero = no manifest)
+    pub unique_viewers: u64,     // Distinct wallets that opened a session (see ViewedMarker)
+    pub thumbnail_cid: String,   // IPFS CID of the thumbnail image (empty = none)
+    pub poster_cid: String,      // IPFS CID of the poster image (empty = none)
+    pub duration_seconds: u32,   // Playback length (0 = unknown)
+    pub chunk_duration_ms: u32,  // Target length of each chunk (0 = unknown)
+    pub media_format: MediaFormat, // Codec and container of the renditions
+    pub rating: ContentRating,   // Audience rating
+    pub content_flags: u16,      // Content warnings (CONTENT_FLAG_* bits)
+    pub region_policy: Option<RegionPolicy>, // Geo-restriction (None = unrestricted)
+    pub is_immutable: bool,      // Content and chunk layout locked for good (see lock_metadata)
+    pub price_breaks: Vec<PriceBreak>, // Pricing curve for per-chunk sessions (empty = uniform)
+    pub discount_windows: Vec<DiscountWindow>, // Happy-hour discounts applied at approval
+    pub completion_rebate_bps: u16, // Share of a session's spend rebated on finishing (0 = none)
+    pub refund_policy: RefundPolicy, // Disputes offered to viewers of new sessions
+    pub require_viewer_cosign_settlement: bool, // Settlements need an operator and a viewer ed25519 voucher
 }
 
 impl Video {
@@ -72,12 +483,209 @@ impl Video {
         4 +  // total_chunks
         8 +  // price_per_chunk
         4 + MAX_TITLE_LENGTH + // title
-        4 + MAX_DESCRIPTION_LENGTH + // description
+        4 + MAX_METADATA_URI_LENGTH + // metadata_uri
+        32 + // metadata_hash
         1 +  // is_active
         8 +  // total_sessions
         8 +  // total_chunks_served
         8 +  // created_at
-        1; // bump
+        1 +  // bump
+        32 + // gate_collection
+        8 +  // gated_price_per_chunk
+        1 +  // is_private
+        1 +  // ad_supported
+        8 +  // publish_at
+        8 +  // unpublish_at
+        1 +  // is_live
+        1 +  // live_finalized
+        1 +  // billing_mode
+        8 +  // price_per_second
+        4 + MAX_QUALITY_TIERS * QualityTier::LEN + // quality_tiers
+        32 + // chunk_merkle_root
+        8 +  // unique_viewers
+        4 + MAX_IPFS_HASH_LENGTH + // thumbnail_cid
+        4 + MAX_IPFS_HASH_LENGTH + // poster_cid
+        4 +  // duration_seconds
+        4 +  // chunk_duration_ms
+        1 +  // media_format
+        1 +  // rating
+        2 +  // content_flags
+        1 + RegionPolicy::LEN + // region_policy
+        1 +  // is_immutable
+        4 + MAX_PRICE_BREAKS * PriceBreak::LEN + // price_breaks
+        4 + MAX_DISCOUNT_WINDOWS * DiscountWindow::LEN + // discount_windows
+        2 +  // completion_rebate_bps
+        RefundPolicy::LEN + // refund_policy
+        1; // require_viewer_cosign_settlement
+
+    /// Fresh video in its default settings, published by `creator`
+    pub fn new(creator: Pubkey, params: VideoParams, created_at: i64, bump: u8) -> Self {
+        Self {
+            creator,
+            video_id: params.video_id,
+            ipfs_hash: params.ipfs_hash,
+            total_chunks: params.total_chunks,
+            price_per_chunk: params.price_per_chunk,
+            title: params.title,
+            metadata_uri: params.metadata_uri,
+            metadata_hash: params.metadata_hash,
+            is_active: true,
+            total_sessions: 0,
+            total_chunks_served: 0,
+            created_at,
+            bump,
+            gate_collection: Pubkey::default(),
+            gated_price_per_chunk: 0,
+            is_private: false,
+            ad_supported: false,
+            publish_at: 0,
+            unpublish_at: 0,
+            is_live: false,
+            live_finalized: false,
+            billing_mode: BillingMode::PerChunk,
+            price_per_second: 0,
+            quality_tiers: Vec::new(),
+            chunk_merkle_root: params.chunk_merkle_root,
+            unique_viewers: 0,
+            thumbnail_cid: params.thumbnail_cid,
+            poster_cid: params.poster_cid,
+            duration_seconds: 0,
+            chunk_duration_ms: 0,
+            media_format: MediaFormat::Unspecified,
+            rating: ContentRating::Unrated,
+            content_flags: 0,
+            region_policy: None,
+            is_immutable: false,
+            price_breaks: Vec::new(),
+            discount_windows: Vec::new(),
+            completion_rebate_bps: 0,
+            refund_policy: RefundPolicy::Always,
+            require_viewer_cosign_settlement: false,
+        }
+    }
+
+    /// Check a region attestation for an approval. Restricted videos require a
+    /// region co-signed by a platform region attestor that the policy permits;
+    /// returns the region to record on the session (zero = not restricted).
+    pub fn check_region(
+        &self,
+        platform: &Platform,
+        attestor: Option<&Pubkey>,
+        region: Option<[u8; 2]>,
+    ) -> Result<[u8; 2]> {
+        let Some(policy) = self.region_policy.as_ref() else {
+            return Ok([0u8; 2]);
+        };
+        let region = region.ok_or(StreamingError::RegionAttestationRequired)?;
+        require!(
+            attestor.is_some_and(|key| platform.is_region_attestor(key)),
+            StreamingError::RegionAttestationRequired
+        );
+        require!(policy.permits(&region), StreamingError::RegionNotPermitted);
+        Ok(region)
+    }
+
+    /// Whether sessions require the viewer's age attestation
+    pub fn requires_age_attestation(&self) -> bool {
+        self.rating == ContentRating::Mature
+    }
+
+    pub fn has_chunk_manifest(&self) -> bool {
+        self.chunk_merkle_root != [0u8; 32]
+    }
+
+    /// List price per chunk for a quality tier (`None` = base price)
+    pub fn chunk_price(&self, quality_tier: Option<u8>) -> Result<u64> {
+        match quality_tier {
+            None => Ok(self.price_per_chunk),
+            Some(index) => self
+                .quality_tiers
+                .get(index as usize)
+                .map(|tier| tier.price_per_chunk)
+                .ok_or_else(|| error!(StreamingError::InvalidQualityTier)),
+        }
+    }
+
+    /// Apply the deepest happy-hour discount open at `timestamp` to `price`
+    pub fn happy_hour_price(&self, price: u64, timestamp: i64) -> u64 {
+        let discount_bps = self
+            .discount_windows
+            .iter()
+            .filter(|w| w.contains(timestamp))
+            .map(|w| w.discount_bps as u64)
+            .max()
+            .unwrap_or(0);
+        price - (price as u128 * discount_bps as u128 / BASIS_POINTS as u128) as u64
+    }
+
+    /// Pricing curve to lock into a new session. It only shapes per-chunk
+    /// billing, and sessions paid for up front (escrow, signed price caps)
+    /// only take volume-discount curves that never raise the price
+    pub fn session_price_breaks(&self, prepaid: bool) -> Vec<PriceBreak> {
+        let raises_price = self
+            .price_breaks
+            .iter()
+            .any(|b| b.multiplier_bps as u64 > BASIS_POINTS);
+        if self.billing_mode != BillingMode::PerChunk || (prepaid && raises_price) {
+            return Vec::new();
+        }
+        self.price_breaks.clone()
+    }
+
+    /// List price of one billing unit (chunk or second)
+    pub fn unit_price(&self) -> u64 {
+        match self.billing_mode {
+            BillingMode::PerChunk => self.price_per_chunk,
+            BillingMode::PerSecond => self.price_per_second,
+        }
+    }
+
+    /// Cap on units a single approval may add
+    pub fn max_units_per_approval(&self) -> u32 {
+        match self.billing_mode {
+            BillingMode::PerChunk => MAX_CHUNKS_PER_APPROVAL,
+            BillingMode::PerSecond => MAX_SECONDS_PER_APPROVAL,
+        }
+    }
+
+    /// Chunk indexes accepted for payment. Live streams allow a small
+    /// look-ahead past the recorded count to absorb append latency.
+    pub fn is_valid_chunk_index(&self, chunk_index: u32) -> bool {
+        if self.is_live {
+            chunk_index < self.total_chunks.saturating_add(LIVE_CHUNK_LOOKAHEAD)
+        } else {
+            chunk_index < self.total_chunks
+        }
+    }
+
+    /// Enforce the scheduled publish window at `timestamp`
+    pub fn check_publish_window(&self, timestamp: i64) -> Result<()> {
+        require!(
+            self.publish_at == 0 || timestamp >= self.publish_at,
+            StreamingError::VideoNotYetPublished
+        );
+        require!(
+            self.unpublish_at == 0 || timestamp < self.unpublish_at,
+            StreamingError::VideoUnpublished
+        );
+        Ok(())
+    }
+
+    /// Content key id (KID) under which a chunk is encrypted
+    pub fn content_key_id(video: &Pubkey, chunk_index: u32) -> [u8; 16] {
+        let hash = hashv(&[
+            CONTENT_KEY_DOMAIN,
+            video.as_ref(),
+            &chunk_index.to_le_bytes(),
+        ]);
+        let mut key_id = [0u8; 16];
+        key_id.copy_from_slice(&hash.to_bytes()[..16]);
+        key_id
+    }
+
+    pub fn is_gated(&self) -> bool {
+        self.gate_collection != Pubkey::default()
+    }
 
     pub fn validate(&self) -> Result<()> {
         require!(
@@ -93,8 +701,8 @@ impl Video {
             StreamingError::TitleTooLong
         );
         require!(
-            self.description.len() <= MAX_DESCRIPTION_LENGTH,
-            StreamingError::DescriptionTooLong
+            self.metadata_uri.len() <= MAX_METADATA_URI_LENGTH,
+            StreamingError::MetadataUriTooLong
         );
         require!(
             self.total_chunks <= MAX_TOTAL_CHUNKS,
@@ -108,6 +716,19 @@ impl Video {
 // ViewerSession - Tracks individual viewing session with delegation
 // =============================================================================
 
+/// Session action authorized by a passkey (WebAuthn) signature
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PasskeyAction {
+    /// Consent to the session's next settlement with these terms
+    Settlement {
+        chunk_count: u32,
+        seconds_watched: u32,
+        settlement_timestamp: i64,
+    },
+    /// Extend the approval from the auto-renew budget
+    RenewApproval,
+}
+
 #[account]
 pub struct ViewerSession {
     pub viewer: Pubkey,                // Viewer's wallet
@@ -119,6 +740,36 @@ pub struct ViewerSession {
     pub session_start: i64,   // Unix timestamp
     pub last_activity: i64,   // Last settlement or payment time
     pub bump: u8,
+    pub quality_reports: u8,          // Quality reports filed in this session
+    pub verified_quality_reports: u8, // Reports verified by the platform
+    pub list_price_per_chunk: u64,    // Video price at approval, before any discount
+    pub funded_by: Pubkey, // Gifter funding the session escrow (default = viewer delegation)
+    pub billing_mode: BillingMode, // Locked at approval; PerSecond counts chunks_* in seconds
+    pub quality_tier: Option<u8>, // Quality tier chosen at approval (None = base price)
+    pub settlement_count: u32, // Settlements applied; a settlement's nonce is its 0-based index
+    pub last_settled_at: i64, // Time of the latest settlement (opens the dispute window)
+    pub disputed_chunks: u32, // Chunks under dispute across the session
+    pub last_operator: Pubkey, // Bonded operator of the latest settlement (default = none)
+    pub recent_settlement_ids: [[u8; 16]; SETTLEMENT_ID_HISTORY], // Idempotency keys of recent settlements
+    pub last_watched_chunk: u32, // Playback resume position reported at settlement
+    pub session_nonce: u8,       // Distinguishes concurrent sessions of a viewer on one video
+    pub auto_renew: bool,        // Backend may extend the approval from the renewal budget
+    pub renewal_chunks: u32,     // Chunks added per auto-renewal
+    pub renewal_budget_chunks: u32, // Pre-delegated chunks left for auto-renewals
+    pub rent_sponsored: bool,    // Rent paid by the sponsorship vault (returned to it on close)
+    pub session_key: Pubkey,     // Hot key allowed to sign pay_for_chunk (default = none)
+    pub evm_address: [u8; 20],   // EVM wallet whose vouchers authorize settlements (zero = none)
+    pub passkey: [u8; 33],       // Compressed secp256r1 passkey for session actions (zero = none)
+    pub settlement_consent: [u8; 32], // Hash of the next settlement's passkey-consented terms (zero = none)
+    pub watermark_id: [u8; 16],       // Forensic watermark embedded in the session's stream
+    pub attested_region: [u8; 2],     // Viewer region attested at the latest approval (zero = none)
+    pub price_breaks: Vec<PriceBreak>, // Video's pricing curve locked at approval (empty = uniform)
+    pub refund_policy: RefundPolicy,  // Video's refund policy locked at approval
+    pub value_window_start: i64,      // Start of the current hourly settlement value window
+    pub value_window_settled: u64,    // Value settled within the current window
+    pub client_tag: [u8; 32], // Hashed client app/device identifier set at approval (zero = untagged)
+    pub refund_pool: Pubkey, // Pool token account receiving the unspent escrow (default = funder's wallet)
+    pub refunded_chunks: u32, // Chunks refunded through upheld disputes (can't be disputed again)
 }
 
 impl ViewerSession {
@@ -131,12 +782,159 @@ impl ViewerSession {
         8 +  // approved_price_per_chunk
         8 +  // session_start
         8 +  // last_activity
-        1; // bump
+        1 +  // bump
+        1 +  // quality_reports
+        1 +  // verified_quality_reports
+        8 +  // list_price_per_chunk
+        32 + // funded_by
+        1 +  // billing_mode
+        2 +  // quality_tier
+        4 +  // settlement_count
+        8 +  // last_settled_at
+        4 +  // disputed_chunks
+        32 + // last_operator
+        16 * SETTLEMENT_ID_HISTORY + // recent_settlement_ids
+        4 +  // last_watched_chunk
+        1 +  // session_nonce
+        1 +  // auto_renew
+        4 +  // renewal_chunks
+        4 +  // renewal_budget_chunks
+        1 +  // rent_sponsored
+        32 + // session_key
+        20 + // evm_address
+        33 + // passkey
+        32 + // settlement_consent
+        16 + // watermark_id
+        2 +  // attested_region
+        4 + MAX_PRICE_BREAKS * PriceBreak::LEN + // price_breaks
+        RefundPolicy::LEN + // refund_policy
+        8 +  // value_window_start
+        8 +  // value_window_settled
+        32 + // client_tag
+        32 + // refund_pool
+        4; // refunded_chunks
+
+    pub fn is_gifted(&self) -> bool {
+        self.funded_by != Pubkey::default()
+    }
+
+    /// Whether the escrow came from the platform's trial vault (trials and
+    /// free chunks), which no one paid into for this session
+    pub fn is_trial_funded(&self) -> bool {
+        self.refund_pool != Pubkey::default()
+            && self.refund_pool == Pubkey::find_program_address(&[TRIAL_VAULT_SEED], &crate::ID).0
+    }
+
+    /// Consume a passkey consent recorded for a settlement with these voucher
+    /// terms. Returns whether one was recorded.
+    pub fn take_settlement_consent(&mut self, voucher_message: &[u8]) -> bool {
+        let consented = self.settlement_consent != [0u8; 32]
+            && self.settlement_consent == hash(voucher_message).to_bytes();
+        if consented {
+            self.settlement_consent = [0u8; 32];
+        }
+        consented
+    }
+
+    /// Move up to `renewal_chunks` from the renewal budget into the approval.
+    /// Returns the chunks added.
+    pub fn apply_renewal(&mut self, current_time: i64) -> Result<u32> {
+        let renewed_chunks = self.renewal_chunks.min(self.renewal_budget_chunks);
+        self.renewal_budget_chunks -= renewed_chunks;
+        self.max_approved_chunks = self
+            .max_approved_chunks
+            .checked_add(renewed_chunks)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        self.last_activity = current_time;
+        Ok(renewed_chunks)
+    }
+
+    /// Whether `signer` may authorize payments: the viewer or its session key
+    pub fn is_payment_signer(&self, signer: &Pubkey) -> bool {
+        *signer == self.viewer
+            || (self.session_key != Pubkey::default() && *signer == self.session_key)
+    }
+
+    /// Whether a settlement with this idempotency key was recently applied.
+    /// The zero id means "no key" and is never treated as a duplicate.
+    pub fn is_duplicate_settlement(&self, settlement_id: &[u8; 16]) -> bool {
+        *settlement_id != [0u8; 16] && self.recent_settlement_ids.contains(settlement_id)
+    }
+
+    /// Remember a settlement's idempotency key, evicting the oldest
+    pub fn remember_settlement_id(&mut self, settlement_id: [u8; 16]) {
+        if settlement_id != [0u8; 16] {
+            let slot = self.settlement_count as usize % SETTLEMENT_ID_HISTORY;
+            self.recent_settlement_ids[slot] = settlement_id;
+        }
+    }
+
+    /// Resolve the units a settlement bills for under the session's billing
+    /// mode. The count for the other mode must be zero.
+    pub fn billable_units(&self, chunk_count: u32, seconds_watched: u32) -> Result<u32> {
+        match self.billing_mode {
+            BillingMode::PerChunk => {
+                require!(seconds_watched == 0, StreamingError::BillingModeMismatch);
+                Ok(chunk_count)
+            }
+            BillingMode::PerSecond => {
+                require!(chunk_count == 0, StreamingError::BillingModeMismatch);
+                Ok(seconds_watched)
+            }
+        }
+    }
 
     pub fn is_expired(&self, current_time: i64) -> bool {
         current_time - self.session_start > SESSION_EXPIRY_DURATION
     }
 
+    /// Throttle settlements to one per `min_interval` seconds, so an operator
+    /// can't grind the viewer with tiny batches. The final settlement (one
+    /// that uses up the approval, or made within the interval before expiry)
+    /// is always allowed.
+    pub fn check_settlement_interval(
+        &self,
+        min_interval: i64,
+        chunk_count: u32,
+        current_time: i64,
+    ) -> Result<()> {
+        if min_interval == 0 || self.settlement_count == 0 {
+            return Ok(());
+        }
+        let is_final = self.chunks_consumed.saturating_add(chunk_count) >= self.max_approved_chunks
+            || current_time.saturating_add(min_interval)
+                > self.session_start.saturating_add(SESSION_EXPIRY_DURATION);
+        require!(
+            is_final || current_time >= self.last_settled_at.saturating_add(min_interval),
+            StreamingError::SettlementTooFrequent
+        );
+        Ok(())
+    }
+
+    /// Count `amount` against the session's hourly settlement value cap
+    /// (0 = uncapped), so a leaked settlement key can only drain slowly
+    pub fn record_settled_value(
+        &mut self,
+        max_per_hour: u64,
+        amount: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        if current_time - self.value_window_start >= SECONDS_PER_HOUR {
+            self.value_window_start = current_time;
+            self.value_window_settled = 0;
+        }
+        let settled = self
+            .value_window_settled
+            .checked_add(amount)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        require!(
+            max_per_hour == 0 || settled <= max_per_hour,
+            StreamingError::SettlementRateLimited
+        );
+        self.value_window_settled = settled;
+        Ok(())
+    }
+
     pub fn is_inactive(&self, current_time: i64) -> bool {
         current_time - self.last_activity > SESSION_INACTIVITY_DURATION
     }
@@ -144,6 +942,113 @@ impl ViewerSession {
     pub fn has_approval_remaining(&self) -> bool {
         self.chunks_consumed < self.max_approved_chunks
     }
+
+    /// Deterministic forensic watermark id: hash of viewer, video, session start
+    /// and nonce, so a leaked stream can be traced back to the paying session
+    pub fn derive_watermark_id(&self) -> [u8; 16] {
+        let hash = hashv(&[
+            WATERMARK_ID_DOMAIN,
+            self.viewer.as_ref(),
+            self.video.as_ref(),
+            &self.session_start.to_le_bytes(),
+            &[self.session_nonce],
+        ]);
+        let mut watermark_id = [0u8; 16];
+        watermark_id.copy_from_slice(&hash.to_bytes()[..16]);
+        watermark_id
+    }
+
+    /// Whether a chunk (played in order from the start) has already been paid for
+    pub fn is_chunk_paid(&self, chunk_index: u32) -> bool {
+        chunk_index < self.chunks_consumed
+    }
+
+    /// Whether the approval is nearly exhausted and the renewal budget can extend it
+    pub fn is_renewal_due(&self) -> bool {
+        let remaining = self
+            .max_approved_chunks
+            .saturating_sub(self.chunks_consumed) as u64;
+        self.auto_renew
+            && self.renewal_budget_chunks > 0
+            && remaining * 100 <= self.renewal_chunks as u64 * AUTO_RENEW_THRESHOLD_PERCENT
+    }
+
+    /// Unspent delegation this session accounts for, including the auto-renew
+    /// budget (zero for gifted sessions)
+    pub fn remaining_allowance(&self) -> Result<u64> {
+        if self.is_gifted() {
+            return Ok(0);
+        }
+        let remaining = self
+            .max_approved_chunks
+            .saturating_sub(self.chunks_consumed)
+            .checked_add(self.renewal_budget_chunks)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        self.price_of(self.chunks_consumed, remaining)
+    }
+
+    /// Cost of `count` chunks starting at the session's `start`-th paid chunk,
+    /// at the locked price scaled by each pricing curve bracket they fall in
+    pub fn price_of(&self, start: u32, count: u32) -> Result<u64> {
+        let (start, end) = (start as u64, start as u64 + count as u64);
+        let mut bracket_start = 0u64;
+        let mut multiplier_bps = BASIS_POINTS;
+        let mut total = 0u128;
+        for next in self.price_breaks.iter().map(Some).chain([None]) {
+            let bracket_end = next.map_or(u64::MAX, |b| b.from_chunk as u64);
+            let chunks = end
+                .min(bracket_end)
+                .saturating_sub(start.max(bracket_start));
+            total = (self.approved_price_per_chunk as u128 * multiplier_bps as u128)
+                .checked_mul(chunks as u128)
+                .and_then(|cost| total.checked_add(cost))
+                .ok_or(StreamingError::ArithmeticOverflow)?;
+            if let Some(price_break) = next {
+                bracket_start = price_break.from_chunk as u64;
+                multiplier_bps = price_break.multiplier_bps as u64;
+            }
+        }
+        u64::try_from(total / BASIS_POINTS as u128)
+            .map_err(|_| StreamingError::ArithmeticOverflow.into())
+    }
+
+    /// Validate a settlement batch against session state and approval limits.
+    /// Returns the settlement's total payment at the locked approval price.
+    pub fn validate_settlement(
+        &self,
+        chunk_count: u32,
+        settlement_timestamp: i64,
+        current_time: i64,
+    ) -> Result<u64> {
+        require!(chunk_count > 0, StreamingError::InvalidChunkCount);
+        require!(
+            !self.is_expired(current_time),
+            StreamingError::SessionExpired
+        );
+
+        // Allow settlement even if inactive (backend might have queued chunks)
+        // But ensure settlement timestamp is not too old
+        require!(
+            settlement_timestamp >= self.session_start,
+            StreamingError::SettlementTooOld
+        );
+        require!(
+            settlement_timestamp <= current_time,
+            StreamingError::SettlementInFuture
+        );
+
+        let new_total_chunks = self
+            .chunks_consumed
+            .checked_add(chunk_count)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        require!(
+            new_total_chunks <= self.max_approved_chunks,
+            StreamingError::SettlementExceedsApproval
+        );
+
+        // Use locked price and pricing curve from approval time (protects viewer)
+        self.price_of(self.chunks_consumed, chunk_count)
+    }
 }
 
 // =============================================================================
@@ -158,6 +1063,13 @@ pub struct CreatorEarnings {
     pub total_sessions: u64,    // Total unique sessions
     pub total_chunks_sold: u64, // Total chunks sold
     pub bump: u8,
+    pub penalty_outstanding: u64, // Quality refunds fronted by the platform, recovered from future earnings
+    pub disputed_amount: u64,     // Earnings held under open settlement disputes
+    pub claimable: u64, // Netted earnings held in the payout vault, awaiting claim or flush
+    pub claimed: u64,   // Lifetime netted earnings paid out of the payout vault
+    pub payout_address: Pubkey, // Wallet receiving the creator share (default = creator)
+    pub maturing: u64,  // Part of `claimable` still inside the payout maturation window
+    pub matures_at: i64, // When the maturing balance becomes payable
 }
 
 impl CreatorEarnings {
@@ -167,5 +1079,1690 @@ impl CreatorEarnings {
         8 +  // total_earned
         8 +  // total_sessions
         8 +  // total_chunks_sold
+        1 +  // bump
+        8 +  // penalty_outstanding
+        8 +  // disputed_amount
+        8 +  // claimable
+        8 +  // claimed
+        32 + // payout_address
+        8 +  // maturing
+        8; // matures_at
+
+    /// Empty earnings record for a newly created video
+    pub fn new(creator: Pubkey, video: Pubkey, bump: u8) -> Self {
+        Self {
+            creator,
+            video,
+            total_earned: 0,
+            total_sessions: 0,
+            total_chunks_sold: 0,
+            bump,
+            penalty_outstanding: 0,
+            disputed_amount: 0,
+            claimable: 0,
+            claimed: 0,
+            payout_address: Pubkey::default(),
+            maturing: 0,
+            matures_at: 0,
+        }
+    }
+
+    /// Owner of the token account creator payouts must go to
+    pub fn payout_owner(&self) -> Pubkey {
+        if self.payout_address == Pubkey::default() {
+            self.creator
+        } else {
+            self.payout_address
+        }
+    }
+
+    /// Credit netted earnings to the claimable balance. With a maturation
+    /// period they first mature; the maturity of the balance already waiting
+    /// is blended by amount, so a steady stream of credits cannot postpone
+    /// it indefinitely.
+    pub fn credit(&mut self, amount: u64, maturation_period: i64, now: i64) -> Result<()> {
+        self.release_matured(now);
+        self.claimable = self
+            .claimable
+            .checked_add(amount)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        if maturation_period == 0 || amount == 0 {
+            return Ok(());
+        }
+
+        let matures_at = now + maturation_period;
+        let maturing = self.maturing as i128;
+        self.matures_at = ((maturing * self.matures_at as i128
+            + amount as i128 * matures_at as i128)
+            / (maturing + amount as i128)) as i64;
+        self.maturing += amount;
+        Ok(())
+    }
+
+    /// Drop the maturing hold once its window has passed
+    fn release_matured(&mut self, now: i64) {
+        if now >= self.matures_at {
+            self.maturing = 0;
+        }
+    }
+
+    /// Pay out the matured part of the claimable balance and record it as
+    /// claimed, holding back earnings under open disputes until they are
+    /// resolved. Returns the amount to pay out of the payout vault.
+    pub fn take_claimable(&mut self, now: i64) -> Result<u64> {
+        self.release_matured(now);
+        let held = self
+            .maturing
+            .saturating_add(self.disputed_amount)
+            .min(self.claimable);
+        let amount = self.claimable - held;
+        self.claimed = self
+            .claimed
+            .checked_add(amount)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        self.claimable = held;
+        Ok(amount)
+    }
+
+    /// Reclaim up to `amount` of unpaid earnings (maturing first) to cover an
+    /// upheld dispute. Returns the amount taken back from the payout vault.
+    pub fn claw_back(&mut self, amount: u64) -> u64 {
+        let clawed = amount.min(self.claimable);
+        self.claimable -= clawed;
+        self.maturing = self.maturing.saturating_sub(clawed);
+        clawed
+    }
+
+    /// Return `amount` of unclaimed earnings to a viewer, reversing it out
+    /// of the lifetime total
+    pub fn refund(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= self.claimable,
+            StreamingError::RefundExceedsEarnings
+        );
+        self.claimable -= amount;
+        self.maturing = self.maturing.saturating_sub(amount);
+        self.total_earned = self.total_earned.saturating_sub(amount);
+        Ok(())
+    }
+
+    /// Withhold outstanding quality penalties from a creator payout.
+    /// Returns the amount withheld (never more than `creator_amount`).
+    pub fn withhold_penalty(&mut self, creator_amount: u64) -> u64 {
+        let withheld = creator_amount.min(self.penalty_outstanding);
+        self.penalty_outstanding -= withheld;
+        withheld
+    }
+}
+
+// =============================================================================
+// QualityReport - Viewer-filed A/V quality issue for a range of paid chunks
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QualityIssueCode {
+    Buffering,
+    VideoArtifacts,
+    AudioDesync,
+    MissingAudio,
+    WrongResolution,
+    Other,
+}
+
+#[account]
+pub struct QualityReport {
+    pub viewer_session: Pubkey,
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub chunk_start: u32, // First affected chunk (inclusive)
+    pub chunk_end: u32,   // Last affected chunk (inclusive)
+    pub issue_code: QualityIssueCode,
+    pub is_verified: bool,  // Set by the platform after review
+    pub refund_amount: u64, // Tokens refunded when verification triggered a refund
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl QualityReport {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer_session
+        32 + // viewer
+        32 + // video
+        4 +  // chunk_start
+        4 +  // chunk_end
+        1 +  // issue_code
+        1 +  // is_verified
+        8 +  // refund_amount
+        8 +  // created_at
         1; // bump
+
+    pub fn chunk_count(&self) -> u32 {
+        self.chunk_end - self.chunk_start + 1
+    }
+}
+
+// =============================================================================
+// PendingSettlement - Prepared (not yet executed) settlement batch
+// =============================================================================
+
+#[account]
+pub struct PendingSettlement {
+    pub viewer_session: Pubkey,
+    pub chunk_count: u32,
+    pub chunks_consumed_before: u32, // Session state the batch was prepared against
+    pub total_payment: u64,
+    pub platform_fee: u64,
+    pub creator_amount: u64,   // Creator share before penalty withholding
+    pub merkle_root: [u8; 32], // Commitment to the per-chunk detail of this batch
+    pub settlement_timestamp: i64,
+    pub prepared_at: i64,
+    pub bump: u8,
+    pub fee_basis_points: u16,   // Fee rate the batch was priced at
+    pub batch_discount_bps: u16, // Batch discount included in fee_basis_points
+}
+
+impl PendingSettlement {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer_session
+        4 +  // chunk_count
+        4 +  // chunks_consumed_before
+        8 +  // total_payment
+        8 +  // platform_fee
+        8 +  // creator_amount
+        32 + // merkle_root
+        8 +  // settlement_timestamp
+        8 +  // prepared_at
+        1 +  // bump
+        2 +  // fee_basis_points
+        2; // batch_discount_bps
+}
+
+// =============================================================================
+// CommonAccounts - Registry of static accounts for address lookup tables
+// =============================================================================
+
+#[account]
+pub struct CommonAccounts {
+    pub platform: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury: Pubkey, // Platform fee token account
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub creator_tiers: Pubkey,
+    pub lookup_table: Pubkey, // Address lookup table built from this registry (default if none)
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl CommonAccounts {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // platform
+        32 + // token_mint
+        32 + // treasury
+        32 + // token_program
+        32 + // system_program
+        32 + // creator_tiers
+        32 + // lookup_table
+        8 +  // updated_at
+        1; // bump
+
+    /// Static addresses in the canonical order clients should use when
+    /// extending an address lookup table
+    pub fn static_addresses(&self) -> [Pubkey; 6] {
+        [
+            self.platform,
+            self.token_mint,
+            self.treasury,
+            self.token_program,
+            self.system_program,
+            self.creator_tiers,
+        ]
+    }
+}
+
+// =============================================================================
+// Coupon - Promo code discounting the locked price of new sessions
+// =============================================================================
+
+#[account]
+pub struct Coupon {
+    pub code_hash: [u8; 32], // Hash of the promo code (PDA seed)
+    pub issuer: Pubkey,      // Creator or platform authority
+    pub creator: Pubkey,     // Videos the coupon applies to (default = platform-wide)
+    pub discount_bps: u16,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub expiry: i64, // Unix timestamp after which the coupon is invalid
+    pub bump: u8,
+}
+
+impl Coupon {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // code_hash
+        32 + // issuer
+        32 + // creator
+        2 +  // discount_bps
+        4 +  // max_uses
+        4 +  // uses
+        8 +  // expiry
+        1; // bump
+
+    pub fn is_platform_wide(&self) -> bool {
+        self.creator == Pubkey::default()
+    }
+
+    pub fn applies_to(&self, video: &Video) -> bool {
+        self.is_platform_wide() || self.creator == video.creator
+    }
+
+    pub fn is_redeemable(&self, current_time: i64) -> bool {
+        self.uses < self.max_uses && current_time <= self.expiry
+    }
+
+    pub fn discounted_price(&self, price_per_chunk: u64) -> Result<u64> {
+        let discounted = (price_per_chunk as u128)
+            .checked_mul(BASIS_POINTS as u128 - self.discount_bps as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+
+        Ok(discounted as u64)
+    }
+}
+
+// =============================================================================
+// AllowedViewer - Allowlist entry for a private video
+// =============================================================================
+
+#[account]
+pub struct AllowedViewer {
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub added_at: i64, // Unix timestamp
+    pub bump: u8,
+}
+
+impl AllowedViewer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        32 + // viewer
+        8 +  // added_at
+        1; // bump
+}
+
+// =============================================================================
+// GiftCard - Prepaid credit redeemable by whoever knows the code
+// =============================================================================
+
+#[account]
+pub struct GiftCard {
+    pub code_key: Pubkey, // Public key of the keypair derived from the redemption code (PDA seed)
+    pub issuer: Pubkey,   // Funded the card; receives rent on redemption
+    pub amount: u64,      // Tokens held in the gift card vault
+    pub created_at: i64,  // Unix timestamp
+    pub bump: u8,
+}
+
+impl GiftCard {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // code_key
+        32 + // issuer
+        8 +  // amount
+        8 +  // created_at
+        1; // bump
+}
+
+// =============================================================================
+// RewardsPool - Admin-funded watch-to-earn rewards
+// =============================================================================
+
+#[account]
+pub struct RewardsPool {
+    pub reward_per_chunk: u64, // Tokens accrued to the viewer per settled chunk (0 = paused)
+    pub total_funded: u64,     // Tokens deposited into the rewards vault
+    pub total_accrued: u64,    // Rewards credited to viewers
+    pub total_claimed: u64,    // Rewards paid out to viewers
+    pub bump: u8,
+}
+
+impl RewardsPool {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // reward_per_chunk
+        8 +  // total_funded
+        8 +  // total_accrued
+        8 +  // total_claimed
+        1; // bump
+
+    /// Credit rewards for `chunk_count` settled chunks, capped by the
+    /// unallocated pool balance. Returns the amount accrued.
+    pub fn accrue(&mut self, viewer_rewards: &mut ViewerRewards, chunk_count: u32) -> Result<u64> {
+        let earned = self
+            .reward_per_chunk
+            .checked_mul(chunk_count as u64)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        let available = self.total_funded.saturating_sub(self.total_accrued);
+        let reward = earned.min(available);
+
+        self.total_accrued = self
+            .total_accrued
+            .checked_add(reward)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        viewer_rewards.accrued = viewer_rewards
+            .accrued
+            .checked_add(reward)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+
+        Ok(reward)
+    }
+}
+
+// =============================================================================
+// ViewerRewards - Per-viewer watch-to-earn balance (opt-in)
+// =============================================================================
+
+#[account]
+pub struct ViewerRewards {
+    pub viewer: Pubkey,
+    pub accrued: u64, // Lifetime rewards credited
+    pub claimed: u64, // Lifetime rewards paid out
+    pub bump: u8,
+}
+
+impl ViewerRewards {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        8 +  // accrued
+        8 +  // claimed
+        1; // bump
+
+    pub fn claimable(&self) -> u64 {
+        self.accrued - self.claimed
+    }
+}
+
+// =============================================================================
+// ViewerProfile - Lifetime viewing stats across videos (opt-in)
+// =============================================================================
+
+#[account]
+pub struct ViewerProfile {
+    pub viewer: Pubkey,
+    pub total_chunks_watched: u64, // Lifetime chunks paid for
+    pub total_spent: u64,          // Lifetime tokens spent
+    pub total_sessions: u64,       // Sessions with at least one settlement
+    pub created_at: i64,           // Unix timestamp
+    pub last_activity: i64,        // Last settlement recorded
+    pub bump: u8,
+    pub rate_window_start: i64, // Start of the current approval rate-limit window
+    pub window_sessions: u16,   // Sessions opened in the current window
+    pub window_chunks: u32,     // Chunks approved in the current window
+    pub free_chunks_day: i64,   // UTC day number of free_chunks_used
+    pub free_chunks_used: u32,  // Free chunks claimed on free_chunks_day
+}
+
+impl ViewerProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        8 +  // total_chunks_watched
+        8 +  // total_spent
+        8 +  // total_sessions
+        8 +  // created_at
+        8 +  // last_activity
+        1 +  // bump
+        8 +  // rate_window_start
+        2 +  // window_sessions
+        4 +  // window_chunks
+        8 +  // free_chunks_day
+        4; // free_chunks_used
+
+    /// Count an approval against the platform's hourly limits, starting a new
+    /// window once the current one has elapsed
+    pub fn record_approval(
+        &mut self,
+        platform: &Platform,
+        new_session: bool,
+        chunks: u32,
+        current_time: i64,
+    ) -> Result<()> {
+        if current_time - self.rate_window_start >= APPROVAL_RATE_WINDOW {
+            self.rate_window_start = current_time;
+            self.window_sessions = 0;
+            self.window_chunks = 0;
+        }
+
+        if new_session {
+            self.window_sessions = self.window_sessions.saturating_add(1);
+        }
+        self.window_chunks = self.window_chunks.saturating_add(chunks);
+
+        require!(
+            (platform.max_sessions_per_hour == 0
+                || self.window_sessions <= platform.max_sessions_per_hour)
+                && (platform.max_chunks_per_hour == 0
+                    || self.window_chunks <= platform.max_chunks_per_hour),
+            StreamingError::ApprovalRateLimited
+        );
+        Ok(())
+    }
+
+    /// Count free chunks against the platform's daily allowance, resetting it
+    /// on the first claim of a new UTC day
+    pub fn claim_free_chunks(
+        &mut self,
+        platform: &Platform,
+        chunks: u32,
+        current_time: i64,
+    ) -> Result<()> {
+        let today = EarningsBucket::day_of(current_time);
+        if self.free_chunks_day != today {
+            self.free_chunks_day = today;
+            self.free_chunks_used = 0;
+        }
+
+        self.free_chunks_used = self.free_chunks_used.saturating_add(chunks);
+        require!(
+            self.free_chunks_used <= platform.free_chunks_per_day,
+            StreamingError::FreeChunkAllowanceExceeded
+        );
+        Ok(())
+    }
+}
+
+// =============================================================================
+// AdCampaign - Advertiser escrow subsidizing chunks on ad-supported videos
+// =============================================================================
+
+#[account]
+pub struct AdCampaign {
+    pub advertiser: Pubkey,
+    pub campaign_id: u64,       // Advertiser-chosen id (PDA seed)
+    pub vault: Pubkey,          // Token account holding the campaign budget
+    pub subsidy_per_chunk: u64, // Tokens paid per chunk on behalf of the viewer
+    pub total_deposited: u64,
+    pub total_spent: u64,
+    pub impressions: u64, // Chunks settled with this campaign
+    pub is_active: bool,
+    pub created_at: i64, // Unix timestamp
+    pub bump: u8,
+    pub target_creators: Vec<Pubkey>, // Creators whose videos the advertiser subsidizes
+}
+
+impl AdCampaign {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // advertiser
+        8 +  // campaign_id
+        32 + // vault
+        8 +  // subsidy_per_chunk
+        8 +  // total_deposited
+        8 +  // total_spent
+        8 +  // impressions
+        1 +  // is_active
+        8 +  // created_at
+        1 +  // bump
+        4 + MAX_AD_CAMPAIGN_CREATORS * 32; // target_creators
+
+    /// Whether the advertiser chose to subsidize `creator`'s videos
+    pub fn targets(&self, creator: &Pubkey) -> bool {
+        self.target_creators.contains(creator)
+    }
+
+    /// Subsidy for a settlement, limited by the vault balance. A subsidy that
+    /// covers the whole payment pays everything; otherwise it only offsets
+    /// the creator's share (the viewer always pays the platform fee).
+    pub fn subsidy_for(
+        &self,
+        chunk_count: u32,
+        total_payment: u64,
+        creator_amount: u64,
+        vault_balance: u64,
+    ) -> Result<u64> {
+        if !self.is_active {
+            return Ok(0);
+        }
+
+        let subsidy = self
+            .subsidy_per_chunk
+            .checked_mul(chunk_count as u64)
+            .ok_or(StreamingError::ArithmeticOverflow)?
+            .min(vault_balance);
+
+        if subsidy >= total_payment {
+            Ok(total_payment)
+        } else {
+            Ok(subsidy.min(creator_amount))
+        }
+    }
+}
+
+// =============================================================================
+// Promotion - Sponsored placement budget for a video
+// =============================================================================
+
+#[account]
+pub struct Promotion {
+    pub video: Pubkey,
+    pub sponsor: Pubkey,
+    pub vault: Pubkey,           // Token account holding the budget
+    pub bid_per_impression: u64, // Tokens paid per promoted impression
+    pub budget: u64,             // Total tokens deposited
+    pub spent: u64,              // Tokens drawn by the platform
+    pub impressions: u64,        // Promoted impressions recorded
+    pub is_active: bool,         // Eligible for placement (false once exhausted)
+    pub created_at: i64,         // Unix timestamp
+    pub bump: u8,
+}
+
+impl Promotion {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        32 + // sponsor
+        32 + // vault
+        8 +  // bid_per_impression
+        8 +  // budget
+        8 +  // spent
+        8 +  // impressions
+        1 +  // is_active
+        8 +  // created_at
+        1; // bump
+
+    pub fn remaining_budget(&self) -> u64 {
+        self.budget - self.spent
+    }
+}
+
+// =============================================================================
+// FundingCampaign - Crowdfunding escrow for an unreleased video
+// =============================================================================
+
+#[account]
+pub struct FundingCampaign {
+    pub creator: Pubkey,
+    pub video_id: String, // Id the funded video must be published under
+    pub goal: u64,        // Minimum pledged for funds to release
+    pub deadline: i64,    // Video must be published by this Unix timestamp
+    pub total_pledged: u64,
+    pub claimed: bool, // Funds released to the creator
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl FundingCampaign {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        4 + MAX_VIDEO_ID_LENGTH + // video_id
+        8 +  // goal
+        8 +  // deadline
+        8 +  // total_pledged
+        1 +  // claimed
+        8 +  // created_at
+        1; // bump
+
+    /// Funds release only if the goal was met and the video was published
+    /// (by the campaign creator) before the deadline
+    pub fn is_successful(&self, published: Option<&Video>) -> bool {
+        self.total_pledged >= self.goal
+            && published.is_some_and(|video| {
+                video.creator == self.creator && video.created_at <= self.deadline
+            })
+    }
+}
+
+// =============================================================================
+// Pledge - A backer's contribution to a funding campaign
+// =============================================================================
+
+#[account]
+pub struct Pledge {
+    pub campaign: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Pledge {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // campaign
+        32 + // backer
+        8 +  // amount
+        1; // bump
+}
+
+// =============================================================================
+// VideoRenditions - Per-rendition storage CIDs for a video's HLS ladder
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Rendition {
+    pub label: String, // Rendition label, matches a quality tier label (e.g. "1080p")
+    pub cid: String,   // IPFS CID of the rendition's manifest
+    pub chunk_count: u32, // HLS chunks in this rendition
+}
+
+impl Rendition {
+    pub const LEN: usize = 4 + MAX_QUALITY_LABEL_LENGTH + // label
+        4 + MAX_IPFS_HASH_LENGTH + // cid
+        4; // chunk_count
+
+    pub fn is_valid(&self) -> bool {
+        !self.label.is_empty()
+            && self.label.len() <= MAX_QUALITY_LABEL_LENGTH
+            && !self.cid.is_empty()
+            && self.cid.len() <= MAX_IPFS_HASH_LENGTH
+            && self.chunk_count > 0
+            && self.chunk_count <= MAX_TOTAL_CHUNKS
+    }
+}
+
+#[account]
+pub struct VideoRenditions {
+    pub video: Pubkey,
+    pub renditions: Vec<Rendition>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VideoRenditions {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        4 + MAX_RENDITIONS * Rendition::LEN + // renditions
+        8 +  // updated_at
+        1; // bump
+}
+
+// =============================================================================
+// DeliveryChallenge - Viewer dispute over a chunk that didn't match the manifest
+// =============================================================================
+
+#[account]
+pub struct DeliveryChallenge {
+    pub viewer_session: Pubkey,
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub chunk_index: u32,
+    pub expected_hash: [u8; 32], // Committed chunk hash (proven against the manifest)
+    pub received_hash: [u8; 32], // Hash of the chunk the viewer actually received
+    pub opened_at: i64,
+    pub bump: u8,
+}
+
+impl DeliveryChallenge {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer_session
+        32 + // video
+        32 + // viewer
+        4 +  // chunk_index
+        32 + // expected_hash
+        32 + // received_hash
+        8 +  // opened_at
+        1; // bump
+}
+
+// =============================================================================
+// Dispute - Viewer chargeback request against a settlement
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisputeStatus {
+    Open,
+    Refunded,    // Upheld - viewer refunded, creator bears the amount
+    Rejected,    // Settlement stands
+    Slashed,     // Fraudulent settlement - viewer refunded from the operator's bond
+    Compensated, // Upheld - viewer refunded from the insurance fund, creator untouched
+}
+
+#[account]
+pub struct Dispute {
+    pub viewer_session: Pubkey,
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub nonce: u32, // Disputed settlement (index into the session's settlements)
+    pub disputed_chunks: u32, // Chunks the viewer claims were not delivered
+    pub amount: u64, // Value of the disputed chunks at the locked price
+    pub operator: Pubkey, // Bonded operator that settled the session (default = none)
+    pub status: DisputeStatus,
+    pub opened_at: i64,
+    pub resolved_at: i64,
+    pub bump: u8,
+}
+
+impl Dispute {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer_session
+        32 + // video
+        32 + // viewer
+        4 +  // nonce
+        4 +  // disputed_chunks
+        8 +  // amount
+        32 + // operator
+        1 +  // status
+        8 +  // opened_at
+        8 +  // resolved_at
+        1; // bump
+
+    /// Release this dispute's chunks from its session once it is closed out
+    /// (skipped when the session has since been closed or re-created)
+    pub fn release_session(&self, viewer_session: &AccountInfo, refunded: bool) -> Result<()> {
+        if viewer_session.owner != &crate::ID || viewer_session.data_is_empty() {
+            return Ok(());
+        }
+        let mut session =
+            ViewerSession::try_deserialize(&mut &viewer_session.try_borrow_data()?[..])?;
+        if session.session_start > self.opened_at {
+            return Ok(());
+        }
+        session.disputed_chunks = session.disputed_chunks.saturating_sub(self.disputed_chunks);
+        if refunded {
+            session.refunded_chunks = session.refunded_chunks.saturating_add(self.disputed_chunks);
+        }
+        session.try_serialize(&mut &mut viewer_session.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
+
+// =============================================================================
+// OperatorBond - Stake backing a settlement operator's chunk counting
+// =============================================================================
+
+#[account]
+pub struct OperatorBond {
+    pub operator: Pubkey,
+    pub vault: Pubkey, // Token account holding the bond (owned by the platform PDA)
+    pub amount: u64,   // Currently bonded
+    pub total_slashed: u64, // Lifetime slashed amount
+    pub last_settled_at: i64, // Latest settlement by this operator (locks withdrawals)
+    pub bump: u8,
+}
+
+impl OperatorBond {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // operator
+        32 + // vault
+        8 +  // amount
+        8 +  // total_slashed
+        8 +  // last_settled_at
+        1; // bump
+}
+
+// =============================================================================
+// MerkleBatch - Operator-posted settlement covering many viewers of a video
+// =============================================================================
+
+#[account]
+pub struct MerkleBatch {
+    pub video: Pubkey,
+    pub operator: Pubkey,
+    pub batch_id: u64,
+    pub root: [u8; 32], // Commits to the batch's (viewer, chunk_count) leaves
+    pub leaf_count: u32,
+    pub total_chunks: u64,
+    pub total_amount: u64, // Paid from the operator's pool
+    pub disputed_leaves: u32,
+    pub settled_at: i64,
+    pub bump: u8,
+}
+
+impl MerkleBatch {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        32 + // operator
+        8 +  // batch_id
+        32 + // root
+        4 +  // leaf_count
+        8 +  // total_chunks
+        8 +  // total_amount
+        4 +  // disputed_leaves
+        8 +  // settled_at
+        1; // bump
+}
+
+// =============================================================================
+// BatchLeafDispute - A viewer contesting their leaf in a Merkle batch
+// =============================================================================
+
+#[account]
+pub struct BatchLeafDispute {
+    pub batch: Pubkey,
+    pub viewer: Pubkey,
+    pub leaf_index: u32,
+    pub chunk_count: u32, // Chunks the batch charged the viewer for
+    pub opened_at: i64,
+    pub bump: u8,
+}
+
+impl BatchLeafDispute {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // batch
+        32 + // viewer
+        4 +  // leaf_index
+        4 +  // chunk_count
+        8 +  // opened_at
+        1; // bump
+}
+
+// =============================================================================
+// InvariantKind - Accounting identities checked by assert_invariants
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InvariantKind {
+    ChunksSoldMismatch, // Video.total_chunks_served != CreatorEarnings.total_chunks_sold
+    DisputedExceedsEarned, // Disputed holds larger than the creator ever earned
+    PayoutVaultShortfall, // Payout vault holds less than recorded claimables plus fee_owed
+    RewardsVaultShortfall, // Rewards vault holds less than accrued but unclaimed rewards
+}
+
+// =============================================================================
+// EarningsBucket - A video's settled revenue for one UTC day
+// =============================================================================
+
+#[account]
+pub struct EarningsBucket {
+    pub video: Pubkey,
+    pub day: i64, // Days since the Unix epoch (UTC)
+    pub chunks_sold: u64,
+    pub creator_revenue: u64, // Creator share settled this day
+    pub platform_fee: u64,    // Platform fee settled this day
+    pub settlements: u32,
+    pub bump: u8,
+}
+
+impl EarningsBucket {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        8 +  // day
+        8 +  // chunks_sold
+        8 +  // creator_revenue
+        8 +  // platform_fee
+        4 +  // settlements
+        1; // bump
+
+    /// UTC day number containing `timestamp`
+    pub fn day_of(timestamp: i64) -> i64 {
+        timestamp.div_euclid(SECONDS_PER_DAY)
+    }
+}
+
+// =============================================================================
+// PlatformStatsSnapshot - Platform totals captured once per Solana epoch
+// =============================================================================
+
+#[account]
+pub struct PlatformStatsSnapshot {
+    pub epoch: u64,
+    pub total_videos: u64,
+    pub total_sessions: u64,
+    pub total_revenue: u64,
+    pub total_burned: u64,
+    pub taken_at: i64,
+    pub bump: u8,
+}
+
+impl PlatformStatsSnapshot {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // epoch
+        8 +  // total_videos
+        8 +  // total_sessions
+        8 +  // total_revenue
+        8 +  // total_burned
+        8 +  // taken_at
+        1; // bump
+}
+
+// =============================================================================
+// ViewedMarker - Records that a wallet has opened a session on a video
+// =============================================================================
+
+#[account]
+pub struct ViewedMarker {
+    pub video: Pubkey,
+    pub viewer: Pubkey,
+    pub first_viewed_at: i64, // 0 until the first session is opened
+    pub bump: u8,
+}
+
+impl ViewedMarker {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        32 + // viewer
+        8 +  // first_viewed_at
+        1; // bump
+
+    /// Count the viewer towards `video.unique_viewers` the first time they
+    /// open a session on it; later sessions leave the count unchanged
+    pub fn record_view(
+        &mut self,
+        video: &mut Video,
+        video_key: Pubkey,
+        viewer: Pubkey,
+        timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        if self.first_viewed_at != 0 {
+            return Ok(());
+        }
+
+        self.video = video_key;
+        self.viewer = viewer;
+        self.first_viewed_at = timestamp;
+        self.bump = bump;
+        video.unique_viewers = video
+            .unique_viewers
+            .checked_add(1)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// =============================================================================
+// WatchHistory - Opt-in list of a viewer's most recently watched videos
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct WatchEntry {
+    pub video: Pubkey,
+    pub chunks_watched: u32, // Chunks settled in the viewer's session on this video
+    pub last_watched_at: i64,
+}
+
+impl WatchEntry {
+    pub const LEN: usize = 32 + // video
+        4 +  // chunks_watched
+        8; // last_watched_at
+}
+
+#[account]
+pub struct WatchHistory {
+    pub viewer: Pubkey,
+    pub entries: Vec<WatchEntry>, // Oldest first, at most WATCH_HISTORY_LENGTH
+    pub bump: u8,
+}
+
+impl WatchHistory {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        4 + WATCH_HISTORY_LENGTH * WatchEntry::LEN + // entries
+        1; // bump
+
+    /// Move `video` to the most recent entry, evicting the oldest when full
+    pub fn record(&mut self, video: Pubkey, chunks_watched: u32, timestamp: i64) {
+        self.entries.retain(|entry| entry.video != video);
+        if self.entries.len() >= WATCH_HISTORY_LENGTH {
+            self.entries.remove(0);
+        }
+        self.entries.push(WatchEntry {
+            video,
+            chunks_watched,
+            last_watched_at: timestamp,
+        });
+    }
+}
+
+// =============================================================================
+// SponsorshipVault - Platform-funded rent for new viewer sessions
+// =============================================================================
+
+#[account]
+pub struct SponsorshipVault {
+    pub enabled: bool,
+    pub total_funded: u64,    // Lamports deposited
+    pub total_sponsored: u64, // Session rent paid out
+    pub total_returned: u64,  // Session rent returned on close
+    pub sessions_sponsored: u64,
+    pub bump: u8,
+}
+
+impl SponsorshipVault {
+    pub const LEN: usize = 8 + // discriminator
+        1 +  // enabled
+        8 +  // total_funded
+        8 +  // total_sponsored
+        8 +  // total_returned
+        8 +  // sessions_sponsored
+        1; // bump
+}
+
+// =============================================================================
+// CrossChainEmitter - Trusted payment contract on another chain (per chain id)
+// =============================================================================
+
+#[account]
+pub struct CrossChainEmitter {
+    pub chain_id: u16,             // Wormhole chain id (e.g. 30 = Base)
+    pub emitter_address: [u8; 32], // Payment contract, left-padded to 32 bytes
+    pub core_bridge: Pubkey,       // Wormhole core bridge that posts the VAAs
+    pub enabled: bool,
+    pub payments_credited: u64,
+    pub total_credited: u64, // Tokens moved from the float into sessions
+    pub bump: u8,
+}
+
+impl CrossChainEmitter {
+    pub const LEN: usize = 8 + // discriminator
+        2 +  // chain_id
+        32 + // emitter_address
+        32 + // core_bridge
+        1 +  // enabled
+        8 +  // payments_credited
+        8 +  // total_credited
+        1; // bump
+}
+
+// =============================================================================
+// CrossChainReceipt - Replay guard for a credited VAA (per emitter sequence)
+// =============================================================================
+
+#[account]
+pub struct CrossChainReceipt {
+    pub emitter: Pubkey,
+    pub sequence: u64,
+    pub viewer_session: Pubkey,
+    pub amount: u64,
+    pub credited_at: i64,
+    pub bump: u8,
+}
+
+impl CrossChainReceipt {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // emitter
+        8 +  // sequence
+        32 + // viewer_session
+        8 +  // amount
+        8 +  // credited_at
+        1; // bump
+}
+
+// =============================================================================
+// X402Proof - Verified x402 payment payload bound to a session (per nonce)
+// =============================================================================
+
+#[account]
+pub struct X402Proof {
+    pub viewer_session: Pubkey,
+    pub signer: Pubkey,          // Viewer or their session key
+    pub amount: u64,             // Tokens the payload authorizes
+    pub resource_hash: [u8; 32], // sha256 of the paid resource (e.g. chunk URL)
+    pub nonce: [u8; 16],
+    pub verified_at: i64,
+    pub settled: bool, // Consumed by a settlement
+    pub bump: u8,
+}
+
+impl X402Proof {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer_session
+        32 + // signer
+        8 +  // amount
+        32 + // resource_hash
+        16 + // nonce
+        8 +  // verified_at
+        1 +  // settled
+        1; // bump
+}
+
+// =============================================================================
+// BannedViewer - Platform ban on a wallet opening or extending sessions
+// =============================================================================
+
+#[account]
+pub struct BannedViewer {
+    pub viewer: Pubkey,
+    pub banned_at: i64, // Unix timestamp
+    pub bump: u8,
+}
+
+impl BannedViewer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        8 +  // banned_at
+        1; // bump
+}
+
+// =============================================================================
+// SuspendedCreator - Moderation hold on a creator pending review
+// =============================================================================
+
+#[account]
+pub struct SuspendedCreator {
+    pub creator: Pubkey,
+    pub suspended_at: i64, // Unix timestamp
+    pub bump: u8,
+}
+
+impl SuspendedCreator {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 +  // suspended_at
+        1; // bump
+}
+
+// =============================================================================
+// TrialMarker - Records a viewer's one free trial of a video
+// =============================================================================
+
+#[account]
+pub struct TrialMarker {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub viewer_session: Pubkey, // Session the trial opened
+    pub started_at: i64,        // Unix timestamp
+    pub bump: u8,
+}
+
+impl TrialMarker {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        32 + // video
+        32 + // viewer_session
+        8 +  // started_at
+        1; // bump
+}
+
+// =============================================================================
+// CreatorTeam - Keys acting on a creator's behalf with scoped roles
+// =============================================================================
+
+/// What a team member may do for the creator. Analysts get no on-chain
+/// permissions; the role lets off-chain dashboards grant read access.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TeamRole {
+    Manager,  // May call update_video and manage tracks on the creator's videos
+    Uploader, // May call create_video and manage tracks for the creator
+    Analyst,  // Read-only
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TeamMember {
+    pub member: Pubkey,
+    pub role: TeamRole,
+}
+
+impl TeamMember {
+    pub const LEN: usize = 32 + 1;
+}
+
+#[account]
+pub struct CreatorTeam {
+    pub creator: Pubkey,
+    pub members: Vec<TeamMember>, // Unique members
+    pub bump: u8,
+}
+
+impl CreatorTeam {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        4 + MAX_TEAM_MEMBERS * TeamMember::LEN + // members
+        1; // bump
+
+    /// Whether `key` is on the team with `role`
+    pub fn has_role(&self, key: &Pubkey, role: TeamRole) -> bool {
+        self.members
+            .iter()
+            .any(|m| m.member == *key && m.role == role)
+    }
+
+    /// Whether `key` may manage subtitle and audio tracks for the creator
+    pub fn can_manage_tracks(&self, key: &Pubkey) -> bool {
+        self.has_role(key, TeamRole::Manager) || self.has_role(key, TeamRole::Uploader)
+    }
+}
+
+// =============================================================================
+// CreatorProfile - Platform-issued facts about a creator
+// =============================================================================
+
+#[account]
+pub struct CreatorProfile {
+    pub creator: Pubkey,
+    pub verified: bool,   // Identity verified by the platform
+    pub verified_at: i64, // Unix timestamp of the last verification (0 = never)
+    pub bump: u8,
+}
+
+impl CreatorProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        1 +  // verified
+        8 +  // verified_at
+        1; // bump
+}
+
+// =============================================================================
+// CreatorStake - Platform tokens a creator locks for placement and fee discounts
+// =============================================================================
+
+#[account]
+pub struct CreatorStake {
+    pub creator: Pubkey,
+    pub vault: Pubkey, // Token account holding the stake (owned by the platform PDA)
+    pub amount: u64,   // Currently staked
+    pub weight: u64,   // Amount weighted by lock length; read by ranking frontends
+    pub lock_until: i64, // Stake can be withdrawn (and stops earning) after this
+    pub bump: u8,
+}
+
+impl CreatorStake {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        32 + // vault
+        8 +  // amount
+        8 +  // weight
+        8 +  // lock_until
+        1; // bump
+
+    /// Weight of `amount` locked for `duration`: full weight at the maximum lock
+    pub fn weight_for(amount: u64, duration: i64) -> Result<u64> {
+        let weight = (amount as u128)
+            .checked_mul(duration as u128)
+            .ok_or(StreamingError::ArithmeticOverflow)?
+            / MAX_BOOST_LOCK_DURATION as u128;
+        u64::try_from(weight).map_err(|_| error!(StreamingError::ArithmeticOverflow))
+    }
+
+    /// Fee discount the stake earns while locked
+    pub fn fee_discount_bps(&self, platform: &Platform, current_time: i64) -> u16 {
+        if platform.stake_weight_per_discount_bps == 0 || current_time >= self.lock_until {
+            return 0;
+        }
+        (self.weight / platform.stake_weight_per_discount_bps)
+            .min(MAX_BOOST_FEE_DISCOUNT_BPS as u64) as u16
+    }
+}
+
+// =============================================================================
+// EmissionSchedule - Platform-token rewards emitted per settled chunk
+// =============================================================================
+
+#[account]
+pub struct EmissionSchedule {
+    pub reward_mint: Pubkey,     // Platform token emitted (fixed at creation)
+    pub emission_per_chunk: u64, // Initial tokens emitted per settled chunk (0 = paused)
+    pub halving_interval: i64,   // Seconds between halvings of the rate (0 = constant)
+    pub start_time: i64,         // Schedule start, the origin of halvings
+    pub creator_share_bps: u16,  // Creator's share of each emission; the viewer gets the rest
+    pub total_funded: u64,       // Tokens deposited into the emission vault
+    pub total_accrued: u64,      // Tokens credited to balances
+    pub total_claimed: u64,      // Tokens paid out of the vault
+    pub bump: u8,
+}
+
+impl EmissionSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // reward_mint
+        8 +  // emission_per_chunk
+        8 +  // halving_interval
+        8 +  // start_time
+        2 +  // creator_share_bps
+        8 +  // total_funded
+        8 +  // total_accrued
+        8 +  // total_claimed
+        1; // bump
+
+    /// Tokens emitted per chunk at `current_time`
+    pub fn rate_at(&self, current_time: i64) -> u64 {
+        if self.halving_interval <= 0 {
+            return self.emission_per_chunk;
+        }
+        let halvings = (current_time - self.start_time).max(0) / self.halving_interval;
+        self.emission_per_chunk
+            .checked_shr(halvings.min(u32::MAX as i64) as u32)
+            .unwrap_or(0)
+    }
+
+    /// Emit for `chunk_count` settled chunks, capped by the unallocated vault
+    /// balance, and split it between the creator and viewer balances.
+    /// Returns the (creator, viewer) amounts accrued.
+    pub fn accrue(
+        &mut self,
+        creator_balance: &mut EmissionBalance,
+        viewer_balance: &mut EmissionBalance,
+        chunk_count: u32,
+        current_time: i64,
+    ) -> Result<(u64, u64)> {
+        let emitted = self
+            .rate_at(current_time)
+            .checked_mul(chunk_count as u64)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        let available = self.total_funded.saturating_sub(self.total_accrued);
+        let emitted = emitted.min(available);
+
+        let creator_amount =
+            ((emitted as u128) * (self.creator_share_bps as u128) / BASIS_POINTS as u128) as u64;
+        let viewer_amount = emitted - creator_amount;
+
+        self.total_accrued = self
+            .total_accrued
+            .checked_add(emitted)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        creator_balance.accrued = creator_balance
+            .accrued
+            .checked_add(creator_amount)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+        viewer_balance.accrued = viewer_balance
+            .accrued
+            .checked_add(viewer_amount)
+            .ok_or(StreamingError::ArithmeticOverflow)?;
+
+        Ok((creator_amount, viewer_amount))
+    }
+}
+
+// =============================================================================
+// EmissionBalance - A wallet's platform-token emissions (opt-in)
+// =============================================================================
+
+#[account]
+pub struct EmissionBalance {
+    pub owner: Pubkey,
+    pub accrued: u64, // Lifetime emissions credited
+    pub claimed: u64, // Lifetime emissions paid out
+    pub bump: u8,
+}
+
+impl EmissionBalance {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 +  // accrued
+        8 +  // claimed
+        1; // bump
+
+    pub fn claimable(&self) -> u64 {
+        self.accrued - self.claimed
+    }
+}
+
+// =============================================================================
+// MatchingRound - Sponsor-funded quadratic matching pool for creators
+// =============================================================================
+
+#[account]
+pub struct MatchingRound {
+    pub sponsor: Pubkey,
+    pub round_id: u64,
+    pub vault: Pubkey,         // Token account holding the matching pool
+    pub pool: u64,             // Tokens deposited for matching
+    pub start_time: i64,       // Support recorded from this Unix timestamp...
+    pub end_time: i64,         // ...until this one; matches distribute after it
+    pub total_weight: u128,    // Sum over creators of unique supporters squared
+    pub creators: u32,         // Creators with at least one supporter
+    pub creators_matched: u32, // Creators whose match has been distributed
+    pub distributed: u64,      // Tokens paid out as matches
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl MatchingRound {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // sponsor
+        8 +  // round_id
+        32 + // vault
+        8 +  // pool
+        8 +  // start_time
+        8 +  // end_time
+        16 + // total_weight
+        4 +  // creators
+        4 +  // creators_matched
+        8 +  // distributed
+        8 +  // created_at
+        1; // bump
+
+    pub fn is_open(&self, current_time: i64) -> bool {
+        current_time >= self.start_time && current_time < self.end_time
+    }
+
+    /// Match owed to a creator with `supporters` unique supporters: the pool
+    /// split in proportion to each creator's supporters squared
+    pub fn match_for(&self, supporters: u32) -> u64 {
+        if self.total_weight == 0 {
+            return 0;
+        }
+        let weight = (supporters as u128) * (supporters as u128);
+        (self.pool as u128 * weight / self.total_weight) as u64
+    }
+}
+
+// =============================================================================
+// MatchingEntry - A creator's unique-supporter tally in a matching round
+// =============================================================================
+
+#[account]
+pub struct MatchingEntry {
+    pub round: Pubkey,
+    pub creator: Pubkey,
+    pub supporters: u32, // Unique viewers who paid for the creator's videos
+    pub matched: bool,   // Match distributed
+    pub bump: u8,
+}
+
+impl MatchingEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // round
+        32 + // creator
+        4 +  // supporters
+        1 +  // matched
+        1; // bump
+}
+
+// =============================================================================
+// MatchingSupport - Marks a viewer as counted for a creator in a round
+// =============================================================================
+
+#[account]
+pub struct MatchingSupport {
+    pub round: Pubkey,
+    pub creator: Pubkey,
+    pub supporter: Pubkey,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl MatchingSupport {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // round
+        32 + // creator
+        32 + // supporter
+        8 +  // recorded_at
+        1; // bump
+}
+
+// =============================================================================
+// SubtitleBounty - Escrowed reward for a subtitle track in one language
+// =============================================================================
+
+#[account]
+pub struct SubtitleBounty {
+    pub video: Pubkey,
+    pub poster: Pubkey,
+    pub language: String, // BCP 47 language tag the subtitles must cover
+    pub vault: Pubkey,    // Token account holding the reward
+    pub amount: u64,      // Tokens paid to the approved contributor
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl SubtitleBounty {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        32 + // poster
+        4 + MAX_LANGUAGE_LENGTH + // language
+        32 + // vault
+        8 +  // amount
+        8 +  // created_at
+        1; // bump
+}
+
+// =============================================================================
+// VideoTracks - Subtitle and alternate audio tracks for a video
+// =============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrackKind {
+    Subtitle,
+    Audio,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MediaTrack {
+    pub kind: TrackKind,
+    pub language: String, // BCP 47 language tag (e.g. "pt-BR")
+    pub cid: String,      // IPFS CID of the subtitle file or audio manifest
+}
+
+impl MediaTrack {
+    pub const LEN: usize = 1 + // kind
+        4 + MAX_LANGUAGE_LENGTH + // language
+        4 + MAX_IPFS_HASH_LENGTH; // cid
+
+    pub fn is_valid(&self) -> bool {
+        !self.language.is_empty()
+            && self.language.len() <= MAX_LANGUAGE_LENGTH
+            && !self.cid.is_empty()
+            && self.cid.len() <= MAX_IPFS_HASH_LENGTH
+    }
+}
+
+#[account]
+pub struct VideoTracks {
+    pub video: Pubkey,
+    pub tracks: Vec<MediaTrack>, // At most one track per kind and language
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VideoTracks {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        4 + MAX_VIDEO_TRACKS * MediaTrack::LEN + // tracks
+        8 +  // updated_at
+        1; // bump
+
+    pub fn find(&self, kind: TrackKind, language: &str) -> Option<usize> {
+        self.tracks
+            .iter()
+            .position(|t| t.kind == kind && t.language == language)
+    }
+}
+
+// =============================================================================
+// AgeAttestation - Viewer attested of age by the platform's attestor
+// =============================================================================
+
+#[account]
+pub struct AgeAttestation {
+    pub viewer: Pubkey,
+    pub attestor: Pubkey, // Attestor key that issued the attestation
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl AgeAttestation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        32 + // attestor
+        8 +  // attested_at
+        1; // bump
+}
+
+// =============================================================================
+// ArchivedVideo - Compact stub left behind by archive_video
+// =============================================================================
+
+#[account]
+pub struct ArchivedVideo {
+    pub video: Pubkey, // Address of the closed Video (CreatorEarnings stays keyed by it)
+    pub creator: Pubkey,
+    pub video_id: String,
+    pub ipfs_hash: String,
+    pub total_chunks: u32,
+    pub total_sessions: u64,
+    pub total_chunks_served: u64,
+    pub unique_viewers: u64,
+    pub total_earned: u64, // CreatorEarnings::total_earned at archival
+    pub created_at: i64,
+    pub archived_at: i64,
+    pub bump: u8,
+}
+
+impl ArchivedVideo {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // video
+        32 + // creator
+        4 + MAX_VIDEO_ID_LENGTH + // video_id
+        4 + MAX_IPFS_HASH_LENGTH + // ipfs_hash
+        4 +  // total_chunks
+        8 +  // total_sessions
+        8 +  // total_chunks_served
+        8 +  // unique_viewers
+        8 +  // total_earned
+        8 +  // created_at
+        8 +  // archived_at
+        1; // bump
+}
+
+// =============================================================================
+// Organization - Shared viewing budget for a school or company's members
+// =============================================================================
+
+#[account]
+pub struct Organization {
+    pub admin: Pubkey,
+    pub seats: u16,           // Most member wallets the organization pays for
+    pub members: Vec<Pubkey>, // Unique member wallets drawing on the budget
+    pub total_spent: u64,     // Budget moved into members' session escrows
+    pub bump: u8,
+}
+
+impl Organization {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        2 +  // seats
+        4 + MAX_ORGANIZATION_SEATS * 32 + // members
+        8 +  // total_spent
+        1; // bump
+
+    pub fn is_member(&self, wallet: &Pubkey) -> bool {
+        self.members.contains(wallet)
+    }
+}
+
+// =============================================================================
+// ChannelSession - One approval covering a creator's whole catalog
+// =============================================================================
+
+#[account]
+pub struct ChannelSession {
+    pub viewer: Pubkey,
+    pub creator: Pubkey,
+    pub max_spend: u64,           // Token budget approved for the session
+    pub max_price_per_chunk: u64, // Highest chunk price the viewer accepted
+    pub total_spent: u64,
+    pub chunks_consumed: u32, // Across all of the creator's videos
+    pub session_start: i64,
+    pub last_activity: i64,
+    pub bump: u8,
+}
+
+impl ChannelSession {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // viewer
+        32 + // creator
+        8 +  // max_spend
+        8 +  // max_price_per_chunk
+        8 +  // total_spent
+        4 +  // chunks_consumed
+        8 +  // session_start
+        8 +  // last_activity
+        1; // bump
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time - self.session_start > SESSION_EXPIRY_DURATION
+    }
+
+    pub fn is_inactive(&self, current_time: i64) -> bool {
+        current_time - self.last_activity > SESSION_INACTIVITY_DURATION
+    }
+
+    /// Budget not yet spent, still held by the viewer's delegation
+    pub fn remaining_allowance(&self) -> u64 {
+        self.max_spend.saturating_sub(self.total_spent)
+    }
 }
@@ -0,0 +1,99 @@
+// =============================================================================
+// Wormhole - Cross-chain payment receipts from guardian-verified VAAs
+// =============================================================================
+// Viewers on other chains (e.g. USDC on Base) pay a registered emitter
+// contract, which publishes a Wormhole message describing the payment. Once
+// the guardians sign it, the VAA is posted to Solana by the Wormhole core
+// bridge, which verifies the signatures and stores the message in a
+// `PostedVAA` account it owns. We parse that account directly (no Wormhole SDK
+// dependency): ownership by the core bridge is what proves the VAA verified.
+// =============================================================================
+
+use crate::errors::*;
+use anchor_lang::prelude::*;
+
+// PostedVAA (v1) account layout: b"vaa", then the Borsh-encoded message data
+const POSTED_VAA_MAGIC: &[u8] = b"vaa";
+const SEQUENCE_OFFSET: usize = 49; // after version, consistency, times, signature set, nonce
+const EMITTER_CHAIN_OFFSET: usize = 57;
+const EMITTER_ADDRESS_OFFSET: usize = 59;
+const PAYLOAD_OFFSET: usize = 91; // u32 length, then the payload bytes
+
+// Payment payload published by the emitter contract (big-endian, as on EVM)
+pub const CROSS_CHAIN_PAYMENT_PAYLOAD_ID: u8 = 1;
+const CROSS_CHAIN_PAYMENT_LEN: usize = 78;
+
+/// Message fields of a posted VAA
+pub struct PostedVaa {
+    pub sequence: u64,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub payload: Vec<u8>,
+}
+
+/// Payment made on another chain for a Solana viewing session
+pub struct CrossChainPayment {
+    pub viewer: Pubkey,
+    pub video: Pubkey,
+    pub session_nonce: u8,
+    pub amount: u64, // Platform token base units
+    pub max_chunks: u32,
+}
+
+/// Read a VAA posted (and so verified) by the Wormhole core bridge `core_bridge`.
+pub fn load_posted_vaa(posted_vaa: &AccountInfo, core_bridge: &Pubkey) -> Result<PostedVaa> {
+    require_keys_eq!(*posted_vaa.owner, *core_bridge, StreamingError::InvalidVaa);
+
+    let data = posted_vaa.try_borrow_data()?;
+    require!(
+        data.len() >= PAYLOAD_OFFSET + 4 && data.starts_with(POSTED_VAA_MAGIC),
+        StreamingError::InvalidVaa
+    );
+
+    let mut sequence = [0u8; 8];
+    sequence.copy_from_slice(&data[SEQUENCE_OFFSET..SEQUENCE_OFFSET + 8]);
+    let mut emitter_chain = [0u8; 2];
+    emitter_chain.copy_from_slice(&data[EMITTER_CHAIN_OFFSET..EMITTER_CHAIN_OFFSET + 2]);
+    let mut emitter_address = [0u8; 32];
+    emitter_address.copy_from_slice(&data[EMITTER_ADDRESS_OFFSET..EMITTER_ADDRESS_OFFSET + 32]);
+    let mut payload_len = [0u8; 4];
+    payload_len.copy_from_slice(&data[PAYLOAD_OFFSET..PAYLOAD_OFFSET + 4]);
+
+    let payload_start = PAYLOAD_OFFSET + 4;
+    let payload = data
+        .get(payload_start..payload_start + u32::from_le_bytes(payload_len) as usize)
+        .ok_or(StreamingError::InvalidVaa)?
+        .to_vec();
+
+    Ok(PostedVaa {
+        sequence: u64::from_le_bytes(sequence),
+        emitter_chain: u16::from_le_bytes(emitter_chain),
+        emitter_address,
+        payload,
+    })
+}
+
+impl CrossChainPayment {
+    /// Decode the payload: id (1), viewer (32), video (32), session nonce (1),
+    /// amount (u64), max chunks (u32)
+    pub fn decode(payload: &[u8]) -> Result<Self> {
+        require!(
+            payload.len() == CROSS_CHAIN_PAYMENT_LEN
+                && payload[0] == CROSS_CHAIN_PAYMENT_PAYLOAD_ID,
+            StreamingError::InvalidVaa
+        );
+
+        let mut amount = [0u8; 8];
+        amount.copy_from_slice(&payload[66..74]);
+        let mut max_chunks = [0u8; 4];
+        max_chunks.copy_from_slice(&payload[74..78]);
+
+        Ok(Self {
+            viewer: Pubkey::try_from(&payload[1..33]).map_err(|_| StreamingError::InvalidVaa)?,
+            video: Pubkey::try_from(&payload[33..65]).map_err(|_| StreamingError::InvalidVaa)?,
+            session_nonce: payload[65],
+            amount: u64::from_be_bytes(amount),
+            max_chunks: u32::from_be_bytes(max_chunks),
+        })
+    }
+}
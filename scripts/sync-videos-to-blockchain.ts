@@ -119,7 +119,7 @@ async function main() {
           ? Math.ceil(video.duration / 5)
           : 100; // 5 seconds per chunk
         const title = video.title.substring(0, 200); // Max 200 chars
-        const description = ""; // Empty description for now
+        const metadataUri = ""; // Description stays in the database for now
 
         console.log(`   Price per chunk: ${pricePerChunk.toString()} tokens`);
         console.log(`   Total chunks: ${totalChunks}`);
@@ -138,7 +138,11 @@ async function main() {
             totalChunks,
             pricePerChunk,
             title,
-            description
+            metadataUri,
+            Array(32).fill(0), // No metadata hash
+            Array(32).fill(0), // No chunk manifest commitment
+            "", // No thumbnail
+            "" // No poster
           )
           .accountsPartial({
             video: videoPda,